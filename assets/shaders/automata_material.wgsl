@@ -1,8 +1,8 @@
 // ─────────────────────────────────────────────────────────────────────────────
 // AutomataMaterial shader  –  Bevy 0.16-compatible
 //
-// Renders an **R8-unorm** texture that encodes cell life state
-// (0 = dead, 1 = alive) on a world-aligned quad.
+// Renders an **RGBA8** texture holding one colour per cell (transparent =
+// dead, otherwise the rule’s palette colour) on a world-aligned quad.
 //
 // Key fix in this version
 // -----------------------
@@ -34,8 +34,8 @@ struct AutomataParams {
     zoom:         f32,         // orthographic zoom factor
     cell_size:    f32,         // one cell in world units
     texture_size: vec2<f32>,   // (width, height) in texels
-    dead_color:   vec4<f32>,   // RGBA for state = 0
-    alive_color:  vec4<f32>,   // RGBA for state = 1
+    dead_color:   vec4<f32>,   // RGBA where the texel is transparent
+    lattice:      u32,         // 0 squares · 1 odd-r hexagons · 2 triangles
 };
 
 @group(2) @binding(0) var<uniform> Params    : AutomataParams;
@@ -69,6 +69,49 @@ fn vertex(in: VertexInput) -> VOut {
     return out;
 }
 
+// ─────– Lattice lookup (position in texel widths → texel) ─────────────────────
+const SQRT3: f32 = 1.7320508;
+
+// Pointy-top hexagons one texel wide, rows √3/2 apart, odd rows shifted
+// right by half a cell (odd-r offset storage, see `DimHex`).
+fn hex_texel(p: vec2<f32>) -> vec2<i32> {
+    let pitch = SQRT3 * 0.5;
+    let rel   = p - vec2<f32>(0.5, 0.5 * pitch);   // centre of hex (0, 0)
+
+    // fractional axial coordinates, then cube rounding
+    let r  = rel.y / pitch;
+    let q  = rel.x - 0.5 * r;
+    let s  = -q - r;
+    var rq = round(q);
+    var rr = round(r);
+    let rs = round(s);
+    let dq = abs(rq - q);
+    let dr = abs(rr - r);
+    let ds = abs(rs - s);
+    if (dq > dr && dq > ds) {
+        rq = -rr - rs;
+    } else if (dr > ds) {
+        rr = -rq - rs;
+    }
+
+    let row = i32(rr);
+    return vec2<i32>(i32(rq) + (row >> 1u), row);
+}
+
+// Triangles two texels wide and √3 tall, overlapping by half; cell (x, y)
+// points up (towards +y) when x + y is even (see `DimTri`).
+fn tri_texel(p: vec2<f32>) -> vec2<i32> {
+    let row = i32(floor(p.y / SQRT3));
+    let t   = fract(p.y / SQRT3);
+    let i   = i32(floor(p.x));
+    let f   = fract(p.x);
+
+    // the slanted edge between triangles i − 1 and i crosses this column
+    let up     = ((i + row) & 1) == 0;
+    let inside = select(t >= 1.0 - f, t <= f, up);
+    return vec2<i32>(select(i - 1, i, inside), row);
+}
+
 // ─────– Fragment stage ───────────────────────────────────────────────────────
 @fragment
 fn fragment(in: VOut) -> @location(0) vec4<f32> {
@@ -80,11 +123,34 @@ fn fragment(in: VOut) -> @location(0) vec4<f32> {
     let tex_space    = world_offset / cell_scale;
 
     // 3. Map to texture coordinates (0 … 1), treating the grid centre as (0, 0)
-    let tex_uv = (tex_space + Params.texture_size * 0.5) / Params.texture_size;
+    var tex_uv = (tex_space + Params.texture_size * 0.5) / Params.texture_size;
+
+    // 3b. Hex / triangle lattices: find the cell under the fragment and
+    //     sample the centre of its texel (outside the lattice → dead); rows
+    //     are `pitch` texels tall, so the quad is that much taller or shorter
+    if (Params.lattice != 0u) {
+        let pitch = select(SQRT3, SQRT3 * 0.5, Params.lattice == 1u);
+        let p     = tex_space + Params.texture_size * vec2<f32>(0.5, 0.5 * pitch);
+        var texel: vec2<i32>;
+        if (Params.lattice == 1u) {
+            texel = hex_texel(p);
+        } else {
+            texel = tri_texel(p);
+        }
+        let size = vec2<i32>(Params.texture_size);
+        if (any(texel < vec2<i32>(0)) || any(texel >= size)) {
+            tex_uv = vec2<f32>(-1.0);
+        } else {
+            tex_uv = (vec2<f32>(texel) + 0.5) / Params.texture_size;
+        }
+    }
 
-    // 4. Sample R8 texture (nearest-neighbour set in Rust)
-    let state = textureSample(grid_tex, grid_samp, tex_uv).r;
+    // 4. Sample the cell colour (nearest-neighbour set in Rust)
+    var cell = textureSample(grid_tex, grid_samp, tex_uv);
+    if (tex_uv.x < 0.0) {
+        cell = vec4<f32>(0.0);
+    }
 
-    // 5. Blend dead ↔ alive colours
-    return mix(Params.dead_color, Params.alive_color, state);
+    // 5. Dead cells are transparent → fall back to the dead colour
+    return mix(Params.dead_color, vec4<f32>(cell.rgb, 1.0), cell.a);
 }
@@ -0,0 +1,64 @@
+//! ECS components shared by every agent type.
+
+use bevy::prelude::*;
+use engine_core::events::AutomatonId;
+
+use super::Agent;
+
+/// Cell the agent currently occupies (slice‑relative coordinates).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GridPos(pub IVec2);
+
+/// Facing / velocity.  Grid walkers keep a unit axis vector, continuous
+/// movers (boids) store their full velocity here.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Heading(pub Vec2);
+
+impl Heading {
+    /// Nearest whole‑cell step in the facing direction.
+    pub fn step(self) -> IVec2 {
+        self.0.round().as_ivec2()
+    }
+    /// 90° clockwise (y axis pointing up).
+    pub fn turned_right(self) -> Self {
+        Self(Vec2::new(self.0.y, -self.0.x))
+    }
+    /// 90° counter‑clockwise.
+    pub fn turned_left(self) -> Self {
+        Self(self.0.perp())
+    }
+    pub fn reversed(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+/// Latest sensor readings, written by [`Agent::sense`] and kept between
+/// ticks so UI / analytics can inspect them.
+#[derive(Component, Clone, Debug, Default)]
+pub struct Sensors(pub Vec<f32>);
+
+/// The automaton slice an agent lives in.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Habitat(pub AutomatonId);
+
+/// Everything a freshly spawned agent needs.
+#[derive(Bundle)]
+pub struct AgentBundle<A: Agent> {
+    pub agent:   A,
+    pub pos:     GridPos,
+    pub heading: Heading,
+    pub sensors: Sensors,
+    pub habitat: Habitat,
+}
+
+impl<A: Agent> AgentBundle<A> {
+    pub fn new(agent: A, habitat: AutomatonId, pos: IVec2, heading: Vec2) -> Self {
+        Self {
+            agent,
+            pos:     GridPos(pos),
+            heading: Heading(heading),
+            sensors: Sensors::default(),
+            habitat: Habitat(habitat),
+        }
+    }
+}
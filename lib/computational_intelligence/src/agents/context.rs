@@ -0,0 +1,119 @@
+//! Per‑turn context handed to [`Agent::sense`] / [`Agent::step`].
+
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+};
+
+use bevy::prelude::*;
+use engine_core::events::AutomatonId;
+use rand::rngs::ThreadRng;
+use serde_json::Value;
+
+use super::{Agent, AgentBundle, CellView};
+
+/// Snapshot of a fellow agent (same type, same habitat) taken at the start
+/// of the tick – lets flocking‑style agents update *synchronously*.
+#[derive(Clone, Copy, Debug)]
+pub struct Peer {
+    pub entity:  Entity,
+    pub pos:     IVec2,
+    pub heading: Vec2,
+}
+
+/// Everything an agent may read or change during its turn.
+///
+/// Agents act *sequentially* (in random order each tick), so the occupancy
+/// map always reflects moves already made this tick.  Only agents of the
+/// same type share a map.
+pub struct AgentCtx<'a, 'w, 's> {
+    pub entity:  Entity,
+    pub habitat: AutomatonId,
+    pub heading: Vec2,
+    pub sensors: &'a mut Vec<f32>,
+    pub cells:   CellView<'a>,
+    pub params:  &'a Value,
+    pub rng:     &'a mut ThreadRng,
+
+    pub(crate) pos:       IVec2,
+    pub(crate) peers:     &'a [Peer],
+    pub(crate) occupancy: &'a mut HashMap<IVec2, Entity>,
+    pub(crate) killed:    &'a mut HashSet<Entity>,
+    pub(crate) commands:  &'a mut Commands<'w, 's>,
+    /// `HashMap<Entity, A>` with the state of every *other* agent of the
+    /// habitat (type‑erased so the context stays non‑generic).
+    pub(crate) others:    &'a mut dyn Any,
+}
+
+impl AgentCtx<'_, '_, '_> {
+    pub fn pos(&self) -> IVec2 { self.pos }
+
+    /// All agents of this type in the habitat as they were at tick start
+    /// (including this one – filter on `entity`).
+    pub fn peers(&self) -> &[Peer] { self.peers }
+
+    /// Agent currently standing on `p`, if any.
+    pub fn occupant(&self, p: IVec2) -> Option<Entity> {
+        self.occupancy.get(&self.cells.wrap(p)).copied()
+    }
+
+    pub fn is_free(&self, p: IVec2) -> bool {
+        self.occupant(p).is_none()
+    }
+
+    /// Moves to `p` (wrapped) regardless of who else stands there.
+    pub fn move_to(&mut self, p: IVec2) {
+        let p = self.cells.wrap(p);
+        if self.occupancy.get(&self.pos) == Some(&self.entity) {
+            self.occupancy.remove(&self.pos);
+        }
+        self.occupancy.entry(p).or_insert(self.entity);
+        self.pos = p;
+    }
+
+    /// Moves to `p` only if the cell is free; returns whether it moved.
+    pub fn try_move_to(&mut self, p: IVec2) -> bool {
+        let free = self.occupant(p).is_none_or(|e| e == self.entity);
+        if free { self.move_to(p); }
+        free
+    }
+
+    /// Removes the agent standing on `p` (never the caller itself).
+    pub fn kill_at(&mut self, p: IVec2) -> Option<Entity> {
+        let p = self.cells.wrap(p);
+        let victim = self.occupancy.get(&p).copied().filter(|&e| e != self.entity)?;
+        self.occupancy.remove(&p);
+        self.killed.insert(victim);
+        self.commands.entity(victim).despawn();
+        Some(victim)
+    }
+
+    /// Internal state of another agent of the same type (`None` if it is
+    /// unknown, was killed this tick or `B` is not the caller’s type).
+    ///
+    /// This is the tick’s working copy, not a snapshot: agents that already
+    /// took their turn show the state they ended it with, later ones the
+    /// state from last tick, and agents spawned this tick their initial one.
+    pub fn agent<B: Agent>(&self, e: Entity) -> Option<&B> {
+        if self.killed.contains(&e) { return None; }
+        self.others.downcast_ref::<HashMap<Entity, B>>()?.get(&e)
+    }
+
+    /// Mutable variant of [`AgentCtx::agent`] – trade, inheritance, ….
+    pub fn agent_mut<B: Agent>(&mut self, e: Entity) -> Option<&mut B> {
+        if self.killed.contains(&e) { return None; }
+        self.others.downcast_mut::<HashMap<Entity, B>>()?.get_mut(&e)
+    }
+
+    /// Spawns a new agent into this habitat.  It first acts next tick, but
+    /// other agents can see it through [`AgentCtx::agent`] right away.
+    pub fn spawn<B: Agent>(&mut self, agent: B, pos: IVec2, heading: Vec2) -> Entity {
+        let pos = self.cells.wrap(pos);
+        let e = self.commands.spawn(AgentBundle::new(agent.clone(), self.habitat, pos, heading)).id();
+        self.occupancy.entry(pos).or_insert(e);
+        if let Some(others) = self.others.downcast_mut::<HashMap<Entity, B>>() {
+            others.insert(e, agent);
+        }
+        e
+    }
+}
@@ -0,0 +1,100 @@
+//! The slice an agent population lives in: cell states + scalar layers.
+
+use std::sync::Arc;
+
+use bevy::math::{IVec2, UVec2};
+use engine_core::{
+    core::{cell::CellState, dim::Dim2, AutomatonRule, CellCtx, CellOutcome},
+    engine::grid::{CellLayers, GridBackend},
+};
+use serde_json::Value;
+
+/// Mutable window onto one automaton’s grid and its [`CellLayers`].
+///
+/// Dense slices are treated as a torus: [`CellView::wrap`] folds any
+/// coordinate back inside, [`CellView::delta`] returns the shortest
+/// displacement across the seams.  Sparse slices are unbounded.
+pub struct CellView<'a> {
+    pub grid:   &'a mut GridBackend,
+    pub layers: &'a mut CellLayers,
+}
+
+impl<'a> CellView<'a> {
+    pub fn new(grid: &'a mut GridBackend, layers: &'a mut CellLayers) -> Self {
+        Self { grid, layers }
+    }
+
+    /// Width × height of a dense slice (`None` for sparse grids and graphs).
+    pub fn size(&self) -> Option<UVec2> {
+        match &*self.grid {
+            GridBackend::Dense(g)  => Some(g.size),
+            GridBackend::Sparse(_) | GridBackend::Graph(_) => None,
+        }
+    }
+
+    pub fn wrap(&self, p: IVec2) -> IVec2 {
+        match self.size() {
+            Some(s) => IVec2::new(p.x.rem_euclid(s.x as i32), p.y.rem_euclid(s.y as i32)),
+            None    => p,
+        }
+    }
+
+    /// Shortest vector `from → to` on the torus.
+    pub fn delta(&self, from: IVec2, to: IVec2) -> IVec2 {
+        let d = to - from;
+        let Some(s) = self.size() else { return d };
+        let fold = |d: i32, n: i32| {
+            let d = d.rem_euclid(n);
+            if d > n / 2 { d - n } else { d }
+        };
+        IVec2::new(fold(d.x, s.x as i32), fold(d.y, s.y as i32))
+    }
+
+    pub fn state(&self, p: IVec2) -> CellState {
+        let p = self.wrap(p);
+        match &*self.grid {
+            GridBackend::Dense(g)  => g.get(p).map_or(CellState::Dead, |c| c.state),
+            GridBackend::Sparse(g) => g.get(p).map_or(CellState::Dead, |c| c.state),
+            GridBackend::Graph(g)  => g.cells.get(p.x as usize).map_or(CellState::Dead, |c| c.state),
+        }
+    }
+
+    pub fn set_state(&mut self, p: IVec2, s: CellState) {
+        let p = self.wrap(p);
+        match &mut *self.grid {
+            GridBackend::Dense(g)  => if let Some(c) = g.get_mut(p) { c.state = s; },
+            GridBackend::Sparse(g) => g.set_state(p, s),
+            GridBackend::Graph(g)  => if let Some(c) = g.cells.get_mut(p.x as usize) { c.state = s; },
+        }
+    }
+
+    /// Value of the scalar layer `name` at `p`.
+    pub fn layer(&self, name: &str, p: IVec2) -> f32 {
+        self.layers.get(name, self.wrap(p))
+    }
+
+    pub fn set_layer(&mut self, name: &str, p: IVec2, v: f32) {
+        let p = self.wrap(p);
+        self.layers.set(name, p, v);
+    }
+}
+
+/* ───────────────────────────── cell rule ───────────────────────────── */
+
+/// Cell rule for slices that are driven *entirely* by their agents: every
+/// cell keeps its state, only agents write to the grid.
+#[derive(Clone)]
+pub struct HabitatRule;
+
+impl HabitatRule {
+    pub fn boxed() -> Arc<dyn AutomatonRule<D = Dim2> + Send + Sync> {
+        Arc::new(Self)
+    }
+}
+
+impl AutomatonRule for HabitatRule {
+    type D = Dim2;
+    fn next_state(&self, _ctx: CellCtx<Self::D>, _params: &Value) -> CellOutcome {
+        CellOutcome::Unchanged
+    }
+}
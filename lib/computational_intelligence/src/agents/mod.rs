@@ -0,0 +1,62 @@
+//! Generic **grid agents** – walkers that live *on top of* an automaton slice.
+//!
+//! Cellular rules update every cell in lock‑step; agents instead carry their
+//! own position, heading and internal state and act on the cells they stand
+//! on.  Ants, boids, turmites and every later multi‑agent model share the
+//! same plumbing:
+//!
+//! * [`Agent`] – the behaviour, implemented by a Bevy `Component` that holds
+//!   the agent’s internal state.
+//! * [`components`] – `GridPos`, `Heading`, `Sensors` and `Habitat` (the
+//!   automaton slice the agent lives in).
+//! * [`CellView`] – read/write access to the host slice’s cell states and
+//!   named scalar layers.
+//! * [`AgentStepperPlugin`] – steps every agent of one type against its
+//!   habitat once per tick, in random order.
+//! * [`AgentSeedFn`] – registered next to a rule in `RuleRegistry`, so agent
+//!   models spawn through the ordinary `AutomataCommand::SeedPattern` path.
+
+use bevy::ecs::component::{Component, Mutable};
+use serde::de::DeserializeOwned;
+
+pub mod components;
+pub mod context;
+pub mod habitat;
+pub mod plugin;
+pub mod spawn;
+
+pub use components::{AgentBundle, GridPos, Habitat, Heading, Sensors};
+pub use context::{AgentCtx, Peer};
+pub use habitat::{CellView, HabitatRule};
+pub use plugin::{AgentStepperPlugin, AgentsPlugin};
+pub use spawn::{AgentSeedFn, AgentSpawner};
+
+/// What happens to an agent after its turn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AgentOutcome {
+    Live,
+    /// Despawn the agent (starvation, old age, …).
+    Die,
+}
+
+/// Behaviour of one kind of grid agent.
+///
+/// The implementing component *is* the agent’s internal state; position,
+/// heading and sensor readings live in the sibling components and are
+/// handed over through [`AgentCtx`].  States are cloned into a per‑tick
+/// working set so agents can reach each other via [`AgentCtx::agent_mut`].
+pub trait Agent: Component<Mutability = Mutable> + Clone + Sized {
+    /// Model parameters, parsed from the habitat’s JSON `params` once per
+    /// tick (unknown / missing fields fall back to `Default`).
+    type Params: DeserializeOwned + Default;
+
+    /// Runs once per tick for every habitat that hosts at least one agent of
+    /// this type, *before* any of them moves (regrowth, evaporation, …).
+    fn habitat_tick(_cells: &mut CellView, _params: &Self::Params) {}
+
+    /// Fill `ctx.sensors` from the surroundings; called right before `step`.
+    fn sense(&self, _ctx: &mut AgentCtx, _params: &Self::Params) {}
+
+    /// Act on the habitat: move, read/write cells, eat, reproduce …
+    fn step(&mut self, ctx: &mut AgentCtx, params: &Self::Params) -> AgentOutcome;
+}
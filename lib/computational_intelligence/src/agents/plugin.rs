@@ -0,0 +1,160 @@
+//! Systems that drive agent populations.
+//!
+//! * [`AgentsPlugin`] – added once by the CI root plugin; despawns agents
+//!   whose habitat was removed.
+//! * [`AgentStepperPlugin<A>`] – added once **per agent type**; steps all
+//!   agents of type `A` every logic tick.
+
+use std::{
+    collections::{HashMap, HashSet},
+    marker::PhantomData,
+};
+
+use bevy::prelude::*;
+use engine_core::{
+    events::{AutomatonId, AutomatonRemoved},
+    schedule::MainSet,
+    state::AppState,
+};
+use rand::seq::SliceRandom;
+
+use super::{Agent, AgentCtx, AgentOutcome, CellView, GridPos, Habitat, Heading, Peer, Sensors};
+use crate::registry::{AutomataRegistry, AutomatonInfo};
+
+/* ───────────────────────────── core plugin ─────────────────────────── */
+
+pub struct AgentsPlugin;
+impl Plugin for AgentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, despawn_orphaned_agents);
+    }
+}
+
+fn despawn_orphaned_agents(
+    mut removed:  EventReader<AutomatonRemoved>,
+    agents:       Query<(Entity, &Habitat)>,
+    mut commands: Commands,
+) {
+    let gone: HashSet<AutomatonId> = removed.read().map(|ev| ev.id).collect();
+    if gone.is_empty() { return; }
+
+    for (entity, habitat) in &agents {
+        if gone.contains(&habitat.0) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/* ─────────────────────────── per‑type stepper ──────────────────────── */
+
+/// Steps every agent of type `A` once per logic tick.
+pub struct AgentStepperPlugin<A: Agent>(PhantomData<fn() -> A>);
+
+impl<A: Agent> Default for AgentStepperPlugin<A> {
+    fn default() -> Self { Self(PhantomData) }
+}
+
+impl<A: Agent> Plugin for AgentStepperPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            step_agents::<A>
+                .in_set(MainSet::Logic)
+                .run_if(in_state(AppState::InGame)),
+        );
+    }
+}
+
+type AgentQuery<'w, 's, A> = Query<
+    'w, 's,
+    (Entity, &'static mut A, &'static mut GridPos, &'static mut Heading, &'static mut Sensors, &'static Habitat),
+>;
+
+fn step_agents<A: Agent>(
+    mut registry: ResMut<AutomataRegistry>,
+    mut agents:   AgentQuery<A>,
+    mut commands: Commands,
+) {
+    let mut rng = rand::rng();
+
+    // ── 1. group by habitat + tick‑start snapshot ──────────────────────
+    let mut populations: HashMap<AutomatonId, Vec<Peer>> = HashMap::new();
+    for (entity, _, pos, heading, _, habitat) in &agents {
+        populations.entry(habitat.0).or_default().push(Peer {
+            entity,
+            pos:     pos.0,
+            heading: heading.0,
+        });
+    }
+
+    for (habitat, peers) in populations {
+        // Orphans are cleaned up by `AgentsPlugin`.
+        let Some(info) = registry.get_mut(habitat) else { continue };
+        let AutomatonInfo { grid, layers, params, .. } = info;
+
+        // ── 2. environment update ──────────────────────────────────────
+        let config: A::Params = serde_json::from_value(params.clone()).unwrap_or_default();
+        A::habitat_tick(&mut CellView::new(grid, layers), &config);
+
+        // ── 3. sequential, randomly ordered agent turns ────────────────
+        let mut occupancy: HashMap<IVec2, Entity> =
+            peers.iter().map(|p| (p.pos, p.entity)).collect();
+        let mut killed = HashSet::new();
+        let mut order: Vec<Entity> = peers.iter().map(|p| p.entity).collect();
+        order.shuffle(&mut rng);
+
+        // Working copy of every agent state, so agents can reach each other.
+        let mut states: HashMap<Entity, A> = order.iter()
+            .filter_map(|&e| agents.get(e).ok().map(|(_, a, ..)| (e, a.clone())))
+            .collect();
+
+        for entity in order {
+            if killed.contains(&entity) { continue; }
+            let Some(mut agent) = states.remove(&entity) else { continue };
+            let Ok((_, _, mut pos, mut heading, mut sensors, _)) = agents.get_mut(entity)
+            else { continue };
+
+            let mut ctx = AgentCtx {
+                entity,
+                habitat,
+                heading:   heading.0,
+                sensors:   &mut sensors.0,
+                cells:     CellView::new(grid, layers),
+                params,
+                rng:       &mut rng,
+                pos:       pos.0,
+                peers:     &peers,
+                occupancy: &mut occupancy,
+                killed:    &mut killed,
+                commands:  &mut commands,
+                others:    &mut states,
+            };
+            agent.sense(&mut ctx, &config);
+            let outcome = agent.step(&mut ctx, &config);
+            let (new_pos, new_heading) = (ctx.pos, ctx.heading);
+
+            pos.0     = new_pos;
+            heading.0 = new_heading;
+            states.insert(entity, agent);
+
+            // ── 4. deaths ──────────────────────────────────────────────
+            if outcome == AgentOutcome::Die {
+                if occupancy.get(&new_pos) == Some(&entity) {
+                    occupancy.remove(&new_pos);
+                }
+                killed.insert(entity);
+                commands.entity(entity).despawn();
+            }
+        }
+
+        // ── 5. write the working set back ──────────────────────────────
+        for (entity, state) in states {
+            if killed.contains(&entity) { continue; }
+            match agents.get_mut(entity) {
+                Ok((_, mut agent, ..)) => *agent = state,
+                // Spawned this tick; the entity only exists once commands apply.
+                Err(_) => { commands.entity(entity).insert(state); }
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Initial agent populations, spawned through `AutomataCommand::SeedPattern`.
+
+use bevy::prelude::*;
+use engine_core::events::AutomatonId;
+use serde_json::Value;
+
+use super::{Agent, AgentBundle, CellView};
+
+/// Populates a freshly seeded habitat; registered with
+/// `RuleRegistry::register_agents`.
+pub type AgentSeedFn = fn(&mut AgentSpawner);
+
+/// Handed to an [`AgentSeedFn`] right after the cell seed ran.
+pub struct AgentSpawner<'a, 'w, 's> {
+    pub habitat: AutomatonId,
+    /// ECS entity that represents the automaton itself.
+    pub entity:  Entity,
+    pub cells:   CellView<'a>,
+    pub params:  &'a Value,
+    commands:    &'a mut Commands<'w, 's>,
+}
+
+impl<'a, 'w, 's> AgentSpawner<'a, 'w, 's> {
+    pub fn new(
+        habitat:  AutomatonId,
+        entity:   Entity,
+        cells:    CellView<'a>,
+        params:   &'a Value,
+        commands: &'a mut Commands<'w, 's>,
+    ) -> Self {
+        Self { habitat, entity, cells, params, commands }
+    }
+
+    pub fn spawn<A: Agent>(&mut self, agent: A, pos: IVec2, heading: Vec2) -> Entity {
+        let pos = self.cells.wrap(pos);
+        self.commands.spawn(AgentBundle::new(agent, self.habitat, pos, heading)).id()
+    }
+
+    /// Raw access for models that attach extra components.
+    pub fn commands(&mut self) -> &mut Commands<'w, 's> {
+        self.commands
+    }
+}
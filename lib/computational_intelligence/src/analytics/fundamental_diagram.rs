@@ -0,0 +1,67 @@
+//! Flow–density **fundamental diagram** of the Nagel–Schreckenberg model,
+//! obtained by sweeping the car density on a fresh ring road.
+
+use std::fmt::Write as _;
+
+use crate::automata::dynamical::traffic::{RingRoad, TrafficParams};
+
+/// One point of the diagram, averaged over the measurement window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlowSample {
+    /// Cars per site.
+    pub density:    f64,
+    /// Cars passing a fixed point per tick and lane.
+    pub flow:       f64,
+    pub mean_speed: f64,
+}
+
+/// Runs `points` roads of `length` sites at densities `1/points … 1`, each
+/// for `warmup` ticks and then `measure` ticks of averaging.  Lane count,
+/// `vmax` and braking come from `params`; its `density` is ignored.
+///
+/// ```
+/// use computational_intelligence::analytics::fundamental_diagram::fundamental_diagram;
+/// use computational_intelligence::automata::dynamical::traffic::TrafficParams;
+///
+/// let p  = TrafficParams { lanes: 1, ..Default::default() };
+/// let fd = fundamental_diagram(&p, 200, 10, 50, 50);
+/// assert_eq!(fd.len(), 10);
+/// // flow = density × mean speed ≤ density × vmax; a full road is jammed
+/// assert!(fd.iter().all(|s| s.flow <= s.density * p.vmax as f64 + 1e-9));
+/// assert_eq!(fd.last().unwrap().flow, 0.0);
+/// ```
+pub fn fundamental_diagram(
+    params:  &TrafficParams,
+    length:  usize,
+    points:  usize,
+    warmup:  usize,
+    measure: usize,
+) -> Vec<FlowSample> {
+    let mut rng = rand::rng();
+    (1..=points)
+        .map(|i| {
+            let density  = i as f64 / points as f64;
+            let mut road = RingRoad::random(length, params.lanes as usize, density, &mut rng);
+            for _ in 0..warmup {
+                road.step(params, &mut rng);
+            }
+            let (mut flow, mut speed) = (0.0, 0.0);
+            for _ in 0..measure.max(1) {
+                road.step(params, &mut rng);
+                flow  += road.flow();
+                speed += road.mean_speed();
+            }
+            let n = measure.max(1) as f64;
+            FlowSample { density: road.density(), flow: flow / n, mean_speed: speed / n }
+        })
+        .collect()
+}
+
+/// `density,flow,mean_speed` rows with a header line.
+pub fn to_csv(samples: &[FlowSample]) -> String {
+    let mut out = String::from("density,flow,mean_speed\n");
+    for s in samples {
+        let _ = writeln!(out, "{},{},{}", s.density, s.flow, s.mean_speed);
+    }
+    out
+}
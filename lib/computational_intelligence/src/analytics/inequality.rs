@@ -0,0 +1,26 @@
+//! Inequality measures over agent wealth distributions.
+
+/// Gini coefficient of `values` (0 = perfect equality, → 1 = one agent owns
+/// everything).  Negative entries are clamped to zero; empty or all‑zero
+/// input yields `0.0`.
+///
+/// ```
+/// use computational_intelligence::analytics::inequality::gini;
+/// assert_eq!(gini(&[5.0, 5.0, 5.0]), 0.0);
+/// assert!((gini(&[0.0, 0.0, 0.0, 10.0]) - 0.75).abs() < 1e-9);
+/// ```
+pub fn gini(values: &[f64]) -> f64 {
+    let mut v: Vec<f64> = values.iter().map(|x| x.max(0.0)).collect();
+    let n     = v.len();
+    let total: f64 = v.iter().sum();
+    if n == 0 || total <= 0.0 {
+        return 0.0;
+    }
+    v.sort_by(f64::total_cmp);
+    // G = Σ (2i − n − 1)·xᵢ / (n·Σx), i = 1…n over the sorted values
+    let weighted: f64 = v.iter()
+        .enumerate()
+        .map(|(i, x)| (2.0 * (i as f64 + 1.0) - n as f64 - 1.0) * x)
+        .sum();
+    weighted / (n as f64 * total)
+}
@@ -1,3 +1,11 @@
+pub mod fundamental_diagram;
 pub mod iit_phi;
+pub mod inequality;
 pub mod iti_individuality;
-pub mod swarm_metrics;
\ No newline at end of file
+pub mod segregation;
+pub mod swarm_metrics;
+pub mod synchrony;
+pub mod thermodynamics;
+pub mod time_series;
+
+pub mod plugin;
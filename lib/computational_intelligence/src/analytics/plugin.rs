@@ -0,0 +1,24 @@
+//! Registers the analytics resources shared by all models.
+
+use bevy::prelude::*;
+use engine_core::events::AutomatonRemoved;
+
+use super::time_series::TimeSeries;
+
+pub struct AnalyticsPlugin;
+impl Plugin for AnalyticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimeSeries>()
+           .add_systems(Update, drop_removed_series);
+    }
+}
+
+/// Forget the series of automata that no longer exist.
+fn drop_removed_series(
+    mut removed: EventReader<AutomatonRemoved>,
+    mut series:  ResMut<TimeSeries>,
+) {
+    for ev in removed.read() {
+        series.remove(ev.id);
+    }
+}
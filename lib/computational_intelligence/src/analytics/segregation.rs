@@ -0,0 +1,79 @@
+//! Spatial segregation measures over categorical grids (Schelling & co.).
+
+use bevy::math::IVec2;
+use engine_core::{
+    core::{cell::CellState, dim::Dim2, Dim},
+    engine::grid::GridBackend,
+};
+
+/// Mean share of *like* neighbours: for every occupied cell, the fraction of
+/// its occupied Moore neighbours that carry the same state, averaged over
+/// all occupied cells that have at least one occupied neighbour.
+///
+/// `1.0` means fully segregated, a random mix of `g` equal groups sits near
+/// `1 / g`.  Dense grids wrap toroidally, sparse grids do not, graphs use
+/// their adjacency lists.
+///
+/// ```
+/// use bevy::math::{IVec2, UVec2};
+/// use computational_intelligence::analytics::segregation::segregation_index;
+/// use engine_core::{core::cell::CellState, engine::grid::{DenseGrid, GridBackend}};
+///
+/// // Checkerboard: the four diagonal neighbours agree, the four orthogonal don’t.
+/// let mut g = DenseGrid::blank(UVec2::new(4, 4));
+/// for y in 0..4 {
+///     for x in 0..4 {
+///         g.get_mut(IVec2::new(x, y)).unwrap().state = CellState::Alive(1 + ((x + y) % 2) as u8);
+///     }
+/// }
+/// assert_eq!(segregation_index(&GridBackend::Dense(g)), 0.5);
+/// ```
+pub fn segregation_index(grid: &GridBackend) -> f64 {
+    if let GridBackend::Graph(g) = grid {
+        return mean_like_share(g.cells.iter().enumerate().map(|(i, c)| {
+            (c.state, g.neighbours(i).iter().map(|&j| g.cells[j as usize].state))
+        }));
+    }
+
+    let state_at = |p: IVec2| -> CellState {
+        match grid {
+            GridBackend::Dense(g) => {
+                let s = g.size.as_ivec2();
+                g.get(p.rem_euclid(s)).map_or(CellState::Dead, |c| c.state)
+            }
+            GridBackend::Sparse(g) => g.get(p).map_or(CellState::Dead, |c| c.state),
+            GridBackend::Graph(_)  => CellState::Dead,
+        }
+    };
+    let occupied: Box<dyn Iterator<Item = (IVec2, CellState)>> = match grid {
+        GridBackend::Dense(g)  => Box::new(g.iter().map(|(p, c)| (p, c.state))),
+        GridBackend::Sparse(g) => Box::new(g.iter().map(|(p, c)| (p, c.state))),
+        GridBackend::Graph(_)  => Box::new(std::iter::empty()),
+    };
+    let state_at = &state_at;
+    mean_like_share(occupied.map(|(p, s)| {
+        (s, Dim2::NEIGHBOUR_OFFSETS.iter().map(move |off| state_at(p + *off)))
+    }))
+}
+
+/// Mean like‑neighbour share over `(state, neighbour states)` pairs.
+fn mean_like_share<N: Iterator<Item = CellState>>(cells: impl Iterator<Item = (CellState, N)>) -> f64 {
+    let (mut sum, mut counted) = (0.0, 0usize);
+    for (s, neighbours) in cells.filter(|(s, _)| *s != CellState::Dead) {
+        let (mut like, mut total) = (0u32, 0u32);
+        for n in neighbours {
+            match n {
+                CellState::Dead => {}
+                n => {
+                    total += 1;
+                    if n == s { like += 1; }
+                }
+            }
+        }
+        if total > 0 {
+            sum += like as f64 / total as f64;
+            counted += 1;
+        }
+    }
+    if counted == 0 { 0.0 } else { sum / counted as f64 }
+}
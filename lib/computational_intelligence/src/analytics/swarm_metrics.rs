@@ -104,6 +104,8 @@ pub fn find_clusters(world: &World2D) -> Vec<ClusterStats> {
             }
             cluster_id_map_sparse = Some(cluster_map);
         }
+        // clusters are spatial – graph automata report none
+        engine_core::engine::grid::GridBackend::Graph(_) => {}
     }
     // Second pass: compute internal/external neighbor links for each cluster
     for cluster in clusters.iter_mut() {
@@ -158,6 +160,7 @@ pub fn find_clusters(world: &World2D) -> Vec<ClusterStats> {
                     }
                 }
             }
+            engine_core::engine::grid::GridBackend::Graph(_) => {}
         }
         // Each adjacency between two cells in the same cluster was counted twice (once from each side)
         cluster.internal_links = internal_sum / 2;
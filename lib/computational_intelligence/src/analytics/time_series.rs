@@ -0,0 +1,121 @@
+//! Per‑automaton scalar time series (population, Gini, magnetisation, …).
+//!
+//! Models push one sample per tick under a free‑form name; the HUD and CSV
+//! export read them back.  Each series is a bounded ring buffer so long
+//! runs do not grow without limit.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::Write as _;
+
+use bevy::prelude::*;
+use engine_core::events::AutomatonId;
+
+/// Samples kept per series before the oldest ones are dropped.
+pub const DEFAULT_CAPACITY: usize = 4_096;
+
+#[derive(Clone, Debug)]
+pub struct Series {
+    /// Sample index of `values[0]`.
+    start:    u64,
+    values:   VecDeque<f64>,
+    capacity: usize,
+}
+
+impl Default for Series {
+    fn default() -> Self { Self::with_capacity(DEFAULT_CAPACITY) }
+}
+
+impl Series {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { start: 0, values: VecDeque::with_capacity(capacity), capacity: capacity.max(1) }
+    }
+
+    pub fn push(&mut self, v: f64) {
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+            self.start += 1;
+        }
+        self.values.push_back(v);
+    }
+
+    pub fn len(&self) -> usize { self.values.len() }
+    pub fn is_empty(&self) -> bool { self.values.is_empty() }
+    pub fn last(&self) -> Option<f64> { self.values.back().copied() }
+
+    /// `(sample index, value)` pairs, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, f64)> + '_ {
+        self.values.iter().enumerate().map(|(i, v)| (self.start + i as u64, *v))
+    }
+
+    /// Smallest / largest retained value.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        self.values.iter().fold(None, |acc, &v| match acc {
+            None             => Some((v, v)),
+            Some((lo, hi))   => Some((lo.min(v), hi.max(v))),
+        })
+    }
+}
+
+/// All series, grouped by automaton and ordered by name.
+#[derive(Resource, Default)]
+pub struct TimeSeries {
+    by_automaton: HashMap<AutomatonId, BTreeMap<String, Series>>,
+}
+
+impl TimeSeries {
+    /// Appends `v` to the series `name` of automaton `id` (created on demand).
+    pub fn record(&mut self, id: AutomatonId, name: &str, v: f64) {
+        let series = self.by_automaton.entry(id).or_default();
+        match series.get_mut(name) {
+            Some(s) => s.push(v),
+            None    => {
+                let mut s = Series::default();
+                s.push(v);
+                series.insert(name.to_owned(), s);
+            }
+        }
+    }
+
+    pub fn get(&self, id: AutomatonId, name: &str) -> Option<&Series> {
+        self.by_automaton.get(&id)?.get(name)
+    }
+
+    /// Every series of `id`, sorted by name.
+    pub fn series(&self, id: AutomatonId) -> impl Iterator<Item = (&String, &Series)> {
+        self.by_automaton.get(&id).into_iter().flatten()
+    }
+
+    pub fn remove(&mut self, id: AutomatonId) {
+        self.by_automaton.remove(&id);
+    }
+
+    /// All series of `id` as CSV: a `t` column followed by one column per
+    /// series; cells are empty where a series has no sample.
+    pub fn to_csv(&self, id: AutomatonId) -> String {
+        let Some(series) = self.by_automaton.get(&id) else { return String::new() };
+
+        let mut rows: BTreeMap<u64, Vec<Option<f64>>> = BTreeMap::new();
+        for (col, s) in series.values().enumerate() {
+            for (t, v) in s.iter() {
+                rows.entry(t).or_insert_with(|| vec![None; series.len()])[col] = Some(v);
+            }
+        }
+
+        let mut out = String::from("t");
+        for name in series.keys() {
+            let _ = write!(out, ",{name}");
+        }
+        out.push('\n');
+        for (t, cols) in rows {
+            let _ = write!(out, "{t}");
+            for v in cols {
+                match v {
+                    Some(v) => { let _ = write!(out, ",{v}"); }
+                    None    => out.push(','),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
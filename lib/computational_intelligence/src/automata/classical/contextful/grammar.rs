@@ -0,0 +1,198 @@
+//! Context‑sensitive and monotone grammars over characters, with bounded
+//! derivation search.
+//!
+//! Uppercase letters are nonterminals, every other character a terminal;
+//! productions are written without spaces, `ε` (or nothing) for the empty
+//! word, and the left‑hand side of the first line is the start symbol:
+//!
+//! ```text
+//! S  -> aSBC | aBC
+//! CB -> BC
+//! aB -> ab
+//! bB -> bb
+//! bC -> bc
+//! cC -> cc
+//! ```
+//!
+//! A monotone grammar never shortens a sentential form, so the forms no
+//! longer than `w` are finitely many and searching them decides `w`.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsProduction {
+    pub lhs: String,
+    pub rhs: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsGrammar {
+    pub start:       char,
+    pub productions: Vec<CsProduction>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Derivation {
+    /// Sentential forms from the start symbol to the word, if derivable.
+    pub steps:     Option<Vec<String>>,
+    pub explored:  usize,
+    /// The search hit its limit, so a failure is not conclusive.
+    pub exhausted: bool,
+}
+
+pub fn is_nonterminal(c: char) -> bool { c.is_ascii_uppercase() }
+
+impl CsGrammar {
+    /// Reads the text format (see the [module docs](self)).
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut productions = Vec::new();
+        for (n, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() { continue; }
+            let (lhs, rhs) = line.split_once("->").ok_or(format!("line {}: expected 'α -> β'", n + 1))?;
+            let lhs: String = lhs.split_whitespace().collect();
+            if !lhs.chars().any(is_nonterminal) {
+                return Err(format!("line {}: '{lhs}' has no nonterminal", n + 1));
+            }
+            for alt in rhs.split('|') {
+                let rhs: String = alt.split_whitespace().collect::<String>().replace('ε', "");
+                productions.push(CsProduction { lhs: lhs.clone(), rhs });
+            }
+        }
+        let first = productions.first().ok_or("no productions")?;
+        let mut chars = first.lhs.chars();
+        let start = chars.next().filter(|_| chars.next().is_none()).ok_or("the first left‑hand side must be one nonterminal")?;
+        Ok(Self { start, productions })
+    }
+
+    /// No production shortens, except `S -> ε` with `S` on no right‑hand
+    /// side.
+    pub fn is_monotone(&self) -> bool {
+        let start_on_right = self.productions.iter().any(|p| p.rhs.contains(self.start));
+        self.productions.iter().all(|p| {
+            p.lhs.chars().count() <= p.rhs.chars().count()
+                || (p.rhs.is_empty() && p.lhs == self.start.to_string() && !start_on_right)
+        })
+    }
+
+    /// Every production is `αAβ -> αγβ` with `γ` non‑empty (plus the
+    /// monotone `S -> ε` exception).
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextful::grammar::CsGrammar;
+    ///
+    /// let g = CsGrammar::parse("S -> aSBC | aBC\nCB -> BC\naB -> ab\nbB -> bb\nbC -> bc\ncC -> cc").unwrap();
+    /// assert!(g.is_monotone());
+    /// assert!(!g.is_context_sensitive());    // CB -> BC swaps two nonterminals
+    /// let cs = CsGrammar::parse("S -> aSBC | aBC\nCB -> CZ\nCZ -> WZ\nWZ -> WC\nWC -> BC\naB -> ab\nbB -> bb\nbC -> bc\ncC -> cc").unwrap();
+    /// assert!(cs.is_context_sensitive());
+    /// ```
+    pub fn is_context_sensitive(&self) -> bool {
+        self.is_monotone()
+            && self.productions.iter().all(|p| {
+                if p.rhs.is_empty() { return true; }
+                let (l, r): (Vec<char>, Vec<char>) = (p.lhs.chars().collect(), p.rhs.chars().collect());
+                (0..l.len()).any(|i| {
+                    is_nonterminal(l[i])
+                        && r.len() > l.len() - 1
+                        && r.starts_with(&l[..i])
+                        && r.ends_with(&l[i + 1..])
+                })
+            })
+    }
+
+    /// Forms reachable in one step, rewriting every occurrence of a
+    /// left‑hand side – overlapping ones included.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextful::grammar::CsGrammar;
+    ///
+    /// let g = CsGrammar::parse("S -> AAA\nAA -> bc\nAb -> ab").unwrap();
+    /// let next = g.successors("AAA");
+    /// assert!(next.contains("bcA") && next.contains("Abc"));
+    /// assert!(g.derive("abc", 1_000).steps.is_some());    // S, AAA, Abc, abc
+    /// ```
+    pub fn successors(&self, form: &str) -> BTreeSet<String> {
+        let mut out = BTreeSet::new();
+        for p in &self.productions {
+            for (i, _) in form.char_indices().filter(|&(i, _)| form[i..].starts_with(p.lhs.as_str())) {
+                out.insert(format!("{}{}{}", &form[..i], p.rhs, &form[i + p.lhs.len()..]));
+            }
+        }
+        out
+    }
+
+    /// Breadth‑first search for a derivation of `word` over forms no longer
+    /// than it, exploring at most `limit` forms.  Complete for monotone
+    /// grammars.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextful::grammar::CsGrammar;
+    ///
+    /// let g = CsGrammar::parse("S -> aSBC | aBC\nCB -> BC\naB -> ab\nbB -> bb\nbC -> bc\ncC -> cc").unwrap();
+    /// let d = g.derive("aabbcc", 100_000);
+    /// let steps = d.steps.unwrap();
+    /// assert_eq!(steps.first().unwrap(), "S");
+    /// assert_eq!(steps.last().unwrap(), "aabbcc");
+    /// assert!(g.derive("aabbc", 100_000).steps.is_none());
+    /// assert!(!g.derive("abcabc", 100_000).exhausted);
+    /// ```
+    pub fn derive(&self, word: &str, limit: usize) -> Derivation {
+        let bound = word.chars().count().max(1);
+        let start = self.start.to_string();
+        let mut parent: HashMap<String, Option<String>> = HashMap::from([(start.clone(), None)]);
+        let mut queue = VecDeque::from([start]);
+        let mut explored = 0;
+        while let Some(form) = queue.pop_front() {
+            explored += 1;
+            if form == word {
+                let mut steps = vec![form];
+                while let Some(Some(p)) = parent.get(steps.last().expect("non-empty")) {
+                    steps.push(p.clone());
+                }
+                steps.reverse();
+                return Derivation { steps: Some(steps), explored, exhausted: false };
+            }
+            if explored >= limit {
+                return Derivation { steps: None, explored, exhausted: true };
+            }
+            for next in self.successors(&form) {
+                if next.chars().count() <= bound && !parent.contains_key(&next) {
+                    parent.insert(next.clone(), Some(form.clone()));
+                    queue.push_back(next);
+                }
+            }
+        }
+        Derivation { steps: None, explored, exhausted: false }
+    }
+
+    /// Every terminal word of length at most `max_len`, from a search of at
+    /// most `limit` forms.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextful::grammar::CsGrammar;
+    ///
+    /// let g = CsGrammar::parse("S -> aSBC | aBC\nCB -> BC\naB -> ab\nbB -> bb\nbC -> bc\ncC -> cc").unwrap();
+    /// let words: Vec<String> = g.language(9, 1_000_000).into_iter().collect();
+    /// assert_eq!(words, ["aaabbbccc", "aabbcc", "abc"]);
+    /// ```
+    pub fn language(&self, max_len: usize, limit: usize) -> BTreeSet<String> {
+        let start = self.start.to_string();
+        let mut seen = BTreeSet::from([start.clone()]);
+        let mut queue = VecDeque::from([start]);
+        let mut words = BTreeSet::new();
+        while let Some(form) = queue.pop_front() {
+            if seen.len() > limit { break; }
+            if !form.chars().any(is_nonterminal) {
+                words.insert(form);
+                continue;
+            }
+            for next in self.successors(&form) {
+                if next.chars().count() <= max_len && seen.insert(next.clone()) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        words
+    }
+}
@@ -0,0 +1,234 @@
+//! General (nondeterministic) linear‑bounded automata: Turing machines
+//! confined to their input between the end markers `<` and `>`.
+//!
+//! Text format, one line each, `#` comments:
+//!
+//! ```text
+//! start  q0
+//! accept qA
+//! q0 a -> X R q1            # state read -> write move next
+//! q4 > -> > S qA            # moves: L, S(tay), R
+//! ```
+//!
+//! The markers may be read but not overwritten, and the head never moves
+//! past them.  Several lines for one `(state, symbol)` make the machine
+//! nondeterministic.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, VecDeque},
+    fmt,
+};
+
+use crate::automata::classical::tape::{intern, single};
+
+pub const LEFT_END: char  = '<';
+pub const RIGHT_END: char = '>';
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Move { Left, Stay, Right }
+
+impl Move {
+    pub fn letter(self) -> char {
+        match self {
+            Move::Left  => 'L',
+            Move::Stay  => 'S',
+            Move::Right => 'R',
+        }
+    }
+
+    pub fn from_letter(c: &str) -> Option<Self> {
+        match c {
+            "L" => Some(Move::Left),
+            "S" => Some(Move::Stay),
+            "R" => Some(Move::Right),
+            _   => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LbaTransition {
+    pub write:    char,
+    pub movement: Move,
+    pub to:       usize,
+}
+
+/// State, head position and tape including both markers.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LbaConfig {
+    pub state: usize,
+    pub head:  usize,
+    pub tape:  Vec<char>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LbaRun {
+    pub accepted:  bool,
+    /// Shortest accepting computation, when one was found.
+    pub trace:     Option<Vec<LbaConfig>>,
+    pub explored:  usize,
+    /// The search hit its limit, so a rejection is not conclusive.
+    pub exhausted: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LBA {
+    pub names:  Vec<String>,
+    pub start:  usize,
+    pub accept: BTreeSet<usize>,
+    pub delta:  BTreeMap<(usize, char), Vec<LbaTransition>>,
+}
+
+impl LBA {
+    /// Index of state `name`; a new name becomes a new state.
+    pub fn state(&mut self, name: &str) -> usize { intern(&mut self.names, name) }
+
+    pub fn add_transition(&mut self, from: usize, read: char, t: LbaTransition) -> Result<(), String> {
+        let marker_ok = match read {
+            LEFT_END  => t.write == LEFT_END && t.movement != Move::Left,
+            RIGHT_END => t.write == RIGHT_END && t.movement != Move::Right,
+            _         => t.write != LEFT_END && t.write != RIGHT_END,
+        };
+        if !marker_ok {
+            return Err(format!("'{read}' -> '{}' {:?} would break the end markers", t.write, t.movement));
+        }
+        self.delta.entry((from, read)).or_default().push(t);
+        Ok(())
+    }
+
+    /// Reads the text format (see the [module docs](self)).
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut m = LBA::default();
+        let mut start = None;
+        for (n, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() { continue; }
+            let err = |e: &str| format!("line {}: {e}", n + 1);
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                ["start", q] => start = Some(m.state(q)),
+                ["accept", qs @ ..] => {
+                    for q in qs {
+                        let q = m.state(q);
+                        m.accept.insert(q);
+                    }
+                }
+                [q, read, "->", write, mv, to] => {
+                    let (Some(read), Some(write)) = (single(read), single(write)) else {
+                        return Err(err("symbols are single characters"));
+                    };
+                    let movement = Move::from_letter(mv).ok_or(err("move is L, S or R"))?;
+                    let (from, to) = (m.state(q), m.state(to));
+                    m.add_transition(from, read, LbaTransition { write, movement, to }).map_err(|e| err(&e))?;
+                }
+                _ => return Err(err("expected 'start q', 'accept q…' or 'q a -> b M r'")),
+            }
+        }
+        m.start = start.ok_or("missing 'start'")?;
+        Ok(m)
+    }
+
+    pub fn is_deterministic(&self) -> bool { self.delta.values().all(|ts| ts.len() <= 1) }
+
+    /// `<input>` with the head on the first input symbol.
+    pub fn initial(&self, input: &str) -> LbaConfig {
+        let tape = std::iter::once(LEFT_END).chain(input.chars()).chain(std::iter::once(RIGHT_END)).collect();
+        LbaConfig { state: self.start, head: 1, tape }
+    }
+
+    pub fn successors(&self, c: &LbaConfig) -> Vec<LbaConfig> {
+        if self.accept.contains(&c.state) { return Vec::new(); }
+        let Some(ts) = self.delta.get(&(c.state, c.tape[c.head])) else { return Vec::new() };
+        ts.iter()
+            .map(|t| {
+                let mut tape = c.tape.clone();
+                tape[c.head] = t.write;
+                let head = match t.movement {
+                    Move::Left  => c.head - 1,
+                    Move::Stay  => c.head,
+                    Move::Right => c.head + 1,
+                };
+                LbaConfig { state: t.to, head, tape }
+            })
+            .collect()
+    }
+
+    /// The first successor – the only one for deterministic machines.
+    pub fn step(&self, c: &LbaConfig) -> Option<LbaConfig> { self.successors(c).into_iter().next() }
+
+    /// Breadth‑first search of the (finite) configuration graph, exploring
+    /// at most `limit` configurations.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextful::lba::LBA;
+    /// use computational_intelligence::automata::classical::contextful::ANBNCN_LBA;
+    ///
+    /// let m = LBA::parse(ANBNCN_LBA).unwrap();
+    /// assert!(m.is_deterministic());
+    /// for (w, ok) in [("abc", true), ("aabbcc", true), ("aabbc", false), ("abcabc", false), ("", false)] {
+    ///     let run = m.run(w, 100_000);
+    ///     assert_eq!(run.accepted, ok, "{w}");
+    ///     assert!(!run.exhausted);
+    /// }
+    /// ```
+    pub fn run(&self, input: &str, limit: usize) -> LbaRun {
+        let init = self.initial(input);
+        let mut parent: HashMap<LbaConfig, Option<LbaConfig>> = HashMap::from([(init.clone(), None)]);
+        let mut queue = VecDeque::from([init]);
+        let mut explored = 0;
+        while let Some(c) = queue.pop_front() {
+            explored += 1;
+            if self.accept.contains(&c.state) {
+                let mut trace = vec![c.clone()];
+                while let Some(Some(p)) = parent.get(trace.last().expect("non-empty")) {
+                    trace.push(p.clone());
+                }
+                trace.reverse();
+                return LbaRun { accepted: true, trace: Some(trace), explored, exhausted: false };
+            }
+            if explored >= limit {
+                return LbaRun { accepted: false, trace: None, explored, exhausted: true };
+            }
+            for next in self.successors(&c) {
+                if !parent.contains_key(&next) {
+                    parent.insert(next.clone(), Some(c.clone()));
+                    queue.push_back(next);
+                }
+            }
+        }
+        LbaRun { accepted: false, trace: None, explored, exhausted: false }
+    }
+
+    /// Every symbol the machine reads or writes, markers excluded.
+    pub fn tape_alphabet(&self) -> BTreeSet<char> {
+        self.delta
+            .iter()
+            .flat_map(|((_, r), ts)| std::iter::once(*r).chain(ts.iter().map(|t| t.write)))
+            .filter(|c| *c != LEFT_END && *c != RIGHT_END)
+            .collect()
+    }
+
+    /// `state: tape` with the head symbol bracketed, e.g. `q1: <X[a]bc>`.
+    pub fn describe(&self, c: &LbaConfig) -> String {
+        let tape: String = c.tape.iter().enumerate()
+            .map(|(i, s)| if i == c.head { format!("[{s}]") } else { s.to_string() })
+            .collect();
+        format!("{}: {tape}", self.names[c.state])
+    }
+}
+
+impl fmt::Display for LBA {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "start {}", self.names[self.start])?;
+        if !self.accept.is_empty() {
+            let names: Vec<&str> = self.accept.iter().map(|&q| self.names[q].as_str()).collect();
+            writeln!(f, "accept {}", names.join(" "))?;
+        }
+        for ((q, read), ts) in &self.delta {
+            for t in ts {
+                writeln!(f, "{} {read} -> {} {} {}", self.names[*q], t.write, t.movement.letter(), self.names[t.to])?;
+            }
+        }
+        Ok(())
+    }
+}
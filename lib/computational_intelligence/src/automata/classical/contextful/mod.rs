@@ -1,14 +1,224 @@
 //! Type‑1 **Context‑Sensitive** automata (linear‑bounded automata).
+//!
+//! [`lba`] holds general nondeterministic LBAs and [`grammar`] monotone and
+//! context‑sensitive grammars with bounded derivation search.  Spawned as
+//! automata, the presets run their machine on `input` and draw the tape
+//! one row per step:
+//!
+//! * `lba:anbncn`  – marks one `a`, `b`, `c` per sweep to decide aⁿbⁿcⁿ;
+//! * `lba:counter` – counts in binary across its input until it overflows.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::tape::{TapeAlphabet, TapeHistory, TapeRow, TapeSession, TapeSessionPlugin, TapeSessions};
+use lba::{LbaConfig, LBA, LEFT_END, RIGHT_END};
 
 pub struct ContextSensitiveAutomataPlugin;
 
 impl Plugin for ContextSensitiveAutomataPlugin {
-    fn build(&self, _app: &mut App) {
-        // TODO: add LBA rules
+    fn build(&self, app: &mut App) {
+        app.add_plugins(TapeSessionPlugin::<LbaSession>::default());
     }
 }
 
-/* ───── sub‑modules (stubs) ───── */
+/* ───── sub‑modules ───── */
+pub mod grammar;
+pub mod lba;
 pub mod lba_examples;
+
+pub const LBA_ANBNCN_ID: &str  = "lba:anbncn";
+pub const LBA_COUNTER_ID: &str = "lba:counter";
+
+/// Decides aⁿbⁿcⁿ, n ≥ 1: each sweep turns one `a`, `b`, `c` into `X`, `Y`,
+/// `Z`, then the tail must be all marks.
+pub const ANBNCN_LBA: &str = "\
+start q0
+accept qA
+q0 a -> X R q1
+q0 Y -> Y R q4
+q1 a -> a R q1
+q1 Y -> Y R q1
+q1 b -> Y R q2
+q2 b -> b R q2
+q2 Z -> Z R q2
+q2 c -> Z L q3
+q3 a -> a L q3
+q3 b -> b L q3
+q3 Y -> Y L q3
+q3 Z -> Z L q3
+q3 X -> X R q0
+q4 Y -> Y R q4
+q4 Z -> Z R q4
+q4 > -> > S qA";
+
+/// Adds one to the binary number on the tape (least significant bit on the
+/// right) until every bit wraps to `0`.
+pub const COUNTER_LBA: &str = "\
+start go
+accept done
+go 0 -> 0 R go
+go 1 -> 1 R go
+go > -> > L add
+add 1 -> 0 L add
+add 0 -> 1 L back
+add < -> < S done
+back 0 -> 0 L back
+back 1 -> 1 L back
+back < -> < R go";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LbaParams {
+    /// The machine in the [`lba`] text format.
+    pub machine:        String,
+    pub input:          String,
+    pub steps_per_tick: u32,
+    /// Steps before the run is given up as undecided.
+    pub max_steps:      u64,
+}
+
+impl Default for LbaParams {
+    fn default() -> Self {
+        Self {
+            machine:        ANBNCN_LBA.into(),
+            input:          "aaaabbbbcccc".into(),
+            steps_per_tick: 1,
+            max_steps:      1_000_000,
+        }
+    }
+}
+
+impl LbaParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+
+    /// Every preset id with its defaults.
+    pub fn presets() -> [(&'static str, Self); 2] {
+        [
+            (LBA_ANBNCN_ID, Self::default()),
+            (LBA_COUNTER_ID, Self { machine: COUNTER_LBA.into(), input: "000000".into(), ..default() }),
+        ]
+    }
+}
+
+/// A machine being run on its input, with its tape history.
+#[derive(Clone, Debug)]
+pub struct LbaSession {
+    pub machine:   LBA,
+    pub config:    LbaConfig,
+    /// Symbol codes: markers first, then the tape alphabet.
+    pub alphabet:  TapeAlphabet,
+    pub history:   TapeHistory,
+    /// `Some(accepted)` once the machine has halted.
+    pub halted:    Option<bool>,
+    pub steps:     u64,
+    pub max_steps: u64,
+    /// Set when `max_steps` ran out before the machine halted.
+    pub undecided: bool,
+}
+
+impl LbaSession {
+    /// Keeps the last `rows` tapes.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextful::{LbaParams, LbaSession};
+    ///
+    /// let mut s = LbaSession::new(&LbaParams { input: "aabbcc".into(), ..Default::default() }, 64).unwrap();
+    /// while s.advance(1) {}
+    /// assert_eq!(s.halted, Some(true));
+    /// assert_eq!(s.history.rows.len() as u64, s.steps + 1);
+    ///
+    /// // A machine that never moves is given up on.
+    /// let stuck = LbaParams { machine: "start q0\nq0 a -> a S q0".into(), input: "a".into(), max_steps: 50, ..Default::default() };
+    /// let mut s = LbaSession::new(&stuck, 64).unwrap();
+    /// while s.advance(1) {}
+    /// assert!(s.undecided && s.halted.is_none());
+    /// ```
+    pub fn new(p: &LbaParams, rows: usize) -> Result<Self, String> {
+        let machine = LBA::parse(&p.machine)?;
+        if p.input.contains([LEFT_END, RIGHT_END]) {
+            return Err("the input may not contain the end markers".into());
+        }
+        let markers  = [LEFT_END, RIGHT_END];
+        let alphabet = TapeAlphabet::new(markers.into_iter().chain(machine.tape_alphabet()).chain(p.input.chars()), None)?;
+        let config = machine.initial(&p.input);
+        let mut s = Self {
+            machine,
+            config,
+            alphabet,
+            history:   TapeHistory::new(rows),
+            halted:    None,
+            steps:     0,
+            max_steps: p.max_steps,
+            undecided: false,
+        };
+        s.history.push(s.row());
+        Ok(s)
+    }
+
+    pub fn row(&self) -> TapeRow {
+        self.alphabet.row(0, &self.config.tape, self.config.head as i64)
+    }
+
+    /// Up to `steps` moves (the first choice where nondeterministic).
+    /// False once halted or out of steps.
+    pub fn advance(&mut self, steps: u32) -> bool {
+        for _ in 0..steps {
+            if self.halted.is_some() || self.undecided { return false; }
+            if self.machine.accept.contains(&self.config.state) {
+                self.halted = Some(true);
+                return false;
+            }
+            if self.steps >= self.max_steps {
+                self.undecided = true;
+                return false;
+            }
+            match self.machine.step(&self.config) {
+                Some(next) => {
+                    self.config = next;
+                    self.steps += 1;
+                    self.history.push(self.row());
+                }
+                None => {
+                    self.halted = Some(false);
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl TapeSession for LbaSession {
+    type Params = LbaParams;
+
+    const KIND: &'static str = "LBA";
+
+    fn presets() -> Vec<(&'static str, LbaParams)> { LbaParams::presets().into() }
+    fn start(p: &LbaParams, rows: usize) -> Result<Self, String> { Self::new(p, rows) }
+    fn steps_per_tick(p: &LbaParams) -> u32 { p.steps_per_tick }
+    fn advance(&mut self, steps: u32) -> bool { self.advance(steps) }
+    fn steps(&self) -> u64 { self.steps }
+    fn history(&self) -> &TapeHistory { &self.history }
+    fn head(&self) -> i64 { self.config.head as i64 }
+
+    /// A nondeterministic machine only rejects on this run: the session
+    /// follows the first choice, so other runs may still accept.
+    fn outcome(&self) -> Option<String> {
+        if self.undecided {
+            return Some(format!("undecided after {} steps", self.steps));
+        }
+        let verdict = match self.halted? {
+            true                                     => "accepted",
+            false if self.machine.is_deterministic() => "rejected",
+            false                                    => "this run rejected",
+        };
+        Some(format!("{verdict} after {} steps", self.steps))
+    }
+}
+
+/// LBAs of every spawned preset.
+pub type LbaSessions = TapeSessions<LbaSession>;
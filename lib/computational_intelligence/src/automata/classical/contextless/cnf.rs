@@ -0,0 +1,183 @@
+//! Chomsky normal form: every production is `A -> B C`, `A -> a`, or
+//! `S -> ε` for the start symbol `S`, which never appears on a right‑hand
+//! side.
+//!
+//! The conversion runs the usual START, TERM, BIN, DEL, UNIT passes and then
+//! drops useless symbols.  Fresh nonterminals are named after what they
+//! replace (`S0`, `T_a`, `A_1` …), with `'` appended on a clash.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use super::grammar::{Grammar, Production, Symbol};
+
+impl Grammar {
+    pub fn is_cnf(&self) -> bool {
+        self.productions.iter().all(|p| match p.rhs.as_slice() {
+            []                                               => p.lhs == self.start,
+            [Symbol::Terminal(_)]                            => true,
+            [Symbol::Nonterminal(b), Symbol::Nonterminal(c)] => *b != self.start && *c != self.start,
+            _                                                => false,
+        })
+    }
+
+    /// An equivalent grammar in Chomsky normal form.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::grammar::Grammar;
+    ///
+    /// let g = Grammar::parse("S -> a S b | ε").unwrap();
+    /// let cnf = g.to_cnf();
+    /// assert!(cnf.is_cnf());
+    /// assert_eq!(cnf.to_string(), "\
+    /// S0 -> ε
+    /// S0 -> T_a S_1
+    /// S -> T_a S_1
+    /// S_1 -> S T_b
+    /// S_1 -> b
+    /// T_a -> a
+    /// T_b -> b
+    /// ");
+    /// ```
+    pub fn to_cnf(&self) -> Grammar {
+        let mut used: BTreeSet<String> = self.nonterminals().into_iter().chain(self.terminals()).collect();
+        let mut fresh = |base: String| {
+            let mut name = base;
+            while !used.insert(name.clone()) {
+                name.push('\'');
+            }
+            name
+        };
+
+        /* START */
+        let start = fresh(format!("{}0", self.start));
+        let mut prods = vec![Production { lhs: start.clone(), rhs: vec![Symbol::Nonterminal(self.start.clone())] }];
+        prods.extend(self.productions.iter().cloned());
+
+        /* TERM */
+        let mut term_nt: BTreeMap<String, String> = BTreeMap::new();
+        let mut term_prods = Vec::new();
+        for p in &mut prods {
+            if p.rhs.len() < 2 { continue; }
+            for s in &mut p.rhs {
+                let Symbol::Terminal(a) = s else { continue };
+                let nt = term_nt.entry(a.clone()).or_insert_with(|| {
+                    let nt = fresh(format!("T_{a}"));
+                    term_prods.push(Production { lhs: nt.clone(), rhs: vec![Symbol::Terminal(a.clone())] });
+                    nt
+                });
+                *s = Symbol::Nonterminal(nt.clone());
+            }
+        }
+        prods.extend(term_prods);
+
+        /* BIN */
+        let mut binary = Vec::new();
+        for p in prods {
+            if p.rhs.len() <= 2 {
+                binary.push(p);
+                continue;
+            }
+            let mut lhs = p.lhs.clone();
+            let last = p.rhs.len() - 2;
+            for (k, s) in p.rhs[..last].iter().enumerate() {
+                let next = fresh(format!("{}_{}", p.lhs, k + 1));
+                binary.push(Production { lhs, rhs: vec![s.clone(), Symbol::Nonterminal(next.clone())] });
+                lhs = next;
+            }
+            binary.push(Production { lhs, rhs: p.rhs[last..].to_vec() });
+        }
+
+        /* DEL */
+        let nullable = Grammar { start: start.clone(), productions: binary.clone() }.nullable();
+        let mut seen = HashSet::new();
+        let mut no_eps = Vec::new();
+        for p in &binary {
+            let optional: Vec<bool> = p.rhs.iter()
+                .map(|s| matches!(s, Symbol::Nonterminal(n) if nullable.contains(n)))
+                .collect();
+            for mask in 0..1u32 << p.rhs.len() {
+                if (0..p.rhs.len()).any(|i| mask & 1 << i != 0 && !optional[i]) { continue; }
+                let rhs: Vec<Symbol> = p.rhs.iter().enumerate()
+                    .filter(|(i, _)| mask & 1 << i == 0)
+                    .map(|(_, s)| s.clone())
+                    .collect();
+                if rhs.is_empty() && p.lhs != start { continue; }
+                if seen.insert((p.lhs.clone(), rhs.clone())) {
+                    no_eps.push(Production { lhs: p.lhs.clone(), rhs });
+                }
+            }
+        }
+
+        /* UNIT */
+        let unit_of = |p: &Production| match p.rhs.as_slice() {
+            [Symbol::Nonterminal(b)] => Some(b.clone()),
+            _                        => None,
+        };
+        let lhs_order: Vec<String> = no_eps.iter().fold(Vec::new(), |mut v, p| {
+            if !v.contains(&p.lhs) { v.push(p.lhs.clone()); }
+            v
+        });
+        let mut seen = HashSet::new();
+        let mut prods = Vec::new();
+        for a in &lhs_order {
+            let mut closure = vec![a.clone()];
+            let mut k = 0;
+            while k < closure.len() {
+                let from = closure[k].clone();
+                for b in no_eps.iter().filter(|p| p.lhs == from).filter_map(unit_of) {
+                    if !closure.contains(&b) { closure.push(b); }
+                }
+                k += 1;
+            }
+            for b in &closure {
+                for p in no_eps.iter().filter(|p| p.lhs == *b && unit_of(p).is_none()) {
+                    if seen.insert((a.clone(), p.rhs.clone())) {
+                        prods.push(Production { lhs: a.clone(), rhs: p.rhs.clone() });
+                    }
+                }
+            }
+        }
+
+        Grammar { start, productions: prods }.without_useless()
+    }
+
+    /// Drops nonterminals that derive no terminal string or are unreachable
+    /// from the start symbol, with every production mentioning them.
+    pub fn without_useless(&self) -> Grammar {
+        let mut generating = BTreeSet::new();
+        loop {
+            let before = generating.len();
+            for p in &self.productions {
+                if p.rhs.iter().all(|s| match s {
+                    Symbol::Terminal(_)    => true,
+                    Symbol::Nonterminal(n) => generating.contains(n),
+                }) {
+                    generating.insert(p.lhs.clone());
+                }
+            }
+            if generating.len() == before { break; }
+        }
+        let productive = |p: &&Production| {
+            generating.contains(&p.lhs)
+                && p.rhs.iter().all(|s| !matches!(s, Symbol::Nonterminal(n) if !generating.contains(n)))
+        };
+        let kept: Vec<&Production> = self.productions.iter().filter(productive).collect();
+
+        let mut reachable = BTreeSet::from([self.start.clone()]);
+        loop {
+            let before = reachable.len();
+            let found: Vec<String> = kept
+                .iter()
+                .filter(|p| reachable.contains(&p.lhs))
+                .flat_map(|p| &p.rhs)
+                .filter_map(|s| match s { Symbol::Nonterminal(n) => Some(n.clone()), _ => None })
+                .collect();
+            reachable.extend(found);
+            if reachable.len() == before { break; }
+        }
+        Grammar {
+            start:       self.start.clone(),
+            productions: kept.into_iter().filter(|p| reachable.contains(&p.lhs)).cloned().collect(),
+        }
+    }
+}
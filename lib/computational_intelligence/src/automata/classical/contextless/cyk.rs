@@ -0,0 +1,150 @@
+//! CYK recognition over a grammar in Chomsky normal form, with parse‑tree
+//! extraction and tree counting.
+//!
+//! Trees are those of the CNF grammar.  [`Grammar::to_cnf`] merges
+//! duplicate productions and folds away unit and ε‑rules, so an ambiguous
+//! grammar can have a single CNF tree (`S -> A | B; A -> a; B -> a` on
+//! `a`); ask [`EarleyChart`](super::earley::EarleyChart) about the
+//! original grammar’s ambiguity.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::grammar::{Grammar, ParseTree, Symbol};
+
+/// How a nonterminal covers a span: production `production`, split after
+/// `left` tokens (0 for `A -> a`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Split {
+    pub production: usize,
+    pub left:       usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct CykChart<'g> {
+    pub grammar: &'g Grammar,
+    pub tokens:  Vec<String>,
+    /// `cells[i][l - 1]`: nonterminals deriving `tokens[i .. i + l]`.
+    pub cells:   Vec<Vec<BTreeMap<String, Vec<Split>>>>,
+}
+
+impl<'g> CykChart<'g> {
+    /// Fills the chart; `grammar` must be in CNF (see [`Grammar::to_cnf`]).
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::{cyk::CykChart, grammar::Grammar};
+    ///
+    /// // the textbook ambiguous expression grammar
+    /// let g = Grammar::parse("E -> E + E | E * E | ( E ) | id").unwrap().to_cnf();
+    /// let tokens = g.tokenize("id + id * id");
+    /// let chart = CykChart::new(&g, &tokens).unwrap();
+    /// assert!(chart.accepts());
+    /// assert_eq!(chart.count_cnf_trees(), 2);
+    /// assert!(chart.cnf_ambiguous());
+    /// for t in chart.trees(10) {
+    ///     assert_eq!(t.leaves(), ["id", "+", "id", "*", "id"]);
+    /// }
+    ///
+    /// assert!(!CykChart::new(&g, &g.tokenize("id + * id")).unwrap().accepts());
+    /// assert!(CykChart::new(&Grammar::parse("S -> a S b | ε").unwrap(), &[]).is_err());
+    /// ```
+    pub fn new(grammar: &'g Grammar, tokens: &[String]) -> Result<Self, String> {
+        if !grammar.is_cnf() {
+            return Err("grammar is not in Chomsky normal form".into());
+        }
+        let n = tokens.len();
+        let mut cells: Vec<Vec<BTreeMap<String, Vec<Split>>>> =
+            (0..n).map(|i| vec![BTreeMap::new(); n - i]).collect();
+        for (i, tok) in tokens.iter().enumerate() {
+            for (p, prod) in grammar.productions.iter().enumerate() {
+                if matches!(prod.rhs.as_slice(), [Symbol::Terminal(a)] if a == tok) {
+                    cells[i][0].entry(prod.lhs.clone()).or_default().push(Split { production: p, left: 0 });
+                }
+            }
+        }
+        for len in 2..=n {
+            for i in 0..=n - len {
+                for left in 1..len {
+                    for (p, prod) in grammar.productions.iter().enumerate() {
+                        let [Symbol::Nonterminal(b), Symbol::Nonterminal(c)] = prod.rhs.as_slice() else { continue };
+                        if cells[i][left - 1].contains_key(b) && cells[i + left][len - left - 1].contains_key(c) {
+                            cells[i][len - 1].entry(prod.lhs.clone()).or_default().push(Split { production: p, left });
+                        }
+                    }
+                }
+            }
+        }
+        Ok(Self { grammar, tokens: tokens.to_vec(), cells })
+    }
+
+    fn epsilon(&self) -> Option<usize> {
+        self.grammar.productions.iter().position(|p| p.lhs == self.grammar.start && p.rhs.is_empty())
+    }
+
+    pub fn accepts(&self) -> bool {
+        match self.tokens.len() {
+            0 => self.epsilon().is_some(),
+            n => self.cells[0][n - 1].contains_key(&self.grammar.start),
+        }
+    }
+
+    /// Number of CNF parse trees (saturating).
+    pub fn count_cnf_trees(&self) -> u64 {
+        match self.tokens.len() {
+            0 => u64::from(self.epsilon().is_some()),
+            n => self.count(&self.grammar.start, 0, n, &mut HashMap::new()),
+        }
+    }
+
+    /// More than one CNF parse tree; says nothing about the grammar it was
+    /// converted from.
+    pub fn cnf_ambiguous(&self) -> bool { self.count_cnf_trees() > 1 }
+
+    fn count(&self, a: &str, i: usize, len: usize, memo: &mut HashMap<(String, usize, usize), u64>) -> u64 {
+        if let Some(&c) = memo.get(&(a.to_string(), i, len)) { return c; }
+        let mut total = 0u64;
+        for s in self.cells[i][len - 1].get(a).into_iter().flatten() {
+            let ways = match self.grammar.productions[s.production].rhs.as_slice() {
+                [Symbol::Nonterminal(b), Symbol::Nonterminal(c)] => self
+                    .count(b, i, s.left, memo)
+                    .saturating_mul(self.count(c, i + s.left, len - s.left, memo)),
+                _ => 1,
+            };
+            total = total.saturating_add(ways);
+        }
+        memo.insert((a.to_string(), i, len), total);
+        total
+    }
+
+    /// Up to `limit` parse trees over the CNF grammar.
+    pub fn trees(&self, limit: usize) -> Vec<ParseTree> {
+        match (self.tokens.len(), self.epsilon()) {
+            (0, Some(p)) => vec![ParseTree::Node { nonterminal: self.grammar.start.clone(), production: p, children: vec![] }],
+            (0, None)    => vec![],
+            (n, _)       => self.trees_of(&self.grammar.start, 0, n, limit),
+        }
+    }
+
+    fn trees_of(&self, a: &str, i: usize, len: usize, limit: usize) -> Vec<ParseTree> {
+        let mut out = Vec::new();
+        for s in self.cells[i][len - 1].get(a).into_iter().flatten() {
+            let node = |children| ParseTree::Node { nonterminal: a.to_string(), production: s.production, children };
+            match self.grammar.productions[s.production].rhs.as_slice() {
+                [Symbol::Nonterminal(b), Symbol::Nonterminal(c)] => {
+                    let rights = self.trees_of(c, i + s.left, len - s.left, limit);
+                    for l in self.trees_of(b, i, s.left, limit) {
+                        for r in &rights {
+                            if out.len() == limit { return out; }
+                            out.push(node(vec![l.clone(), r.clone()]));
+                        }
+                    }
+                }
+                _ => out.push(node(vec![ParseTree::Leaf(self.tokens[i].clone())])),
+            }
+            if out.len() >= limit {
+                out.truncate(limit);
+                return out;
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,282 @@
+//! Earley parsing for arbitrary context‑free grammars – left recursion,
+//! ε‑productions and cycles included – with parse‑tree extraction and
+//! ambiguity detection.
+//!
+//! Nullable nonterminals are skipped over at prediction time (Aycock &
+//! Horspool), so no separate ε‑completion pass is needed.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use super::grammar::{Grammar, ParseTree, Symbol};
+
+/// `production` with the dot before `rhs[dot]`, started at token `origin`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Item {
+    pub production: usize,
+    pub dot:        usize,
+    pub origin:     usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct EarleyChart<'g> {
+    pub grammar: &'g Grammar,
+    pub tokens:  Vec<String>,
+    /// `sets[k]`: items after reading `k` tokens, in insertion order.
+    pub sets:    Vec<Vec<Item>>,
+    /// `(nonterminal, from, to)` for every completed item.
+    spans:       HashSet<(String, usize, usize)>,
+}
+
+/// Node state while counting trees.
+#[derive(Clone, Copy)]
+enum Visit {
+    Open,
+    Done(Option<u64>),
+}
+
+impl<'g> EarleyChart<'g> {
+    /// Runs the recogniser over `tokens`.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::{earley::EarleyChart, grammar::Grammar};
+    ///
+    /// // left‑recursive and unambiguous
+    /// let g = Grammar::parse("E -> E + T | T\nT -> T * F | F\nF -> ( E ) | id").unwrap();
+    /// let chart = EarleyChart::parse(&g, &g.tokenize("id * ( id + id )"));
+    /// assert!(chart.accepts());
+    /// assert_eq!(chart.count_trees(), Some(1));
+    /// assert_eq!(
+    ///     chart.trees(5)[0].to_string(),
+    ///     "(E (T (T (F id)) * (F ( (E (E (T (F id))) + (T (F id))) ))))",
+    /// );
+    /// assert!(!EarleyChart::parse(&g, &g.tokenize("id +")).accepts());
+    ///
+    /// // dangling else: two readings
+    /// let g = Grammar::parse("S -> if b then S | if b then S else S | x").unwrap();
+    /// let chart = EarleyChart::parse(&g, &g.tokenize("if b then if b then x else x"));
+    /// assert!(chart.is_ambiguous());
+    /// assert_eq!(chart.trees(10).len(), 2);
+    ///
+    /// // ambiguities the CNF conversion would merge away
+    /// for g in ["S -> A | B\nA -> a\nB -> a", "S -> A A\nA -> a | ε"] {
+    ///     let g = Grammar::parse(g).unwrap();
+    ///     assert_eq!(EarleyChart::parse(&g, &g.tokenize("a")).count_trees(), Some(2));
+    /// }
+    ///
+    /// // a cycle gives infinitely many trees
+    /// let g = Grammar::parse("S -> S | a").unwrap();
+    /// let chart = EarleyChart::parse(&g, &g.tokenize("a"));
+    /// assert_eq!(chart.count_trees(), None);
+    /// assert_eq!(chart.trees(10).len(), 1);
+    /// ```
+    pub fn parse(grammar: &'g Grammar, tokens: &[String]) -> Self {
+        let nullable = grammar.nullable();
+        let n = tokens.len();
+        let mut sets: Vec<Vec<Item>> = vec![Vec::new(); n + 1];
+        let mut seen: Vec<HashSet<Item>> = vec![HashSet::new(); n + 1];
+        let add = |k: usize, item: Item, sets: &mut Vec<Vec<Item>>, seen: &mut Vec<HashSet<Item>>| {
+            if seen[k].insert(item) {
+                sets[k].push(item);
+            }
+        };
+        for (p, prod) in grammar.productions.iter().enumerate() {
+            if prod.lhs == grammar.start {
+                add(0, Item { production: p, dot: 0, origin: 0 }, &mut sets, &mut seen);
+            }
+        }
+        for k in 0..=n {
+            let mut idx = 0;
+            while idx < sets[k].len() {
+                let item = sets[k][idx];
+                idx += 1;
+                let rhs = &grammar.productions[item.production].rhs;
+                match rhs.get(item.dot) {
+                    /* predict */
+                    Some(Symbol::Nonterminal(b)) => {
+                        for (p, prod) in grammar.productions.iter().enumerate() {
+                            if prod.lhs == *b {
+                                add(k, Item { production: p, dot: 0, origin: k }, &mut sets, &mut seen);
+                            }
+                        }
+                        if nullable.contains(b) {
+                            add(k, Item { dot: item.dot + 1, ..item }, &mut sets, &mut seen);
+                        }
+                    }
+                    /* scan */
+                    Some(Symbol::Terminal(t)) => {
+                        if k < n && tokens[k] == *t {
+                            add(k + 1, Item { dot: item.dot + 1, ..item }, &mut sets, &mut seen);
+                        }
+                    }
+                    /* complete */
+                    None => {
+                        let lhs = &grammar.productions[item.production].lhs;
+                        let mut j = 0;
+                        while j < sets[item.origin].len() {
+                            let waiting = sets[item.origin][j];
+                            j += 1;
+                            if matches!(grammar.productions[waiting.production].rhs.get(waiting.dot),
+                                        Some(Symbol::Nonterminal(b)) if b == lhs)
+                            {
+                                add(k, Item { dot: waiting.dot + 1, ..waiting }, &mut sets, &mut seen);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let spans = sets
+            .iter()
+            .enumerate()
+            .flat_map(|(k, set)| set.iter().map(move |it| (k, it)))
+            .filter(|(_, it)| it.dot == grammar.productions[it.production].rhs.len())
+            .map(|(k, it)| (grammar.productions[it.production].lhs.clone(), it.origin, k))
+            .collect();
+        Self { grammar, tokens: tokens.to_vec(), sets, spans }
+    }
+
+    pub fn accepts(&self) -> bool {
+        self.spans.contains(&(self.grammar.start.clone(), 0, self.tokens.len()))
+    }
+
+    /// Completed productions of `a` over `tokens[i..j]`.
+    fn completed(&self, a: &str, i: usize, j: usize) -> impl Iterator<Item = usize> + '_ {
+        let done: BTreeSet<usize> = self.sets[j]
+            .iter()
+            .filter(|it| it.origin == i && it.dot == self.grammar.productions[it.production].rhs.len())
+            .map(|it| it.production)
+            .collect();
+        let a = a.to_string();
+        done.into_iter().filter(move |&p| self.grammar.productions[p].lhs == a)
+    }
+
+    /* ── counting ───────────────────────────────────────────────────── */
+
+    /// Number of parse trees: `Some(0)` when rejected, `None` when a cycle
+    /// (`A ⇒⁺ A` over the same span) makes it infinite.  Saturates.
+    pub fn count_trees(&self) -> Option<u64> {
+        if !self.accepts() { return Some(0); }
+        self.count(&self.grammar.start, 0, self.tokens.len(), &mut HashMap::new())
+    }
+
+    /// More than one parse tree.
+    pub fn is_ambiguous(&self) -> bool { !matches!(self.count_trees(), Some(0 | 1)) }
+
+    fn count(&self, a: &str, i: usize, j: usize, memo: &mut HashMap<(String, usize, usize), Visit>) -> Option<u64> {
+        match memo.get(&(a.to_string(), i, j)) {
+            Some(Visit::Open)    => return None,
+            Some(Visit::Done(c)) => return *c,
+            None                 => {}
+        }
+        memo.insert((a.to_string(), i, j), Visit::Open);
+        let mut total = Some(0u64);
+        for p in self.completed(a, i, j).collect::<Vec<_>>() {
+            total = add(total, self.ways(p, 0, i, j, memo));
+        }
+        memo.insert((a.to_string(), i, j), Visit::Done(total));
+        total
+    }
+
+    /// Ways `rhs[k..]` of production `p` derives `tokens[x..j]`.
+    fn ways(&self, p: usize, k: usize, x: usize, j: usize, memo: &mut HashMap<(String, usize, usize), Visit>) -> Option<u64> {
+        let rhs = &self.grammar.productions[p].rhs;
+        match rhs.get(k) {
+            None => Some(u64::from(x == j)),
+            Some(Symbol::Terminal(t)) => match self.tokens.get(x) {
+                Some(tok) if x < j && tok == t => self.ways(p, k + 1, x + 1, j, memo),
+                _                              => Some(0),
+            },
+            Some(Symbol::Nonterminal(b)) => {
+                let mut total = Some(0u64);
+                for y in x..=j {
+                    if !self.spans.contains(&(b.clone(), x, y)) { continue; }
+                    let rest = self.ways(p, k + 1, y, j, memo);
+                    if rest == Some(0) { continue; }
+                    total = add(total, mul(self.count(b, x, y, memo), rest));
+                }
+                total
+            }
+        }
+    }
+
+    /* ── trees ──────────────────────────────────────────────────────── */
+
+    /// Up to `limit` parse trees, skipping derivations that revisit a
+    /// nonterminal over the same span.
+    pub fn trees(&self, limit: usize) -> Vec<ParseTree> {
+        if !self.accepts() { return Vec::new(); }
+        let mut open = HashSet::new();
+        self.trees_of(&self.grammar.start, 0, self.tokens.len(), limit, &mut open)
+    }
+
+    fn trees_of(
+        &self,
+        a:     &str,
+        i:     usize,
+        j:     usize,
+        limit: usize,
+        open:  &mut HashSet<(String, usize, usize)>,
+    ) -> Vec<ParseTree> {
+        let key = (a.to_string(), i, j);
+        if !open.insert(key.clone()) { return Vec::new(); }
+        let mut out = Vec::new();
+        for p in self.completed(a, i, j).collect::<Vec<_>>() {
+            for children in self.sequences(p, 0, i, j, limit - out.len(), open) {
+                out.push(ParseTree::Node { nonterminal: a.to_string(), production: p, children });
+            }
+            if out.len() >= limit { break; }
+        }
+        open.remove(&key);
+        out
+    }
+
+    fn sequences(
+        &self,
+        p:     usize,
+        k:     usize,
+        x:     usize,
+        j:     usize,
+        limit: usize,
+        open:  &mut HashSet<(String, usize, usize)>,
+    ) -> Vec<Vec<ParseTree>> {
+        let rhs = &self.grammar.productions[p].rhs;
+        match rhs.get(k) {
+            None if x == j => vec![Vec::new()],
+            None           => Vec::new(),
+            Some(Symbol::Terminal(t)) => match self.tokens.get(x) {
+                Some(tok) if x < j && tok == t => self
+                    .sequences(p, k + 1, x + 1, j, limit, open)
+                    .into_iter()
+                    .map(|rest| std::iter::once(ParseTree::Leaf(t.clone())).chain(rest).collect())
+                    .collect(),
+                _ => Vec::new(),
+            },
+            Some(Symbol::Nonterminal(b)) => {
+                let mut out = Vec::new();
+                for y in x..=j {
+                    if !self.spans.contains(&(b.clone(), x, y)) { continue; }
+                    let rests = self.sequences(p, k + 1, y, j, limit, open);
+                    if rests.is_empty() { continue; }
+                    for head in self.trees_of(b, x, y, limit, open) {
+                        for rest in &rests {
+                            if out.len() == limit { return out; }
+                            out.push(std::iter::once(head.clone()).chain(rest.iter().cloned()).collect());
+                        }
+                    }
+                }
+                out
+            }
+        }
+    }
+}
+
+fn add(a: Option<u64>, b: Option<u64>) -> Option<u64> { Some(a?.saturating_add(b?)) }
+
+/// `0 × ∞ = 0`: a cyclic subtree only counts if the rest can be derived.
+fn mul(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+    match (a, b) {
+        (Some(0), _) | (_, Some(0)) => Some(0),
+        (Some(a), Some(b))          => Some(a.saturating_mul(b)),
+        _                           => None,
+    }
+}
@@ -0,0 +1,355 @@
+//! Context‑free grammars: nullable / FIRST / FOLLOW sets and LL(1) parse
+//! tables with their conflicts.
+//!
+//! Text format – one or more `A -> α | β` lines, symbols separated by
+//! spaces, `ε` (or an empty alternative) for the empty word.  Every symbol
+//! that appears on a left‑hand side is a nonterminal, everything else a
+//! terminal; the first left‑hand side is the start symbol.
+//!
+//! ```text
+//! E  -> T E'
+//! E' -> + T E' | ε
+//! T  -> F T'
+//! T' -> * F T' | ε
+//! F  -> ( E ) | id
+//! ```
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+/// End‑of‑input marker in FOLLOW sets and LL(1) tables.
+pub const END: &str = "$";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Symbol {
+    Terminal(String),
+    Nonterminal(String),
+}
+
+impl Symbol {
+    pub fn name(&self) -> &str {
+        match self {
+            Symbol::Terminal(s) | Symbol::Nonterminal(s) => s,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Production {
+    pub lhs: String,
+    /// Empty for an ε‑production.
+    pub rhs: Vec<Symbol>,
+}
+
+impl fmt::Display for Production {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ->", self.lhs)?;
+        if self.rhs.is_empty() { return write!(f, " ε"); }
+        self.rhs.iter().try_for_each(|s| write!(f, " {}", s.name()))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grammar {
+    pub start:       String,
+    pub productions: Vec<Production>,
+}
+
+/// A derivation tree; `production` indexes the grammar it was parsed with.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseTree {
+    Leaf(String),
+    Node { nonterminal: String, production: usize, children: Vec<ParseTree> },
+}
+
+impl ParseTree {
+    /// The terminals at the leaves, left to right.
+    pub fn leaves(&self) -> Vec<&str> {
+        match self {
+            ParseTree::Leaf(t)               => vec![t.as_str()],
+            ParseTree::Node { children, .. } => children.iter().flat_map(ParseTree::leaves).collect(),
+        }
+    }
+}
+
+/// Bracketed form, e.g. `(S a (S) b)`.
+impl fmt::Display for ParseTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseTree::Leaf(t) => write!(f, "{t}"),
+            ParseTree::Node { nonterminal, children, .. } => {
+                write!(f, "({nonterminal}")?;
+                children.iter().try_for_each(|c| write!(f, " {c}"))?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// LL(1) table: `cells[(A, a)]` lists the productions predicted for
+/// nonterminal `A` on lookahead `a`; more than one is a conflict.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ll1Table {
+    pub cells: BTreeMap<(String, String), Vec<usize>>,
+}
+
+impl Ll1Table {
+    /// The cells holding more than one production.
+    pub fn conflicts(&self) -> Vec<(&(String, String), &Vec<usize>)> {
+        self.cells.iter().filter(|(_, p)| p.len() > 1).collect()
+    }
+
+    pub fn is_ll1(&self) -> bool { self.conflicts().is_empty() }
+
+    /// The production predicted for `nonterminal` on `lookahead`, if
+    /// exactly one.
+    pub fn get(&self, nonterminal: &str, lookahead: &str) -> Option<usize> {
+        match self.cells.get(&(nonterminal.to_string(), lookahead.to_string()))?.as_slice() {
+            [p] => Some(*p),
+            _   => None,
+        }
+    }
+}
+
+impl Grammar {
+    /// Reads the text format (see the [module docs](self)).
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::grammar::Grammar;
+    ///
+    /// let g = Grammar::parse("S -> a S b | ε").unwrap();
+    /// assert_eq!(g.start, "S");
+    /// assert_eq!(g.productions.len(), 2);
+    /// assert_eq!(g.to_string(), "S -> a S b\nS -> ε\n");
+    /// assert!(Grammar::parse("S a b").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut rules = Vec::new();
+        for (n, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() { continue; }
+            let (lhs, rhs) = line
+                .split_once("->")
+                .or_else(|| line.split_once('→'))
+                .ok_or(format!("line {}: expected 'A -> α'", n + 1))?;
+            let lhs = lhs.trim();
+            if lhs.is_empty() || lhs.contains(char::is_whitespace) {
+                return Err(format!("line {}: bad left‑hand side '{lhs}'", n + 1));
+            }
+            for alt in rhs.split('|') {
+                let tokens: Vec<String> = alt
+                    .split_whitespace()
+                    .filter(|t| *t != "ε")
+                    .map(str::to_string)
+                    .collect();
+                rules.push((lhs.to_string(), tokens));
+            }
+        }
+        let Some((start, _)) = rules.first() else { return Err("no productions".into()) };
+        let start = start.clone();
+        let nonterminals: BTreeSet<String> = rules.iter().map(|(l, _)| l.clone()).collect();
+        let productions = rules
+            .into_iter()
+            .map(|(lhs, rhs)| Production {
+                lhs,
+                rhs: rhs
+                    .into_iter()
+                    .map(|t| if nonterminals.contains(&t) { Symbol::Nonterminal(t) } else { Symbol::Terminal(t) })
+                    .collect(),
+            })
+            .collect();
+        Ok(Self { start, productions })
+    }
+
+    pub fn nonterminals(&self) -> BTreeSet<String> {
+        self.productions.iter().map(|p| p.lhs.clone()).collect()
+    }
+
+    pub fn terminals(&self) -> BTreeSet<String> {
+        self.productions
+            .iter()
+            .flat_map(|p| &p.rhs)
+            .filter_map(|s| match s { Symbol::Terminal(t) => Some(t.clone()), _ => None })
+            .collect()
+    }
+
+    /// Splits `input` into terminals: one per character if every terminal
+    /// is a single character, else at whitespace.
+    pub fn tokenize(&self, input: &str) -> Vec<String> {
+        if self.terminals().iter().all(|t| t.chars().count() == 1) {
+            input.chars().filter(|c| !c.is_whitespace()).map(String::from).collect()
+        } else {
+            input.split_whitespace().map(str::to_string).collect()
+        }
+    }
+
+    /* ── FIRST / FOLLOW ─────────────────────────────────────────────── */
+
+    /// Nonterminals deriving ε.
+    pub fn nullable(&self) -> BTreeSet<String> {
+        let mut nullable = BTreeSet::new();
+        loop {
+            let before = nullable.len();
+            for p in &self.productions {
+                if p.rhs.iter().all(|s| matches!(s, Symbol::Nonterminal(n) if nullable.contains(n))) {
+                    nullable.insert(p.lhs.clone());
+                }
+            }
+            if nullable.len() == before { return nullable; }
+        }
+    }
+
+    /// FIRST of every nonterminal (terminals only – see [`nullable`](Self::nullable)).
+    pub fn first_sets(&self) -> BTreeMap<String, BTreeSet<String>> {
+        let nullable = self.nullable();
+        let mut first: BTreeMap<String, BTreeSet<String>> =
+            self.nonterminals().into_iter().map(|n| (n, BTreeSet::new())).collect();
+        loop {
+            let mut changed = false;
+            for p in &self.productions {
+                let (add, _) = first_of_seq(&p.rhs, &first, &nullable);
+                let set = first.get_mut(&p.lhs).expect("lhs is a nonterminal");
+                for t in add {
+                    changed |= set.insert(t);
+                }
+            }
+            if !changed { return first; }
+        }
+    }
+
+    /// FIRST of a symbol string and whether it derives ε.
+    pub fn first_of(&self, seq: &[Symbol]) -> (BTreeSet<String>, bool) {
+        first_of_seq(seq, &self.first_sets(), &self.nullable())
+    }
+
+    /// FOLLOW of every nonterminal; [`END`] follows the start symbol.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::grammar::Grammar;
+    ///
+    /// let g = Grammar::parse("E -> T E'\nE' -> + T E' | ε\nT -> F T'\nT' -> * F T' | ε\nF -> ( E ) | id").unwrap();
+    /// let set = |s: &[&str]| s.iter().map(|x| x.to_string()).collect::<std::collections::BTreeSet<_>>();
+    /// assert_eq!(g.first_sets()["E"], set(&["(", "id"]));
+    /// assert_eq!(g.first_sets()["E'"], set(&["+"]));
+    /// assert_eq!(g.follow_sets()["T"], set(&["$", ")", "+"]));
+    /// assert_eq!(g.follow_sets()["F"], set(&["$", ")", "*", "+"]));
+    /// ```
+    pub fn follow_sets(&self) -> BTreeMap<String, BTreeSet<String>> {
+        let (first, nullable) = (self.first_sets(), self.nullable());
+        let mut follow: BTreeMap<String, BTreeSet<String>> =
+            self.nonterminals().into_iter().map(|n| (n, BTreeSet::new())).collect();
+        follow.entry(self.start.clone()).or_default().insert(END.to_string());
+        loop {
+            let mut changed = false;
+            for p in &self.productions {
+                for (i, s) in p.rhs.iter().enumerate() {
+                    let Symbol::Nonterminal(b) = s else { continue };
+                    let (mut add, rest_nullable) = first_of_seq(&p.rhs[i + 1..], &first, &nullable);
+                    if rest_nullable {
+                        add.extend(follow[&p.lhs].iter().cloned());
+                    }
+                    let set = follow.get_mut(b).expect("rhs nonterminal has productions");
+                    for t in add {
+                        changed |= set.insert(t);
+                    }
+                }
+            }
+            if !changed { return follow; }
+        }
+    }
+
+    /* ── LL(1) ──────────────────────────────────────────────────────── */
+
+    /// The LL(1) prediction table; conflicting cells keep every candidate.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::grammar::Grammar;
+    ///
+    /// let expr = Grammar::parse("E -> T E'\nE' -> + T E' | ε\nT -> F T'\nT' -> * F T' | ε\nF -> ( E ) | id").unwrap();
+    /// assert!(expr.ll1_table().is_ll1());
+    ///
+    /// // left recursion is never LL(1)
+    /// let left = Grammar::parse("E -> E + T | T\nT -> id").unwrap();
+    /// let table = left.ll1_table();
+    /// assert_eq!(table.conflicts().len(), 1);
+    /// assert_eq!(table.conflicts()[0].0, &("E".to_string(), "id".to_string()));
+    /// ```
+    pub fn ll1_table(&self) -> Ll1Table {
+        let (first, nullable, follow) = (self.first_sets(), self.nullable(), self.follow_sets());
+        let mut table = Ll1Table::default();
+        for (i, p) in self.productions.iter().enumerate() {
+            let (mut predict, eps) = first_of_seq(&p.rhs, &first, &nullable);
+            if eps {
+                predict.extend(follow[&p.lhs].iter().cloned());
+            }
+            for a in predict {
+                table.cells.entry((p.lhs.clone(), a)).or_default().push(i);
+            }
+        }
+        table
+    }
+
+    /// Predictive parse of `tokens` with an LL(1) `table`: the productions
+    /// of the leftmost derivation, or where it failed.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::grammar::Grammar;
+    ///
+    /// let g = Grammar::parse("S -> a S b | ε").unwrap();
+    /// let table = g.ll1_table();
+    /// assert_eq!(g.ll1_parse(&table, &g.tokenize("aabb")), Ok(vec![0, 0, 1]));
+    /// assert!(g.ll1_parse(&table, &g.tokenize("aab")).is_err());
+    /// ```
+    pub fn ll1_parse(&self, table: &Ll1Table, tokens: &[String]) -> Result<Vec<usize>, String> {
+        let mut stack = vec![Symbol::Nonterminal(self.start.clone())];
+        let mut pos   = 0;
+        let mut used  = Vec::new();
+        while let Some(top) = stack.pop() {
+            let look = tokens.get(pos).map_or(END, String::as_str);
+            match top {
+                Symbol::Terminal(t) if t == look => pos += 1,
+                Symbol::Terminal(t) => return Err(format!("token {pos}: expected '{t}', found '{look}'")),
+                Symbol::Nonterminal(a) => {
+                    let Some(p) = table.get(&a, look) else {
+                        return Err(format!("token {pos}: no unique production for {a} on '{look}'"));
+                    };
+                    used.push(p);
+                    stack.extend(self.productions[p].rhs.iter().rev().cloned());
+                }
+            }
+        }
+        match tokens.get(pos) {
+            None    => Ok(used),
+            Some(t) => Err(format!("token {pos}: unexpected '{t}' after the end")),
+        }
+    }
+}
+
+impl fmt::Display for Grammar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.productions.iter().try_for_each(|p| writeln!(f, "{p}"))
+    }
+}
+
+fn first_of_seq(
+    seq:      &[Symbol],
+    first:    &BTreeMap<String, BTreeSet<String>>,
+    nullable: &BTreeSet<String>,
+) -> (BTreeSet<String>, bool) {
+    let mut out = BTreeSet::new();
+    for s in seq {
+        match s {
+            Symbol::Terminal(t) => {
+                out.insert(t.clone());
+                return (out, false);
+            }
+            Symbol::Nonterminal(n) => {
+                out.extend(first.get(n).into_iter().flatten().cloned());
+                if !nullable.contains(n) { return (out, false); }
+            }
+        }
+    }
+    (out, true)
+}
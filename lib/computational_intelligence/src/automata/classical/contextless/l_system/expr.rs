@@ -0,0 +1,181 @@
+//! Arithmetic and conditions of parametric L‑systems.
+//!
+//! `+ - * / ^`, comparisons `< <= > >= == !=`, `&&`, `||`, unary `-` and
+//! `!`, numbers, names and parentheses.  Truth is `1.0`, falsehood `0.0`.
+
+use std::collections::BTreeMap;
+
+/// Values of parameter names and constants.
+pub type Env = BTreeMap<String, f64>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    Add, Sub, Mul, Div, Pow,
+    Lt, Le, Gt, Ge, Eq, Ne,
+    And, Or,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Num(f64),
+    Var(String),
+    Neg(Box<Expr>),
+    Not(Box<Expr>),
+    Bin(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::l_system::expr::{Env, Expr};
+    ///
+    /// let env = Env::from([("x".to_string(), 3.0)]);
+    /// assert_eq!(Expr::parse("2 * x ^ 2 - 1").unwrap().eval(&env), 17.0);
+    /// assert_eq!(Expr::parse("x > 1 && !(x == 2)").unwrap().eval(&env), 1.0);
+    /// assert!(Expr::parse("x +").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let tokens = lex(text)?;
+        let mut p = Parser { tokens, pos: 0 };
+        let e = p.or()?;
+        match p.tokens.get(p.pos) {
+            None    => Ok(e),
+            Some(t) => Err(format!("unexpected '{t}' in '{text}'")),
+        }
+    }
+
+    /// Unknown names evaluate to `0`.
+    pub fn eval(&self, env: &Env) -> f64 {
+        let truth = |b: bool| if b { 1.0 } else { 0.0 };
+        match self {
+            Expr::Num(v)  => *v,
+            Expr::Var(n)  => env.get(n).copied().unwrap_or(0.0),
+            Expr::Neg(e)  => -e.eval(env),
+            Expr::Not(e)  => truth(e.eval(env) == 0.0),
+            Expr::Bin(op, a, b) => {
+                let (a, b) = (a.eval(env), b.eval(env));
+                match op {
+                    Op::Add => a + b,
+                    Op::Sub => a - b,
+                    Op::Mul => a * b,
+                    Op::Div => a / b,
+                    Op::Pow => a.powf(b),
+                    Op::Lt  => truth(a < b),
+                    Op::Le  => truth(a <= b),
+                    Op::Gt  => truth(a > b),
+                    Op::Ge  => truth(a >= b),
+                    Op::Eq  => truth(a == b),
+                    Op::Ne  => truth(a != b),
+                    Op::And => truth(a != 0.0 && b != 0.0),
+                    Op::Or  => truth(a != 0.0 || b != 0.0),
+                }
+            }
+        }
+    }
+}
+
+fn lex(text: &str) -> Result<Vec<String>, String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            out.push(chars[start..i].iter().collect());
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            out.push(chars[start..i].iter().collect());
+        } else {
+            let pair: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+            if ["<=", ">=", "==", "!=", "&&", "||"].contains(&pair.as_str()) {
+                out.push(pair);
+                i += 2;
+            } else if "+-*/^<>!()".contains(c) {
+                out.push(c.to_string());
+                i += 1;
+            } else {
+                return Err(format!("unexpected '{c}' in '{text}'"));
+            }
+        }
+    }
+    Ok(out)
+}
+
+struct Parser {
+    tokens: Vec<String>,
+    pos:    usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&str> { self.tokens.get(self.pos).map(String::as_str) }
+
+    fn eat(&mut self, t: &str) -> bool {
+        let hit = self.peek() == Some(t);
+        self.pos += usize::from(hit);
+        hit
+    }
+
+    /// One left‑associative precedence level.
+    fn level(&mut self, ops: &[(&str, Op)], next: fn(&mut Self) -> Result<Expr, String>) -> Result<Expr, String> {
+        let mut lhs = next(self)?;
+        'outer: loop {
+            for (t, op) in ops {
+                if self.eat(t) {
+                    lhs = Expr::Bin(*op, Box::new(lhs), Box::new(next(self)?));
+                    continue 'outer;
+                }
+            }
+            return Ok(lhs);
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr, String> { self.level(&[("||", Op::Or)], Self::and) }
+
+    fn and(&mut self) -> Result<Expr, String> { self.level(&[("&&", Op::And)], Self::cmp) }
+
+    fn cmp(&mut self) -> Result<Expr, String> {
+        self.level(
+            &[("<=", Op::Le), (">=", Op::Ge), ("==", Op::Eq), ("!=", Op::Ne), ("<", Op::Lt), (">", Op::Gt)],
+            Self::sum,
+        )
+    }
+
+    fn sum(&mut self) -> Result<Expr, String> { self.level(&[("+", Op::Add), ("-", Op::Sub)], Self::product) }
+
+    fn product(&mut self) -> Result<Expr, String> { self.level(&[("*", Op::Mul), ("/", Op::Div)], Self::unary) }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.eat("-") { return Ok(Expr::Neg(Box::new(self.unary()?))); }
+        if self.eat("!") { return Ok(Expr::Not(Box::new(self.unary()?))); }
+        self.power()
+    }
+
+    /// Right‑associative, binding tighter than unary minus on its left.
+    fn power(&mut self) -> Result<Expr, String> {
+        let base = self.primary()?;
+        if self.eat("^") {
+            return Ok(Expr::Bin(Op::Pow, Box::new(base), Box::new(self.unary()?)));
+        }
+        Ok(base)
+    }
+
+    fn primary(&mut self) -> Result<Expr, String> {
+        let Some(t) = self.peek().map(str::to_string) else { return Err("unexpected end of expression".into()) };
+        self.pos += 1;
+        if t == "(" {
+            let e = self.or()?;
+            return if self.eat(")") { Ok(e) } else { Err("missing ')'".into()) };
+        }
+        if let Ok(v) = t.parse::<f64>() { return Ok(Expr::Num(v)); }
+        if t.starts_with(|c: char| c.is_alphabetic() || c == '_') { return Ok(Expr::Var(t)); }
+        Err(format!("unexpected '{t}'"))
+    }
+}
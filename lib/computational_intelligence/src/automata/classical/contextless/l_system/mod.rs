@@ -0,0 +1,209 @@
+//! Lindenmayer System (L-system) string rewriting.
+//!
+//! The char‑map helpers below cover plain D0L systems; [`rules`] adds
+//! bracketed, stochastic, parametric and context‑sensitive systems,
+//! [`turtle`] draws their words as polylines and rasterises them.
+//!
+//! Spawned as automata, the presets grow one generation every `interval`
+//! ticks up to `iterations`, redrawn to fit the grid:
+//!
+//! * `lsystem:koch`   – Koch snowflake;
+//! * `lsystem:plant`  – bracketed fractal plant;
+//! * `lsystem:bush`   – stochastic bush, a different one each spawn;
+//! * `lsystem:tree`   – parametric tree with shrinking branches;
+//! * `lsystem:signal` – context‑sensitive plant grown by travelling signals.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use engine_core::{engine::grid::GridBackend, events::AutomatonId};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod expr;
+pub mod plugin;
+pub mod rules;
+pub mod turtle;
+
+use rules::{LSystem, Module};
+use turtle::{rasterise, Turtle, DEPTH_SHADES};
+
+/// Applies one iteration of L-system rewriting on the input string using the given production rules.
+pub fn lsystem_step(input: &str, rules: &HashMap<char, String>) -> String {
+    let mut output = String::new();
+    for ch in input.chars() {
+        if let Some(replacement) = rules.get(&ch) {
+            output.push_str(replacement);
+        } else {
+            output.push(ch);
+        }
+    }
+    output
+}
+
+/// Generates the L-system string after n iterations.
+pub fn lsystem_iterate(mut axiom: String, rules: &HashMap<char, String>, iterations: u32) -> String {
+    for _ in 0..iterations {
+        axiom = lsystem_step(&axiom, rules);
+    }
+    axiom
+}
+
+/// Example: Koch curve L-system rules (F -> F+F--F+F).
+pub fn koch_curve(iterations: u32) -> String {
+    let mut rules = HashMap::new();
+    rules.insert('F', String::from("F+F--F+F"));
+    lsystem_iterate(String::from("F"), &rules, iterations)
+}
+
+/* ── spawnable presets ────────────────────────────────────────────── */
+
+pub const LSYSTEM_KOCH_ID: &str   = "lsystem:koch";
+pub const LSYSTEM_PLANT_ID: &str  = "lsystem:plant";
+pub const LSYSTEM_BUSH_ID: &str   = "lsystem:bush";
+pub const LSYSTEM_TREE_ID: &str   = "lsystem:tree";
+pub const LSYSTEM_SIGNAL_ID: &str = "lsystem:signal";
+
+const KOCH: &str = "\
+axiom: F--F--F
+angle: 60
+F -> F+F--F+F";
+
+const PLANT: &str = "\
+axiom: X
+angle: 25
+X -> F+[[X]-X]-F[-FX]+X
+F -> FF";
+
+const BUSH: &str = "\
+axiom: F
+angle: 25.7
+(0.33) F -> F[+F]F[-F]F
+(0.33) F -> F[+F]F
+(0.34) F -> F[-F]F";
+
+const TREE: &str = "\
+axiom: A(1)
+define: R = 1.456
+define: a = 35
+A(s) : s >= 0.1 -> F(s)[+(a)A(s/R)][-(a)A(s/R)]";
+
+// Prusinkiewicz & Lindenmayer, *The Algorithmic Beauty of Plants*, fig. 1.31b.
+const SIGNAL: &str = "\
+axiom: F1F1F1
+angle: 22.5
+ignore: +-F
+0 < 0 > 0 -> 1
+0 < 0 > 1 -> 1[-F1F1]
+0 < 1 > 0 -> 1
+0 < 1 > 1 -> 1
+1 < 0 > 0 -> 0
+1 < 0 > 1 -> 1F1
+1 < 1 > 0 -> 1
+1 < 1 > 1 -> 0
++ -> -
+- -> +";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LSystemParams {
+    /// The system in the [`rules`] text format.
+    pub definition: String,
+    pub iterations: u32,
+    /// Ticks per generation.
+    pub interval:   u32,
+}
+
+impl Default for LSystemParams {
+    fn default() -> Self {
+        Self { definition: PLANT.into(), iterations: 6, interval: 15 }
+    }
+}
+
+impl LSystemParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+
+    /// Every preset id with its defaults.
+    pub fn presets() -> [(&'static str, Self); 5] {
+        let preset = |definition: &str, iterations| Self { definition: definition.into(), iterations, ..default() };
+        [
+            (LSYSTEM_KOCH_ID,   preset(KOCH, 4)),
+            (LSYSTEM_PLANT_ID,  preset(PLANT, 6)),
+            (LSYSTEM_BUSH_ID,   preset(BUSH, 5)),
+            (LSYSTEM_TREE_ID,   preset(TREE, 8)),
+            (LSYSTEM_SIGNAL_ID, preset(SIGNAL, 30)),
+        ]
+    }
+}
+
+/// An L‑system being grown.
+#[derive(Clone, Debug)]
+pub struct Growth {
+    pub system:     LSystem,
+    pub turtle:     Turtle,
+    pub word:       Vec<Module>,
+    pub generation: u32,
+    pub iterations: u32,
+    pub interval:   u32,
+    pub ticks:      u32,
+}
+
+impl Growth {
+    pub fn new(p: &LSystemParams) -> Result<Self, String> {
+        let system = LSystem::parse(&p.definition)?;
+        Ok(Self {
+            turtle:     Turtle { angle: system.angle, step: system.step, ..default() },
+            word:       system.axiom.clone(),
+            generation: 0,
+            iterations: p.iterations,
+            interval:   p.interval.max(1),
+            ticks:      0,
+            system,
+        })
+    }
+
+    /// Counts a tick; rewrites once every `interval` ticks until
+    /// `iterations` generations.  True if the word changed.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::l_system::{
+    ///     Growth, LSystemParams, LSYSTEM_KOCH_ID,
+    /// };
+    ///
+    /// let (_, p) = LSystemParams::presets().into_iter().find(|(id, _)| *id == LSYSTEM_KOCH_ID).unwrap();
+    /// let mut g = Growth::new(&LSystemParams { interval: 2, ..p }).unwrap();
+    /// let mut rng = rand::rng();
+    /// let changed: Vec<bool> = (0..10).map(|_| g.advance(&mut rng)).collect();
+    /// assert_eq!(changed, [false, true, false, true, false, true, false, true, false, false]);
+    /// assert_eq!(g.word.iter().filter(|m| m.symbol == 'F').count(), 3 * 4usize.pow(4));
+    /// ```
+    pub fn advance(&mut self, rng: &mut impl Rng) -> bool {
+        if self.generation >= self.iterations { return false; }
+        self.ticks += 1;
+        if !self.ticks.is_multiple_of(self.interval) { return false; }
+        self.word = self.system.rewrite(&self.word, rng);
+        self.generation += 1;
+        true
+    }
+
+    pub fn draw(&self, grid: &mut GridBackend) {
+        rasterise(&self.turtle.interpret(&self.word), grid);
+    }
+}
+
+/// L‑systems of every spawned preset.
+#[derive(Resource, Default)]
+pub struct Growths(pub HashMap<AutomatonId, Growth>);
+
+/// Bark at the trunk through to leaf green at the tips.
+pub fn plant_palette() -> Vec<Color> {
+    (0..DEPTH_SHADES)
+        .map(|k| {
+            let t = k as f32 / (DEPTH_SHADES - 1) as f32;
+            Color::srgb(0.45 - 0.25 * t, 0.30 + 0.45 * t, 0.15 + 0.10 * t)
+        })
+        .collect()
+}
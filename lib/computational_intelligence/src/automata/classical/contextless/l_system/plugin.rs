@@ -0,0 +1,89 @@
+//! Registers the L‑system presets, keeps a growing word per spawned
+//! automaton and redraws it whenever a generation is added.
+
+use bevy::prelude::*;
+use engine_core::{
+    events::{AutomatonAdded, AutomatonRemoved},
+    schedule::MainSet,
+    state::AppState,
+};
+
+use crate::{
+    agents::HabitatRule,
+    analytics::time_series::TimeSeries,
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{plant_palette, Growth, Growths, LSystemParams};
+
+fn is_lsystem(name: &str) -> bool {
+    LSystemParams::presets().iter().any(|(id, _)| *id == name)
+}
+
+pub struct LSystemPlugin;
+impl Plugin for LSystemPlugin {
+    fn build(&self, app: &mut App) {
+        // The word lives in `Growths`; the grid only shows its drawing.
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        for (id, defaults) in LSystemParams::presets() {
+            reg.register(id, HabitatRule::boxed());
+            reg.register_params(id, &defaults);
+            reg.register_palette(id, plant_palette());
+        }
+        app.insert_resource(reg);
+
+        app.init_resource::<Growths>()
+           .add_systems(Update, (plant_growths, drop_growths))
+           .add_systems(
+               Update,
+               grow
+                   .in_set(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           )
+           .add_systems(
+               Update,
+               record_modules
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+fn plant_growths(
+    mut added:    EventReader<AutomatonAdded>,
+    mut registry: ResMut<AutomataRegistry>,
+    mut growths:  ResMut<Growths>,
+) {
+    for ev in added.read() {
+        let Some(info) = registry.get_mut(ev.id) else { continue };
+        if !is_lsystem(&info.name) { continue; }
+        match Growth::new(&LSystemParams::from_value(&info.params)) {
+            Ok(growth) => {
+                growth.draw(&mut info.grid);
+                growths.0.insert(ev.id, growth);
+            }
+            Err(e) => warn!("L‑system “{}” does not parse: {e}", info.name),
+        }
+    }
+}
+
+fn drop_growths(mut removed: EventReader<AutomatonRemoved>, mut growths: ResMut<Growths>) {
+    for ev in removed.read() {
+        growths.0.remove(&ev.id);
+    }
+}
+
+fn grow(mut registry: ResMut<AutomataRegistry>, mut growths: ResMut<Growths>) {
+    let mut rng = rand::rng();
+    for (id, growth) in growths.0.iter_mut() {
+        if !growth.advance(&mut rng) { continue; }
+        let Some(info) = registry.get_mut(*id) else { continue };
+        growth.draw(&mut info.grid);
+    }
+}
+
+/// Per‑tick word length.
+fn record_modules(growths: Res<Growths>, mut series: ResMut<TimeSeries>) {
+    for (id, growth) in growths.0.iter() {
+        series.record(*id, "modules", growth.word.len() as f64);
+    }
+}
@@ -0,0 +1,352 @@
+//! Bracketed, stochastic, parametric and context‑sensitive L‑systems.
+//!
+//! Text format, one directive or production per line, `#` comments:
+//!
+//! ```text
+//! axiom:  A(1)
+//! angle:  30                # turtle turn, degrees
+//! step:   10                # turtle stride for a bare F
+//! ignore: +-F               # skipped while matching contexts
+//! define: R = 1.456
+//!
+//! A(s) : s > 0.05 -> F(s)[+A(s/R)][-A(s/R)]
+//! 0 < 1 > 0 -> 1            # context‑sensitive: left < pred > right
+//! (0.3) F -> F[+F]F         # stochastic: relative weight first
+//! ```
+//!
+//! Among the productions matching a module one is drawn with probability
+//! proportional to its weight (default 1); unmatched modules are copied.
+//! Contexts follow the branching structure: the left context continues
+//! into the parent branch, the right context skips over child branches.
+
+use std::{collections::BTreeSet, fmt};
+
+use rand::Rng;
+
+use super::expr::{Env, Expr};
+
+/// A symbol with its actual parameters, e.g. `F(1.5)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Module {
+    pub symbol: char,
+    pub params: Vec<f64>,
+}
+
+impl Module {
+    pub fn new(symbol: char) -> Self { Self { symbol, params: Vec::new() } }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol)?;
+        if self.params.is_empty() { return Ok(()); }
+        let ps: Vec<String> = self.params.iter().map(|p| format!("{p}")).collect();
+        write!(f, "({})", ps.join(","))
+    }
+}
+
+/// A word as text, e.g. `F(1)[+A(0.5)]`.
+pub fn show(word: &[Module]) -> String {
+    word.iter().map(Module::to_string).collect()
+}
+
+/// A symbol with formal parameter names, e.g. `A(s)`; `*` matches any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    pub symbol: char,
+    pub params: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Production {
+    pub left:      Vec<Pattern>,
+    pub pred:      Pattern,
+    pub right:     Vec<Pattern>,
+    pub condition: Option<Expr>,
+    pub successor: Vec<(char, Vec<Expr>)>,
+    pub weight:    f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LSystem {
+    pub axiom:       Vec<Module>,
+    pub productions: Vec<Production>,
+    pub ignore:      BTreeSet<char>,
+    pub constants:   Env,
+    pub angle:       f32,
+    pub step:        f32,
+}
+
+impl Default for LSystem {
+    fn default() -> Self {
+        Self {
+            axiom:       Vec::new(),
+            productions: Vec::new(),
+            ignore:      BTreeSet::new(),
+            constants:   Env::new(),
+            angle:       90.0,
+            step:        1.0,
+        }
+    }
+}
+
+impl LSystem {
+    /// Reads the text format (see the [module docs](self)).
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut sys = LSystem::default();
+        let mut axiom = None;
+        for (n, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() { continue; }
+            let err = |e: String| format!("line {}: {e}", n + 1);
+            let directive = line.split_once(':').filter(|(k, _)| {
+                ["axiom", "angle", "step", "ignore", "define"].contains(&k.trim())
+            });
+            match directive {
+                Some((key, value)) => {
+                    let value = value.trim();
+                    let number = || value.parse::<f32>().map_err(|_| err(format!("bad number '{value}'")));
+                    match key.trim() {
+                        "axiom"  => axiom = Some(value.to_string()),
+                        "angle"  => sys.angle = number()?,
+                        "step"   => sys.step = number()?,
+                        "ignore" => sys.ignore = value.chars().filter(|c| !c.is_whitespace()).collect(),
+                        _ => {
+                            let (name, v) = value.split_once('=').ok_or(err("expected 'define: NAME = value'".into()))?;
+                            let v = Expr::parse(v).map_err(err)?.eval(&sys.constants);
+                            sys.constants.insert(name.trim().to_string(), v);
+                        }
+                    }
+                }
+                None => sys.productions.push(parse_production(line).map_err(err)?),
+            }
+        }
+        let axiom = axiom.ok_or("missing 'axiom:'")?;
+        sys.axiom = modules(&axiom)?
+            .into_iter()
+            .map(|(symbol, args)| {
+                let params = args
+                    .iter()
+                    .map(|a| Expr::parse(a).map(|e| e.eval(&sys.constants)))
+                    .collect::<Result<_, _>>()?;
+                Ok(Module { symbol, params })
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(sys)
+    }
+
+    /// One parallel rewriting step.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::l_system::rules::{show, LSystem};
+    ///
+    /// // Lindenmayer's algae
+    /// let algae = LSystem::parse("axiom: A\nA -> AB\nB -> A").unwrap();
+    /// let mut rng = rand::rng();
+    /// assert_eq!(show(&algae.derive(4, &mut rng)), "ABAABABA");
+    ///
+    /// // parametric with a condition
+    /// let p = LSystem::parse("axiom: A(4)\nA(x) : x > 1 -> F(x)A(x/2)").unwrap();
+    /// assert_eq!(show(&p.derive(5, &mut rng)), "F(4)F(2)A(1)");
+    ///
+    /// // a signal travelling right along a branching filament
+    /// let s = LSystem::parse("axiom: baa[a]aa\nb < a -> b\nb -> a").unwrap();
+    /// assert_eq!(show(&s.derive(1, &mut rng)), "aba[a]aa");
+    /// assert_eq!(show(&s.derive(2, &mut rng)), "aab[a]aa");
+    /// assert_eq!(show(&s.derive(3, &mut rng)), "aaa[b]ba");
+    /// ```
+    pub fn rewrite(&self, word: &[Module], rng: &mut impl Rng) -> Vec<Module> {
+        let mut out = Vec::with_capacity(word.len());
+        for i in 0..word.len() {
+            let candidates: Vec<(&Production, Env)> = self
+                .productions
+                .iter()
+                .filter_map(|p| self.matches(p, word, i).map(|env| (p, env)))
+                .collect();
+            let total: f64 = candidates.iter().map(|(p, _)| p.weight).sum();
+            let chosen = match candidates.len() {
+                0 => None,
+                1 => candidates.first(),
+                _ => {
+                    let mut r = rng.random_range(0.0..total.max(f64::MIN_POSITIVE));
+                    candidates.iter().find(|(p, _)| {
+                        r -= p.weight;
+                        r < 0.0
+                    }).or(candidates.last())
+                }
+            };
+            match chosen {
+                None => out.push(word[i].clone()),
+                Some((p, env)) => out.extend(p.successor.iter().map(|(symbol, args)| Module {
+                    symbol: *symbol,
+                    params: args.iter().map(|a| a.eval(env)).collect(),
+                })),
+            }
+        }
+        out
+    }
+
+    /// The word after `n` rewriting steps from the axiom.
+    pub fn derive(&self, n: u32, rng: &mut impl Rng) -> Vec<Module> {
+        (0..n).fold(self.axiom.clone(), |w, _| self.rewrite(&w, rng))
+    }
+
+    /// Bindings if `p` applies to `word[i]`.
+    fn matches(&self, p: &Production, word: &[Module], i: usize) -> Option<Env> {
+        let mut env = self.constants.clone();
+        bind(&p.pred, &word[i], &mut env)?;
+
+        let mut j = i;
+        for pat in p.left.iter().rev() {
+            j = self.left_neighbour(word, j)?;
+            bind(pat, &word[j], &mut env)?;
+        }
+        let mut j = i;
+        for pat in &p.right {
+            j = self.right_neighbour(word, j)?;
+            bind(pat, &word[j], &mut env)?;
+        }
+        match &p.condition {
+            Some(c) if c.eval(&env) == 0.0 => None,
+            _                              => Some(env),
+        }
+    }
+
+    /// Nearest module before `i` on the path to the root.
+    fn left_neighbour(&self, word: &[Module], i: usize) -> Option<usize> {
+        let mut j = i;
+        while j > 0 {
+            j -= 1;
+            match word[j].symbol {
+                '[' => continue,
+                ']' => {
+                    let mut depth = 1;
+                    while depth > 0 {
+                        j = j.checked_sub(1)?;
+                        match word[j].symbol {
+                            ']' => depth += 1,
+                            '[' => depth -= 1,
+                            _   => {}
+                        }
+                    }
+                }
+                s if self.ignore.contains(&s) => continue,
+                _ => return Some(j),
+            }
+        }
+        None
+    }
+
+    /// Nearest module after `i` on the same branch, skipping child branches.
+    fn right_neighbour(&self, word: &[Module], i: usize) -> Option<usize> {
+        let mut j = i + 1;
+        while j < word.len() {
+            match word[j].symbol {
+                ']' => return None,
+                '[' => {
+                    let mut depth = 1;
+                    while depth > 0 {
+                        j += 1;
+                        match word.get(j)?.symbol {
+                            '[' => depth += 1,
+                            ']' => depth -= 1,
+                            _   => {}
+                        }
+                    }
+                    j += 1;
+                }
+                s if self.ignore.contains(&s) => j += 1,
+                _ => return Some(j),
+            }
+        }
+        None
+    }
+}
+
+fn bind(pat: &Pattern, m: &Module, env: &mut Env) -> Option<()> {
+    if pat.symbol == '*' { return Some(()); }
+    if pat.symbol != m.symbol || pat.params.len() != m.params.len() { return None; }
+    for (name, v) in pat.params.iter().zip(&m.params) {
+        env.insert(name.clone(), *v);
+    }
+    Some(())
+}
+
+/// `[(w)] [left <] pred [> right] [: cond] -> successor`
+fn parse_production(line: &str) -> Result<Production, String> {
+    let (weight, rest) = match line.strip_prefix('(') {
+        Some(r) => {
+            let (w, rest) = r.split_once(')').ok_or("missing ')' after weight")?;
+            (w.trim().parse::<f64>().map_err(|_| format!("bad weight '{w}'"))?, rest)
+        }
+        None => (1.0, line),
+    };
+    let (lhs, succ) = rest.split_once("->").ok_or("expected 'pred -> successor' or 'key: value'")?;
+    let (lhs, condition) = match lhs.split_once(':') {
+        Some((l, c)) => (l, Some(Expr::parse(c)?)),
+        None         => (lhs, None),
+    };
+    let (left, lhs) = match lhs.split_once('<') {
+        Some((l, r)) => (patterns(l)?, r),
+        None         => (Vec::new(), lhs),
+    };
+    let (pred, right) = match lhs.split_once('>') {
+        Some((p, r)) => (patterns(p)?, patterns(r)?),
+        None         => (patterns(lhs)?, Vec::new()),
+    };
+    let [pred] = <[Pattern; 1]>::try_from(pred).map_err(|_| "predecessor must be one module")?;
+    let successor = modules(succ)?
+        .into_iter()
+        .map(|(s, args)| Ok((s, args.iter().map(|a| Expr::parse(a)).collect::<Result<_, String>>()?)))
+        .collect::<Result<_, String>>()?;
+    Ok(Production { left, pred, right, condition, successor, weight })
+}
+
+fn patterns(text: &str) -> Result<Vec<Pattern>, String> {
+    modules(text)?
+        .into_iter()
+        .map(|(symbol, args)| {
+            let params: Vec<String> = args.iter().map(|a| a.trim().to_string()).collect();
+            match params.iter().find(|p| !p.chars().all(|c| c.is_alphanumeric() || c == '_') || p.is_empty()) {
+                Some(bad) => Err(format!("formal parameter '{bad}' is not a name")),
+                None      => Ok(Pattern { symbol, params }),
+            }
+        })
+        .collect()
+}
+
+/// Splits `F(a,b)+[G]` into symbols and their raw argument texts.
+fn modules(text: &str) -> Result<Vec<(char, Vec<String>)>, String> {
+    let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let symbol = chars[i];
+        i += 1;
+        let mut args = Vec::new();
+        if chars.get(i) == Some(&'(') {
+            let (mut depth, mut current) = (1, String::new());
+            i += 1;
+            loop {
+                let c = *chars.get(i).ok_or(format!("unclosed '(' after '{symbol}'"))?;
+                i += 1;
+                match c {
+                    '(' => depth += 1,
+                    ')' => {
+                        depth -= 1;
+                        if depth == 0 { break; }
+                    }
+                    ',' if depth == 1 => {
+                        args.push(std::mem::take(&mut current));
+                        continue;
+                    }
+                    _ => {}
+                }
+                current.push(c);
+            }
+            args.push(current);
+        }
+        out.push((symbol, args));
+    }
+    Ok(out)
+}
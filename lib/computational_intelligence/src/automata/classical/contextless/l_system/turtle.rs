@@ -0,0 +1,179 @@
+//! Turtle interpretation of L‑system words and rasterisation onto a grid.
+//!
+//! | symbol      | action                                           |
+//! |-------------|--------------------------------------------------|
+//! | `F`, `G`    | draw forward by the first parameter, else `step` |
+//! | `f`         | move forward without drawing                     |
+//! | `+` / `-`   | turn left / right by the parameter, else `angle` |
+//! | `\|`        | turn around                                      |
+//! | `[` / `]`   | push / pop position and heading                  |
+//!
+//! Every other symbol is ignored.
+
+use bevy::math::{IVec2, Vec2};
+use engine_core::{core::cell::CellState, engine::grid::GridBackend};
+
+use super::rules::Module;
+
+/// Stroke colours: `Alive(1 + depth)`, capped.
+pub const DEPTH_SHADES: u8 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Turtle {
+    /// Turn, degrees.
+    pub angle:   f32,
+    pub step:    f32,
+    /// Initial heading, degrees anticlockwise from +x.
+    pub heading: f32,
+}
+
+impl Default for Turtle {
+    fn default() -> Self { Self { angle: 90.0, step: 1.0, heading: 90.0 } }
+}
+
+/// A connected stroke and its bracket depth.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polyline {
+    pub points: Vec<Vec2>,
+    pub depth:  u32,
+}
+
+impl Turtle {
+    /// Strokes traced by `word`, each at least two points long.
+    ///
+    /// ```
+    /// use bevy::math::Vec2;
+    /// use computational_intelligence::automata::classical::contextless::l_system::{
+    ///     rules::LSystem, turtle::Turtle,
+    /// };
+    ///
+    /// let sys = LSystem::parse("axiom: FF[+F]fF").unwrap();
+    /// let lines = Turtle::default().interpret(&sys.axiom);
+    /// assert_eq!(lines.len(), 3);
+    /// assert_eq!(lines[0].points.len(), 3);
+    /// assert_eq!(lines[1].depth, 1);
+    /// assert!(lines[1].points[1].abs_diff_eq(Vec2::new(-1.0, 2.0), 1e-5));
+    /// assert!(lines[2].points[1].abs_diff_eq(Vec2::new(0.0, 4.0), 1e-5));
+    /// ```
+    pub fn interpret(&self, word: &[Module]) -> Vec<Polyline> {
+        let mut lines = Vec::new();
+        let mut stack = Vec::new();
+        let (mut pos, mut heading) = (Vec2::ZERO, self.heading.to_radians());
+        let mut current = Polyline { points: vec![pos], depth: 0 };
+        let flush = |current: &mut Polyline, lines: &mut Vec<Polyline>, pos: Vec2, depth: u32| {
+            let done = std::mem::replace(current, Polyline { points: vec![pos], depth });
+            if done.points.len() > 1 { lines.push(done); }
+        };
+
+        for m in word {
+            let arg = m.params.first().map(|&p| p as f32);
+            match m.symbol {
+                'F' | 'G' => {
+                    pos += Vec2::from_angle(heading) * arg.unwrap_or(self.step);
+                    current.points.push(pos);
+                }
+                'f' => {
+                    pos += Vec2::from_angle(heading) * arg.unwrap_or(self.step);
+                    flush(&mut current, &mut lines, pos, stack.len() as u32);
+                }
+                '+' => heading += arg.unwrap_or(self.angle).to_radians(),
+                '-' => heading -= arg.unwrap_or(self.angle).to_radians(),
+                '|' => heading += std::f32::consts::PI,
+                '[' => {
+                    stack.push((pos, heading));
+                    flush(&mut current, &mut lines, pos, stack.len() as u32);
+                }
+                ']' => {
+                    if let Some((p, h)) = stack.pop() {
+                        (pos, heading) = (p, h);
+                    }
+                    flush(&mut current, &mut lines, pos, stack.len() as u32);
+                }
+                _ => {}
+            }
+        }
+        flush(&mut current, &mut lines, pos, 0);
+        lines
+    }
+}
+
+/// Side of the square a sparse grid's drawing is scaled into.
+const SPARSE_ROOM: f32 = 512.0;
+
+/// Draws `lines` into `grid`, replacing its contents.  A dense grid gets the
+/// drawing scaled to fit, centred horizontally and standing on the bottom
+/// row; a sparse grid gets it scaled into a [`SPARSE_ROOM`] square centred
+/// on and standing on the origin; graphs are left alone.
+///
+/// ```
+/// use bevy::math::{IVec2, UVec2, Vec2};
+/// use engine_core::{core::cell::CellState, engine::grid::{DenseGrid, GridBackend}};
+/// use computational_intelligence::automata::classical::contextless::l_system::turtle::{rasterise, Polyline};
+///
+/// let mut grid = GridBackend::Dense(DenseGrid::blank(UVec2::new(9, 9)));
+/// rasterise(&[Polyline { points: vec![Vec2::ZERO, Vec2::new(0.0, 1.0)], depth: 0 }], &mut grid);
+/// let GridBackend::Dense(g) = &grid else { unreachable!() };
+/// let live: Vec<IVec2> = g.iter().filter(|(_, c)| c.state != CellState::Dead).map(|(p, _)| p).collect();
+/// assert_eq!(live.len(), 7);
+/// assert!(live.iter().all(|p| p.x == 4));
+/// ```
+pub fn rasterise(lines: &[Polyline], grid: &mut GridBackend) {
+    let shade = |depth: u32| CellState::Alive(1 + depth.min(DEPTH_SHADES as u32 - 1) as u8);
+    match grid {
+        GridBackend::Dense(g) => {
+            g.cells.iter_mut().for_each(|c| c.state = CellState::Dead);
+            let room = (g.size.as_vec2() - Vec2::splat(3.0)).max(Vec2::ONE);
+            let Some(to_cell) = fit(lines, room, (g.size.x - 1) as f32 * 0.5, 1.0) else { return };
+            for l in lines {
+                for w in l.points.windows(2) {
+                    for c in bresenham(to_cell(w[0]), to_cell(w[1])) {
+                        if let Some(cell) = g.get_mut(c) { cell.state = shade(l.depth); }
+                    }
+                }
+            }
+        }
+        GridBackend::Sparse(s) => {
+            s.map.clear();
+            let Some(to_cell) = fit(lines, Vec2::splat(SPARSE_ROOM), 0.0, 0.0) else { return };
+            for l in lines {
+                for w in l.points.windows(2) {
+                    for c in bresenham(to_cell(w[0]), to_cell(w[1])) {
+                        s.set_state(c, shade(l.depth));
+                    }
+                }
+            }
+        }
+        GridBackend::Graph(_) => {}
+    }
+}
+
+/// Maps turtle coordinates to cells so the drawing fits `room`, centred
+/// horizontally on `centre_x` and standing on row `base`; `None` without
+/// points.
+fn fit(lines: &[Polyline], room: Vec2, centre_x: f32, base: f32) -> Option<impl Fn(Vec2) -> IVec2> {
+    let points   = lines.iter().flat_map(|l| &l.points);
+    let (lo, hi) = points.fold((Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)), |(lo, hi), p| {
+        (lo.min(*p), hi.max(*p))
+    });
+    if lo.x > hi.x { return None; }
+    let span   = (hi - lo).max(Vec2::splat(1e-6));
+    let scale  = (room.x / span.x).min(room.y / span.y);
+    let origin = Vec2::new(centre_x - span.x * scale * 0.5, base);
+    Some(move |p: Vec2| ((p - lo) * scale + origin).round().as_ivec2())
+}
+
+/// Cells on the segment `a → b`, both ends included.
+fn bresenham(a: IVec2, b: IVec2) -> Vec<IVec2> {
+    let d    = (b - a).abs();
+    let s    = (b - a).signum();
+    let mut err = d.x - d.y;
+    let mut p   = a;
+    let mut out = vec![p];
+    while p != b {
+        let e2 = 2 * err;
+        if e2 > -d.y { err -= d.y; p.x += s.x; }
+        if e2 <  d.x { err += d.x; p.y += s.y; }
+        out.push(p);
+    }
+    out
+}
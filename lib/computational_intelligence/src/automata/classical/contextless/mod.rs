@@ -1,5 +1,8 @@
 //! Type‑2 **Context‑Free** automata (push‑down, L‑systems …).
-//! Right now only the plugin skeleton exists.
+//! Grammars with FIRST/FOLLOW and LL(1) tables, Chomsky normal form, CYK
+//! and Earley parsing, general nondeterministic PDAs and the CFG → PDA
+//! construction, and L‑systems drawn by a turtle, whose presets the plugin
+//! makes spawnable.
 
 use bevy::prelude::*;
 
@@ -7,11 +10,16 @@ use bevy::prelude::*;
 pub struct ContextFreeAutomataPlugin;
 
 impl Plugin for ContextFreeAutomataPlugin {
-    fn build(&self, _app: &mut App) {
-        // register rules here later
+    fn build(&self, app: &mut App) {
+        app.add_plugins(l_system::plugin::LSystemPlugin);
     }
 }
 
-/* ───── sub‑modules to be filled in ───── */
+/* ───── sub‑modules ───── */
+pub mod cnf;
+pub mod cyk;
+pub mod earley;
+pub mod grammar;
 pub mod ll1_pushdown;
 pub mod l_system;
+pub mod pda;
@@ -0,0 +1,221 @@
+//! General nondeterministic push‑down automata with ε‑moves, acceptance by
+//! final state or by empty stack, and the top‑down CFG → PDA construction.
+//!
+//! Input and stack symbols are strings so that a PDA built from a
+//! [`Grammar`] runs directly on its terminals.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use super::grammar::{Grammar, Symbol};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Acceptance {
+    #[default]
+    FinalState,
+    EmptyStack,
+}
+
+/// `(from, input, pop) → (to, push)`; `None` input or pop is an ε‑move,
+/// `push[0]` ends up on top of the stack.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PdaMove {
+    pub from:  usize,
+    pub input: Option<String>,
+    pub pop:   Option<String>,
+    pub to:    usize,
+    pub push:  Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PdaConfig {
+    pub state: usize,
+    /// Input symbols consumed so far.
+    pub pos:   usize,
+    /// Bottom first, top last.
+    pub stack: Vec<String>,
+}
+
+/// One configuration of an accepting computation and the move that
+/// produced it (`None` for the initial configuration).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PdaStep {
+    pub via:    Option<usize>,
+    pub config: PdaConfig,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PdaRun {
+    pub accepted:  bool,
+    /// Shortest accepting computation, when one was found.
+    pub trace:     Option<Vec<PdaStep>>,
+    /// Configurations explored.
+    pub explored:  usize,
+    /// The search hit its limit before the frontier emptied, so a
+    /// rejection is not conclusive.
+    pub exhausted: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PDA {
+    pub names:       Vec<String>,
+    pub start:       usize,
+    pub start_stack: Vec<String>,
+    pub accepting:   BTreeSet<usize>,
+    pub acceptance:  Acceptance,
+    pub moves:       Vec<PdaMove>,
+}
+
+impl PDA {
+    /// States named `names`, starting in the first with `start_stack`
+    /// (bottom first) and accepting by `acceptance`.
+    pub fn new(names: &[&str], start_stack: &[&str], acceptance: Acceptance) -> Self {
+        Self {
+            names:       names.iter().map(|s| s.to_string()).collect(),
+            start:       0,
+            start_stack: start_stack.iter().map(|s| s.to_string()).collect(),
+            accepting:   BTreeSet::new(),
+            acceptance,
+            moves:       Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize { self.names.len() }
+
+    pub fn is_empty(&self) -> bool { self.names.is_empty() }
+
+    pub fn add_move(&mut self, from: usize, input: Option<&str>, pop: Option<&str>, to: usize, push: &[&str]) {
+        self.moves.push(PdaMove {
+            from,
+            input: input.map(str::to_string),
+            pop:   pop.map(str::to_string),
+            to,
+            push:  push.iter().map(|s| s.to_string()).collect(),
+        });
+    }
+
+    pub fn initial(&self) -> PdaConfig {
+        PdaConfig { state: self.start, pos: 0, stack: self.start_stack.clone() }
+    }
+
+    pub fn is_accepting(&self, c: &PdaConfig, input_len: usize) -> bool {
+        c.pos == input_len
+            && match self.acceptance {
+                Acceptance::FinalState => self.accepting.contains(&c.state),
+                Acceptance::EmptyStack => c.stack.is_empty(),
+            }
+    }
+
+    /// Every `(move index, successor)` of `c` on `input`.
+    pub fn successors(&self, c: &PdaConfig, input: &[String]) -> Vec<(usize, PdaConfig)> {
+        let next = input.get(c.pos);
+        self.moves
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| m.from == c.state)
+            .filter(|(_, m)| m.input.is_none() || m.input.as_ref() == next)
+            .filter(|(_, m)| m.pop.is_none() || m.pop.as_ref() == c.stack.last())
+            .map(|(i, m)| {
+                let mut stack = c.stack.clone();
+                if m.pop.is_some() {
+                    stack.pop();
+                }
+                stack.extend(m.push.iter().rev().cloned());
+                (i, PdaConfig { state: m.to, pos: c.pos + usize::from(m.input.is_some()), stack })
+            })
+            .collect()
+    }
+
+    /// Breadth‑first search of the configuration graph, exploring at most
+    /// `limit` configurations.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::pda::{Acceptance, PDA};
+    ///
+    /// // aⁿbⁿ, n ≥ 0, by final state
+    /// let mut p = PDA::new(&["push", "pop", "done"], &["Z"], Acceptance::FinalState);
+    /// p.add_move(0, Some("a"), None, 0, &["A"]);
+    /// p.add_move(0, None, None, 1, &[]);
+    /// p.add_move(1, Some("b"), Some("A"), 1, &[]);
+    /// p.add_move(1, None, Some("Z"), 2, &[]);
+    /// p.accepting.insert(2);
+    ///
+    /// let tok = |s: &str| s.chars().map(String::from).collect::<Vec<_>>();
+    /// let run = p.run(&tok("aabb"), 1_000);
+    /// assert!(run.accepted);
+    /// assert_eq!(run.trace.unwrap().len(), 7);
+    /// assert!(!p.run(&tok("aab"), 1_000).accepted);
+    /// assert!(!p.run(&tok("abab"), 1_000).exhausted);
+    /// ```
+    pub fn run(&self, input: &[String], limit: usize) -> PdaRun {
+        let init = self.initial();
+        let mut parent: HashMap<PdaConfig, Option<(usize, PdaConfig)>> = HashMap::new();
+        parent.insert(init.clone(), None);
+        let mut queue = VecDeque::from([init]);
+        let mut explored = 0;
+        while let Some(c) = queue.pop_front() {
+            explored += 1;
+            if self.is_accepting(&c, input.len()) {
+                let mut trace = Vec::new();
+                let mut cur = Some(c);
+                while let Some(conf) = cur {
+                    let prev = parent[&conf].clone();
+                    trace.push(PdaStep { via: prev.as_ref().map(|(m, _)| *m), config: conf });
+                    cur = prev.map(|(_, p)| p);
+                }
+                trace.reverse();
+                return PdaRun { accepted: true, trace: Some(trace), explored, exhausted: false };
+            }
+            if explored >= limit {
+                return PdaRun { accepted: false, trace: None, explored, exhausted: true };
+            }
+            for (m, next) in self.successors(&c, input) {
+                if !parent.contains_key(&next) {
+                    parent.insert(next.clone(), Some((m, c.clone())));
+                    queue.push_back(next);
+                }
+            }
+        }
+        PdaRun { accepted: false, trace: None, explored, exhausted: false }
+    }
+
+    pub fn accepts(&self, input: &[String], limit: usize) -> bool { self.run(input, limit).accepted }
+
+    /// Single‑state top‑down parser for `g`, accepting by empty stack:
+    /// expand `A` to `α` on ε for every production, match each terminal
+    /// against the input.  Move `i < g.productions.len()` is production `i`,
+    /// so a trace spells out a leftmost derivation.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::contextless::{grammar::Grammar, pda::PDA};
+    ///
+    /// // even‑length palindromes – not LL(1), fine for a nondeterministic PDA
+    /// let g = Grammar::parse("S -> a S a | b S b | ε").unwrap();
+    /// assert!(!g.ll1_table().is_ll1());
+    /// let p = PDA::from_grammar(&g);
+    /// let run = p.run(&g.tokenize("abba"), 10_000);
+    /// assert!(run.accepted);
+    /// let derivation: Vec<usize> = run.trace.unwrap().iter()
+    ///     .filter_map(|s| s.via)
+    ///     .filter(|&m| m < g.productions.len())
+    ///     .collect();
+    /// assert_eq!(derivation, vec![0, 1, 2]);
+    /// assert!(!p.accepts(&g.tokenize("abab"), 10_000));
+    /// ```
+    pub fn from_grammar(g: &Grammar) -> Self {
+        let mut p = Self::new(&["q"], &[g.start.as_str()], Acceptance::EmptyStack);
+        for prod in &g.productions {
+            let push: Vec<&str> = prod.rhs.iter().map(Symbol::name).collect();
+            p.add_move(0, None, Some(&prod.lhs), 0, &push);
+        }
+        for t in g.terminals() {
+            p.add_move(0, Some(&t), Some(&t), 0, &[]);
+        }
+        p
+    }
+
+    /// Human‑readable trace line, e.g. `q  ab|ba  [S a]`.
+    pub fn describe(&self, c: &PdaConfig, input: &[String]) -> String {
+        let (done, rest) = input.split_at(c.pos.min(input.len()));
+        format!("{}  {}|{}  [{}]", self.names[c.state], done.concat(), rest.concat(), c.stack.join(" "))
+    }
+}
@@ -0,0 +1,297 @@
+//! State diagrams of the classical machines, for Graphviz and for drawing
+//! in‑app.
+//!
+//! Every machine lowers to a [`Diagram`] through [`ToDiagram`]: node `i` is
+//! the machine’s state `i`, so a run’s current states index the nodes
+//! directly, and all moves between two states share one edge listing them.
+//! A diagram prints as Graphviz DOT (`dot -Tsvg machine.dot`), and
+//! [`Diagram::layout`] places the nodes for viewers without Graphviz.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt::{self, Display},
+};
+
+use bevy::math::Vec2;
+
+use super::{
+    contextful::lba::LBA,
+    contextless::pda::{Acceptance, PDA},
+    regular::{
+        dfa::DFA,
+        regex_nfa::NFA,
+        transducer::{Mealy, Moore},
+    },
+    turing::universal_tm::TuringMachine,
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DiagramNode {
+    pub label:     String,
+    pub accepting: bool,
+}
+
+/// Every move from one state to another, one label each.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiagramEdge {
+    pub from:   usize,
+    pub to:     usize,
+    pub labels: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diagram {
+    pub nodes: Vec<DiagramNode>,
+    pub edges: Vec<DiagramEdge>,
+    pub start: usize,
+}
+
+impl Diagram {
+    /// One non‑accepting node per label and no edges.
+    pub fn new<S: ToString>(labels: impl IntoIterator<Item = S>, start: usize) -> Self {
+        let nodes = labels.into_iter().map(|l| DiagramNode { label: l.to_string(), accepting: false }).collect();
+        Self { nodes, edges: Vec::new(), start }
+    }
+
+    /// Adds a move, joining the edge `from → to` if there is one.
+    pub fn add_edge(&mut self, from: usize, to: usize, label: impl Into<String>) {
+        let label = label.into();
+        match self.edges.iter_mut().find(|e| e.from == from && e.to == to) {
+            Some(e) => if !e.labels.contains(&label) { e.labels.push(label) },
+            None    => self.edges.push(DiagramEdge { from, to, labels: vec![label] }),
+        }
+    }
+
+    /// Graphviz DOT source, left to right, accepting states doubly ringed.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::{
+    ///     diagram::ToDiagram,
+    ///     regular::{dfa::DFA, regex_nfa::NFA},
+    /// };
+    ///
+    /// let dfa = DFA::from_nfa(&NFA::from_regex("a*").unwrap()).minimise();
+    /// assert_eq!(dfa.to_dot(), "\
+    /// digraph {
+    ///     rankdir=LR;
+    ///     node [shape=circle];
+    ///     start [shape=point];
+    ///     0 [label=\"q0\", shape=doublecircle];
+    ///     start -> 0;
+    ///     0 -> 0 [label=\"a\"];
+    /// }
+    /// ");
+    /// ```
+    pub fn to_dot(&self) -> String { self.to_string() }
+
+    /// Positions in the unit square: a column per breadth‑first distance
+    /// from the start, unreachable states last, each column ordered by the
+    /// mean height of the states leading into it to keep crossings down.
+    ///
+    /// ```
+    /// use bevy::math::Vec2;
+    /// use computational_intelligence::automata::classical::diagram::Diagram;
+    ///
+    /// let mut d = Diagram::new(["a", "b", "c", "d"], 0);
+    /// d.add_edge(0, 1, "x");
+    /// d.add_edge(0, 2, "y");
+    /// d.add_edge(2, 2, "z");
+    /// assert_eq!(d.layout(), [
+    ///     Vec2::new(0.0, 0.5),
+    ///     Vec2::new(0.5, 0.25),
+    ///     Vec2::new(0.5, 0.75),
+    ///     Vec2::new(1.0, 0.5),
+    /// ]);
+    /// ```
+    pub fn layout(&self) -> Vec<Vec2> {
+        let n = self.nodes.len();
+        let mut depth = vec![usize::MAX; n];
+        let mut queue = VecDeque::new();
+        if self.start < n {
+            depth[self.start] = 0;
+            queue.push_back(self.start);
+        }
+        while let Some(q) = queue.pop_front() {
+            for e in self.edges.iter().filter(|e| e.from == q) {
+                if depth[e.to] == usize::MAX {
+                    depth[e.to] = depth[q] + 1;
+                    queue.push_back(e.to);
+                }
+            }
+        }
+        let unreachable = depth.iter().filter(|&&d| d != usize::MAX).max().map_or(0, |d| d + 1);
+        depth.iter_mut().filter(|d| **d == usize::MAX).for_each(|d| *d = unreachable);
+
+        let columns = depth.iter().max().map_or(0, |d| d + 1);
+        let mut layers: Vec<Vec<usize>> = vec![Vec::new(); columns];
+        for (q, &d) in depth.iter().enumerate() {
+            layers[d].push(q);
+        }
+        let mut pos = vec![Vec2::ZERO; n];
+        for (d, layer) in layers.iter().enumerate() {
+            let mut keys: Vec<(usize, f32)> = layer
+                .iter()
+                .map(|&q| {
+                    let ys: Vec<f32> = self.edges.iter().filter(|e| e.to == q && depth[e.from] < d).map(|e| pos[e.from].y).collect();
+                    (q, if ys.is_empty() { 0.5 } else { ys.iter().sum::<f32>() / ys.len() as f32 })
+                })
+                .collect();
+            keys.sort_by(|a, b| a.1.total_cmp(&b.1));
+            let x = if columns > 1 { d as f32 / (columns - 1) as f32 } else { 0.5 };
+            for (i, &(q, _)) in keys.iter().enumerate() {
+                pos[q] = Vec2::new(x, (i as f32 + 0.5) / keys.len() as f32);
+            }
+        }
+        pos
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+impl fmt::Display for Diagram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph {{")?;
+        writeln!(f, "    rankdir=LR;")?;
+        writeln!(f, "    node [shape=circle];")?;
+        writeln!(f, "    start [shape=point];")?;
+        for (i, n) in self.nodes.iter().enumerate() {
+            let ring = if n.accepting { ", shape=doublecircle" } else { "" };
+            writeln!(f, "    {i} [label={}{ring}];", quote(&n.label))?;
+        }
+        if self.start < self.nodes.len() {
+            writeln!(f, "    start -> {};", self.start)?;
+        }
+        for e in &self.edges {
+            writeln!(f, "    {} -> {} [label={}];", e.from, e.to, quote(&e.labels.join("\n")))?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// Machines with a state diagram.
+pub trait ToDiagram {
+    fn diagram(&self) -> Diagram;
+
+    fn to_dot(&self) -> String { self.diagram().to_dot() }
+}
+
+/// `ε` for the empty word.
+fn word(w: &str) -> &str { if w.is_empty() { "ε" } else { w } }
+
+/* ───── finite automata ───── */
+
+impl ToDiagram for NFA {
+    fn diagram(&self) -> Diagram {
+        let mut d = Diagram::new((0..self.state_count()).map(|q| format!("q{q}")), self.start);
+        for &q in &self.accept_states {
+            d.nodes[q].accepting = true;
+        }
+        let moves: BTreeMap<_, _> = self.transitions.iter().collect();
+        for (&(from, symbol), targets) in moves {
+            for &to in targets {
+                d.add_edge(from, to, symbol.map_or_else(|| "ε".to_string(), String::from));
+            }
+        }
+        d
+    }
+}
+
+impl ToDiagram for DFA {
+    fn diagram(&self) -> Diagram {
+        let mut d = Diagram::new((0..self.len()).map(|q| format!("q{q}")), self.start);
+        for (q, row) in self.delta.iter().enumerate() {
+            d.nodes[q].accepting = self.accept[q];
+            for (&c, &to) in self.alphabet.iter().zip(row) {
+                d.add_edge(q, to, c);
+            }
+        }
+        d
+    }
+}
+
+/// Edges `symbol / output`.
+impl<O: Clone + Display> ToDiagram for Mealy<O> {
+    fn diagram(&self) -> Diagram {
+        let mut d = Diagram::new(&self.names, self.start);
+        for (q, row) in self.delta.iter().enumerate() {
+            for (c, (to, out)) in row {
+                d.add_edge(q, *to, format!("{c} / {}", word(&out.to_string())));
+            }
+        }
+        d
+    }
+}
+
+/// Nodes `state / output`.
+impl<O: Clone + Display> ToDiagram for Moore<O> {
+    fn diagram(&self) -> Diagram {
+        let labels = self.names.iter().zip(&self.output).map(|(n, o)| format!("{n} / {}", word(&o.to_string())));
+        let mut d = Diagram::new(labels, self.start);
+        for (q, row) in self.delta.iter().enumerate() {
+            for (&c, &to) in row {
+                d.add_edge(q, to, c);
+            }
+        }
+        d
+    }
+}
+
+/* ───── push‑down and tape machines ───── */
+
+/// Edges `input, pop → push`, the pushed word top first; accepting states
+/// are marked only when the PDA accepts by final state.
+impl ToDiagram for PDA {
+    fn diagram(&self) -> Diagram {
+        let mut d = Diagram::new(&self.names, self.start);
+        if self.acceptance == Acceptance::FinalState {
+            for &q in &self.accepting {
+                d.nodes[q].accepting = true;
+            }
+        }
+        for m in &self.moves {
+            let input = m.input.as_deref().unwrap_or("ε");
+            let pop = m.pop.as_deref().unwrap_or("ε");
+            d.add_edge(m.from, m.to, format!("{input}, {pop} → {}", word(&m.push.join(" "))));
+        }
+        d
+    }
+}
+
+/// Edges `read → write, move`; every state up to the largest in use gets a
+/// node.
+impl ToDiagram for TuringMachine {
+    fn diagram(&self) -> Diagram {
+        let states = self.transitions
+            .iter()
+            .flat_map(|(&(q, _), t)| [q, t.new_state])
+            .chain([self.start_state, self.accept_state, self.reject_state, self.names.len().saturating_sub(1)])
+            .max()
+            .map_or(0, |m| m + 1);
+        let mut d = Diagram::new((0..states).map(|q| self.name(q)), self.start_state);
+        d.nodes[self.accept_state].accepting = true;
+        let rules: BTreeMap<_, _> = self.transitions.iter().collect();
+        for (&(q, read), t) in rules {
+            d.add_edge(q, t.new_state, format!("{read} → {}, {}", t.write, t.direction.letter()));
+        }
+        d
+    }
+}
+
+/// Edges `read → write, move`, several per pair where it is
+/// nondeterministic.
+impl ToDiagram for LBA {
+    fn diagram(&self) -> Diagram {
+        let mut d = Diagram::new(&self.names, self.start);
+        for &q in &self.accept {
+            d.nodes[q].accepting = true;
+        }
+        for (&(q, read), ts) in &self.delta {
+            for t in ts {
+                d.add_edge(q, t.to, format!("{read} → {}, {}", t.write, t.movement.letter()));
+            }
+        }
+        d
+    }
+}
@@ -4,4 +4,6 @@ pub mod regular;
 pub mod contextless;
 pub mod contextful;
 pub mod turing;
+pub mod tape;
+pub mod diagram;
 pub mod plugin;
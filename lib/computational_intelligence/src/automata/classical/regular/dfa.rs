@@ -0,0 +1,271 @@
+//! Deterministic finite automata: subset construction from an [`NFA`],
+//! Hopcroft minimisation and language equivalence.
+//!
+//! A [`DFA`] is *complete* over its alphabet – the subset construction
+//! keeps the empty set as an explicit dead state – so every state has one
+//! move per symbol.  Symbols outside the alphabet reject.
+
+use std::collections::{hash_map::Entry, BTreeSet, HashMap, HashSet, VecDeque};
+
+use super::regex_nfa::NFA;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DFA {
+    /// Sorted input symbols; column `k` of `delta` reads `alphabet[k]`.
+    pub alphabet: Vec<char>,
+    /// `delta[state][k]` – successor on `alphabet[k]`.
+    pub delta:    Vec<Vec<usize>>,
+    pub start:    usize,
+    pub accept:   Vec<bool>,
+}
+
+/// One step of [`DFA::trace`]: the symbol just read (`None` for the start)
+/// and the state reached (`None` once a symbol outside the alphabet is read).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DfaStep {
+    pub symbol:    Option<char>,
+    pub state:     Option<usize>,
+    pub accepting: bool,
+}
+
+impl DFA {
+    /// Subset construction over the NFA’s own alphabet.
+    pub fn from_nfa(nfa: &NFA) -> Self {
+        Self::from_nfa_over(nfa, &nfa.alphabet())
+    }
+
+    /// Subset construction over `alphabet` (symbols the NFA never reads go
+    /// to the dead state).  States are numbered in breadth‑first order.
+    pub fn from_nfa_over(nfa: &NFA, alphabet: &[char]) -> Self {
+        let mut alphabet = alphabet.to_vec();
+        alphabet.sort_unstable();
+        alphabet.dedup();
+
+        let key   = |set: &HashSet<usize>| set.iter().copied().collect::<BTreeSet<usize>>();
+        let start = nfa.epsilon_closure(&HashSet::from([nfa.start]));
+        let mut index: HashMap<BTreeSet<usize>, usize> = HashMap::from([(key(&start), 0)]);
+        let mut sets  = vec![start];
+        let mut delta = Vec::new();
+
+        let mut i = 0;
+        while i < sets.len() {
+            let row = alphabet
+                .iter()
+                .map(|&c| {
+                    let next = nfa.step_set(&sets[i], c);
+                    let k    = key(&next);
+                    *index.entry(k).or_insert_with(|| {
+                        sets.push(next);
+                        sets.len() - 1
+                    })
+                })
+                .collect();
+            delta.push(row);
+            i += 1;
+        }
+
+        let accept = sets.iter().map(|s| !s.is_disjoint(&nfa.accept_states)).collect();
+        Self { alphabet, delta, start: 0, accept }
+    }
+
+    pub fn len(&self) -> usize { self.delta.len() }
+    pub fn is_empty(&self) -> bool { self.delta.is_empty() }
+
+    /// Successor of `state` on `symbol`, `None` outside the alphabet.
+    pub fn step(&self, state: usize, symbol: char) -> Option<usize> {
+        let k = self.alphabet.binary_search(&symbol).ok()?;
+        Some(self.delta[state][k])
+    }
+
+    pub fn is_match(&self, input: &str) -> bool {
+        input
+            .chars()
+            .try_fold(self.start, |s, c| self.step(s, c))
+            .is_some_and(|s| self.accept[s])
+    }
+
+    /// The run on `input` one symbol at a time, for step‑by‑step display.
+    pub fn trace(&self, input: &str) -> Vec<DfaStep> {
+        let mut state = Some(self.start);
+        let mut steps = vec![DfaStep { symbol: None, state, accepting: self.accept[self.start] }];
+        for c in input.chars() {
+            let Some(s) = state else { break };
+            state = self.step(s, c);
+            steps.push(DfaStep { symbol: Some(c), state, accepting: state.is_some_and(|s| self.accept[s]) });
+        }
+        steps
+    }
+
+    /// The minimal equivalent DFA (Hopcroft’s partition refinement after
+    /// dropping unreachable states), states renumbered breadth‑first from
+    /// the start so equal languages give identical automata.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::{dfa::DFA, regex_nfa::NFA};
+    ///
+    /// // the textbook (a|b)*abb: 5 subset states, 4 minimal ones
+    /// let dfa = DFA::from_nfa(&NFA::from_regex("(a|b)*abb").unwrap());
+    /// assert_eq!(dfa.len(), 5);
+    /// let min = dfa.minimise();
+    /// assert_eq!(min.len(), 4);
+    /// assert_eq!(min, DFA::from_nfa(&NFA::from_regex("(a*b*)*abb").unwrap()).minimise());
+    /// ```
+    pub fn minimise(&self) -> Self {
+        let reachable = self.reachable();
+        let k = self.alphabet.len();
+
+        /* predecessor lists, reachable states only */
+        let mut inverse = vec![vec![Vec::new(); self.len()]; k];
+        for &s in &reachable {
+            for (c, &t) in self.delta[s].iter().enumerate() {
+                inverse[c][t].push(s);
+            }
+        }
+
+        /* initial partition {F, Q∖F} */
+        let mut block_of = vec![usize::MAX; self.len()];
+        let mut blocks: Vec<Vec<usize>> = Vec::new();
+        for accepting in [true, false] {
+            let members: Vec<usize> = reachable.iter().copied().filter(|&s| self.accept[s] == accepting).collect();
+            if !members.is_empty() {
+                members.iter().for_each(|&s| block_of[s] = blocks.len());
+                blocks.push(members);
+            }
+        }
+        let mut work: Vec<usize> = match blocks.len() {
+            2 => vec![if blocks[0].len() <= blocks[1].len() { 0 } else { 1 }],
+            _ => Vec::new(),
+        };
+        let mut in_work: HashSet<usize> = work.iter().copied().collect();
+
+        while let Some(a) = work.pop() {
+            in_work.remove(&a);
+            let splitter = blocks[a].clone();
+            for pre in &inverse {
+                /* X = states with a move into the splitter */
+                let x: HashSet<usize> = splitter.iter().flat_map(|&t| pre[t].iter().copied()).collect();
+                let touched: BTreeSet<usize> = x.iter().map(|&s| block_of[s]).collect();
+                for y in touched {
+                    let (inside, outside): (Vec<usize>, Vec<usize>) =
+                        blocks[y].iter().partition(|s| x.contains(s));
+                    if outside.is_empty() { continue; }
+
+                    let new = blocks.len();
+                    outside.iter().for_each(|&s| block_of[s] = new);
+                    let smaller_is_new = outside.len() <= inside.len();
+                    blocks[y] = inside;
+                    blocks.push(outside);
+                    if in_work.contains(&y) || smaller_is_new {
+                        work.push(new);
+                        in_work.insert(new);
+                    } else {
+                        work.push(y);
+                        in_work.insert(y);
+                    }
+                }
+            }
+        }
+
+        /* quotient automaton, renumbered breadth‑first */
+        let mut order = vec![usize::MAX; blocks.len()];
+        let mut queue = VecDeque::from([block_of[self.start]]);
+        order[block_of[self.start]] = 0;
+        let mut seq = vec![block_of[self.start]];
+        while let Some(b) = queue.pop_front() {
+            let rep = blocks[b][0];
+            for &t in &self.delta[rep] {
+                let tb = block_of[t];
+                if order[tb] == usize::MAX {
+                    order[tb] = seq.len();
+                    seq.push(tb);
+                    queue.push_back(tb);
+                }
+            }
+        }
+        let delta = seq
+            .iter()
+            .map(|&b| self.delta[blocks[b][0]].iter().map(|&t| order[block_of[t]]).collect())
+            .collect();
+        let accept = seq.iter().map(|&b| self.accept[blocks[b][0]]).collect();
+        Self { alphabet: self.alphabet.clone(), delta, start: 0, accept }
+    }
+
+    /// The same automaton as an [`NFA`], for the functions that take one.
+    pub fn to_nfa(&self) -> NFA {
+        let mut nfa = NFA { start: self.start, ..Default::default() };
+        for (s, row) in self.delta.iter().enumerate() {
+            for (k, &t) in row.iter().enumerate() {
+                nfa.add_transition(s, Some(self.alphabet[k]), t);
+            }
+        }
+        nfa.accept_states = (0..self.len()).filter(|&s| self.accept[s]).collect();
+        nfa
+    }
+
+    fn reachable(&self) -> Vec<usize> {
+        let mut seen  = vec![false; self.len()];
+        let mut stack = vec![self.start];
+        seen[self.start] = true;
+        let mut out = Vec::new();
+        while let Some(s) = stack.pop() {
+            out.push(s);
+            for &t in &self.delta[s] {
+                if !seen[t] {
+                    seen[t] = true;
+                    stack.push(t);
+                }
+            }
+        }
+        out.sort_unstable();
+        out
+    }
+}
+
+/* ── equivalence ──────────────────────────────────────────────────────── */
+
+/// A shortest word accepted by exactly one of `a` and `b` (shortlex
+/// first), or `None` if they recognise the same language.
+///
+/// ```
+/// use computational_intelligence::automata::classical::regular::{dfa::distinguishing_word, regex_nfa::NFA};
+///
+/// let re = |p| NFA::from_regex(p).unwrap();
+/// assert_eq!(distinguishing_word(&re("(a|b)*"), &re("(a*b*)*")), None);
+/// assert_eq!(distinguishing_word(&re("a*"), &re("a+")), Some(String::new()));
+/// assert_eq!(distinguishing_word(&re("(ab)*"), &re("a(ba)*b|")), None);
+/// assert_eq!(distinguishing_word(&re("a|b"), &re("[a-c]")), Some("c".into()));
+/// ```
+pub fn distinguishing_word(a: &NFA, b: &NFA) -> Option<String> {
+    let mut alphabet = a.alphabet();
+    alphabet.extend(b.alphabet());
+    let (da, db) = (DFA::from_nfa_over(a, &alphabet), DFA::from_nfa_over(b, &alphabet));
+
+    /* breadth‑first over the product, remembering how each pair was reached */
+    type Pair = (usize, usize);
+    let mut parent: HashMap<Pair, Option<(Pair, char)>> = HashMap::from([((da.start, db.start), None)]);
+    let mut queue = VecDeque::from([(da.start, db.start)]);
+    while let Some((p, q)) = queue.pop_front() {
+        if da.accept[p] != db.accept[q] {
+            let mut word = Vec::new();
+            let mut at   = (p, q);
+            while let Some(Some((prev, c))) = parent.get(&at) {
+                word.push(*c);
+                at = *prev;
+            }
+            return Some(word.into_iter().rev().collect());
+        }
+        for (k, &c) in da.alphabet.iter().enumerate() {
+            let next = (da.delta[p][k], db.delta[q][k]);
+            if let Entry::Vacant(slot) = parent.entry(next) {
+                slot.insert(Some(((p, q), c)));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Whether `a` and `b` recognise the same language.
+pub fn equivalent(a: &NFA, b: &NFA) -> bool {
+    distinguishing_word(a, b).is_none()
+}
@@ -0,0 +1,287 @@
+//! Hidden Markov models over symbol streams.
+//!
+//! Observations are symbol indices `0..symbols`.  The forward and backward
+//! passes are scaled per step, so long streams – a column of Rule 30, say
+//! (see [`column`](super::wolfram_1d::stream::column)) – neither underflow
+//! nor need log space; Viterbi works in log space.  Baum–Welch re‑estimates
+//! every parameter from one or more sequences until the likelihood stops
+//! improving.
+
+use std::fmt;
+
+use rand::Rng;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hmm {
+    /// `initial[i]` – probability of starting in state `i`.
+    pub initial:    Vec<f64>,
+    /// `transition[i][j]` – probability of moving from `i` to `j`.
+    pub transition: Vec<Vec<f64>>,
+    /// `emission[i][k]` – probability of state `i` emitting symbol `k`.
+    pub emission:   Vec<Vec<f64>>,
+}
+
+/// The scaled forward pass: `alpha[t]` sums to 1 and `scale[t]` is what it
+/// summed to before, so the likelihood is the product of the scales.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Forward {
+    pub alpha: Vec<Vec<f64>>,
+    pub scale: Vec<f64>,
+}
+
+impl Forward {
+    /// Natural log of the probability of the observations.
+    pub fn log_likelihood(&self) -> f64 { self.scale.iter().map(|c| c.ln()).sum() }
+}
+
+fn is_distribution(p: &[f64]) -> bool {
+    p.iter().all(|&x| (0.0..=1.0).contains(&x)) && (p.iter().sum::<f64>() - 1.0).abs() < 1e-6
+}
+
+fn normalise(p: &mut [f64]) {
+    let sum: f64 = p.iter().sum();
+    if sum > 0.0 { p.iter_mut().for_each(|x| *x /= sum); }
+}
+
+fn random_distribution(rng: &mut impl Rng, n: usize) -> Vec<f64> {
+    let mut p: Vec<f64> = (0..n).map(|_| rng.random_range(0.1..1.0)).collect();
+    normalise(&mut p);
+    p
+}
+
+/// An index drawn from distribution `p`.
+pub(crate) fn pick(p: &[f64], rng: &mut impl Rng) -> usize {
+    let mut x = rng.random_range(0.0..1.0);
+    p.iter().position(|&q| { x -= q; x < 0.0 }).unwrap_or(p.len().saturating_sub(1))
+}
+
+/// Index of the largest value, the first on ties.
+fn argmax(v: &[f64]) -> usize {
+    (0..v.len()).fold(0, |best, i| if v[i] > v[best] { i } else { best })
+}
+
+impl Hmm {
+    /// Checks that the shapes agree and every row is a distribution.
+    pub fn new(initial: Vec<f64>, transition: Vec<Vec<f64>>, emission: Vec<Vec<f64>>) -> Result<Self, String> {
+        let n = initial.len();
+        if n == 0 || transition.len() != n || emission.len() != n {
+            return Err("initial, transition and emission need one row per state".into());
+        }
+        let symbols = emission[0].len();
+        if transition.iter().any(|r| r.len() != n) || emission.iter().any(|r| r.len() != symbols) {
+            return Err("transition rows need one entry per state, emission rows one per symbol".into());
+        }
+        if !is_distribution(&initial) || !transition.iter().chain(&emission).all(|r| is_distribution(r)) {
+            return Err("every row must be a probability distribution".into());
+        }
+        Ok(Self { initial, transition, emission })
+    }
+
+    /// Random parameters, a starting point for Baum–Welch.  Starts whose
+    /// states emit alike can settle on a model that cannot tell them apart,
+    /// so train from several and keep the likeliest.
+    pub fn random(states: usize, symbols: usize, rng: &mut impl Rng) -> Self {
+        Self {
+            initial:    random_distribution(rng, states),
+            transition: (0..states).map(|_| random_distribution(rng, states)).collect(),
+            emission:   (0..states).map(|_| random_distribution(rng, symbols)).collect(),
+        }
+    }
+
+    pub fn states(&self) -> usize { self.initial.len() }
+    pub fn symbols(&self) -> usize { self.emission.first().map_or(0, Vec::len) }
+
+    fn emit(&self, state: usize, symbol: usize) -> f64 {
+        self.emission[state].get(symbol).copied().unwrap_or(0.0)
+    }
+
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::hmm::Hmm;
+    ///
+    /// // Healthy / fever emitting normal, cold, dizzy.
+    /// let hmm = Hmm::new(
+    ///     vec![0.6, 0.4],
+    ///     vec![vec![0.7, 0.3], vec![0.4, 0.6]],
+    ///     vec![vec![0.5, 0.4, 0.1], vec![0.1, 0.3, 0.6]],
+    /// ).unwrap();
+    /// let p = hmm.forward(&[0, 1, 2]).log_likelihood().exp();
+    /// assert!((p - 0.03628).abs() < 1e-9);
+    /// let (path, log_p) = hmm.viterbi(&[0, 1, 2]);
+    /// assert_eq!(path, [0, 0, 1]);
+    /// assert!((log_p.exp() - 0.01512).abs() < 1e-9);
+    /// ```
+    pub fn forward(&self, obs: &[usize]) -> Forward {
+        let n = self.states();
+        let mut alpha = Vec::with_capacity(obs.len());
+        let mut scale = Vec::with_capacity(obs.len());
+        for (t, &o) in obs.iter().enumerate() {
+            let mut a: Vec<f64> = if t == 0 {
+                (0..n).map(|i| self.initial[i] * self.emit(i, o)).collect()
+            } else {
+                let prev: &Vec<f64> = &alpha[t - 1];
+                (0..n).map(|j| (0..n).map(|i| prev[i] * self.transition[i][j]).sum::<f64>() * self.emit(j, o)).collect()
+            };
+            let c: f64 = a.iter().sum();
+            normalise(&mut a);
+            alpha.push(a);
+            scale.push(c);
+        }
+        Forward { alpha, scale }
+    }
+
+    /// The backward pass, scaled by the forward pass's `scale`.
+    pub fn backward(&self, obs: &[usize], forward: &Forward) -> Vec<Vec<f64>> {
+        let n = self.states();
+        let mut beta = vec![vec![1.0; n]; obs.len()];
+        for t in (0..obs.len().saturating_sub(1)).rev() {
+            let c = forward.scale[t + 1];
+            for i in 0..n {
+                let sum: f64 = (0..n).map(|j| self.transition[i][j] * self.emit(j, obs[t + 1]) * beta[t + 1][j]).sum();
+                beta[t][i] = if c > 0.0 { sum / c } else { 0.0 };
+            }
+        }
+        beta
+    }
+
+    /// `gamma[t][i]` – probability of being in state `i` at step `t`
+    /// given all the observations.
+    pub fn posteriors(&self, obs: &[usize]) -> Vec<Vec<f64>> {
+        let f = self.forward(obs);
+        let beta = self.backward(obs, &f);
+        f.alpha.iter().zip(&beta).map(|(a, b)| a.iter().zip(b).map(|(x, y)| x * y).collect()).collect()
+    }
+
+    /// The most likely state path, with its log probability jointly with
+    /// the observations.
+    pub fn viterbi(&self, obs: &[usize]) -> (Vec<usize>, f64) {
+        let n = self.states();
+        if obs.is_empty() { return (Vec::new(), 0.0); }
+        let mut delta: Vec<f64> = (0..n).map(|i| (self.initial[i] * self.emit(i, obs[0])).ln()).collect();
+        let mut back = Vec::with_capacity(obs.len());
+        for &o in &obs[1..] {
+            let (next, from): (Vec<f64>, Vec<usize>) = (0..n)
+                .map(|j| {
+                    let scores: Vec<f64> = (0..n).map(|i| delta[i] + self.transition[i][j].ln()).collect();
+                    let i = argmax(&scores);
+                    (scores[i] + self.emit(j, o).ln(), i)
+                })
+                .unzip();
+            delta = next;
+            back.push(from);
+        }
+        let last = argmax(&delta);
+        let mut path = vec![last];
+        for from in back.iter().rev() {
+            path.push(from[*path.last().expect("non-empty")]);
+        }
+        path.reverse();
+        (path, delta[last])
+    }
+
+    /// Baum–Welch on `sequences` for at most `iterations` rounds, stopping
+    /// once the total log likelihood gains less than `tolerance`.  Returns
+    /// the log likelihood before each round and after the last.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::{
+    ///     hmm::Hmm,
+    ///     wolfram_1d::{rules::rule30::Rule30, stream::column},
+    /// };
+    ///
+    /// // Two symbols that come in runs: after training, fewer bits per symbol.
+    /// let stream: Vec<usize> = "0000111100011111000011110000011100001111".bytes().map(|b| (b - b'0') as usize).collect();
+    /// let mut hmm = Hmm::new(
+    ///     vec![0.5, 0.5],
+    ///     vec![vec![0.6, 0.4], vec![0.4, 0.6]],
+    ///     vec![vec![0.6, 0.4], vec![0.4, 0.6]],
+    /// ).unwrap();
+    /// let before = hmm.perplexity(&[stream.clone()]);
+    /// let history = hmm.baum_welch(&[stream.clone()], 100, 1e-9);
+    /// assert!(history.windows(2).all(|w| w[1] >= w[0] - 1e-9));
+    /// assert!(hmm.perplexity(&[stream]) < before.min(1.7));
+    ///
+    /// // The centre column of Rule 30 stays close to a fair coin.
+    /// let bits = column(&Rule30, 257, 200, 128);
+    /// let mut coin = Hmm::new(vec![1.0], vec![vec![1.0]], vec![vec![0.5, 0.5]]).unwrap();
+    /// coin.baum_welch(&[bits.clone()], 10, 1e-9);
+    /// assert!(coin.perplexity(&[bits]) > 1.95);
+    /// ```
+    pub fn baum_welch(&mut self, sequences: &[Vec<usize>], iterations: usize, tolerance: f64) -> Vec<f64> {
+        let (n, m) = (self.states(), self.symbols());
+        let mut history = Vec::new();
+        for _ in 0..iterations {
+            let mut initial = vec![0.0; n];
+            let mut trans = vec![vec![0.0; n]; n];
+            let mut emit = vec![vec![0.0; m]; n];
+            let mut total = 0.0;
+            for obs in sequences.iter().filter(|o| !o.is_empty()) {
+                let f = self.forward(obs);
+                total += f.log_likelihood();
+                let beta = self.backward(obs, &f);
+                for t in 0..obs.len() {
+                    for i in 0..n {
+                        let gamma = f.alpha[t][i] * beta[t][i];
+                        if t == 0 { initial[i] += gamma; }
+                        if let Some(e) = emit[i].get_mut(obs[t]) { *e += gamma; }
+                        if t + 1 < obs.len() && f.scale[t + 1] > 0.0 {
+                            for j in 0..n {
+                                trans[i][j] += f.alpha[t][i] * self.transition[i][j] * self.emit(j, obs[t + 1])
+                                    * beta[t + 1][j] / f.scale[t + 1];
+                            }
+                        }
+                    }
+                }
+            }
+            if history.last().is_some_and(|&last: &f64| total - last < tolerance) {
+                history.push(total);
+                return history;
+            }
+            history.push(total);
+            // Rows without evidence keep their old values.
+            let update = |old: &mut Vec<f64>, mut new: Vec<f64>| {
+                if new.iter().sum::<f64>() > 0.0 {
+                    normalise(&mut new);
+                    *old = new;
+                }
+            };
+            update(&mut self.initial, initial);
+            for (i, (t, e)) in trans.into_iter().zip(emit).enumerate() {
+                update(&mut self.transition[i], t);
+                update(&mut self.emission[i], e);
+            }
+        }
+        history.push(sequences.iter().map(|o| self.forward(o).log_likelihood()).sum());
+        history
+    }
+
+    /// States and observations of a run of `len` steps.
+    pub fn sample(&self, len: usize, rng: &mut impl Rng) -> (Vec<usize>, Vec<usize>) {
+        let (mut states, mut obs): (Vec<usize>, Vec<usize>) = (Vec::with_capacity(len), Vec::with_capacity(len));
+        for t in 0..len {
+            let s = if t == 0 { pick(&self.initial, rng) } else { pick(&self.transition[states[t - 1]], rng) };
+            states.push(s);
+            obs.push(pick(&self.emission[s], rng));
+        }
+        (states, obs)
+    }
+
+    /// `exp` of the negative mean log likelihood per symbol: 2 for a fair
+    /// coin, 1 for a stream the model predicts perfectly.
+    pub fn perplexity(&self, sequences: &[Vec<usize>]) -> f64 {
+        let symbols: usize = sequences.iter().map(Vec::len).sum();
+        if symbols == 0 { return 1.0; }
+        let log: f64 = sequences.iter().map(|o| self.forward(o).log_likelihood()).sum();
+        (-log / symbols as f64).exp()
+    }
+}
+
+impl fmt::Display for Hmm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let row = |r: &[f64]| r.iter().map(|p| format!("{p:.3}")).collect::<Vec<_>>().join(" ");
+        writeln!(f, "initial {}", row(&self.initial))?;
+        for i in 0..self.states() {
+            writeln!(f, "state {i}: to {} | emits {}", row(&self.transition[i]), row(&self.emission[i]))?;
+        }
+        Ok(())
+    }
+}
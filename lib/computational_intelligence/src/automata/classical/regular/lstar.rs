@@ -0,0 +1,332 @@
+//! Angluin's L*: learning a minimal [`DFA`] from a teacher.
+//!
+//! The learner fills an observation table – rows for access words `S` and
+//! their one‑symbol extensions, columns for suffixes `E` – with membership
+//! queries until it is closed and consistent, then offers the DFA it
+//! describes as a hypothesis.  A counterexample refines the table:
+//!
+//! * [`Counterexamples::Angluin`] adds every prefix to `S` (the original);
+//! * [`Counterexamples::MalerPnueli`] adds every suffix to `E`, which keeps
+//!   the table consistent;
+//! * [`Counterexamples::RivestSchapire`] adds one suffix to `E`, found by
+//!   binary search with `log |w|` membership queries.
+//!
+//! Teachers answer from an [`NFA`] (so also from a compiled regex) or from
+//! a black‑box closure, testing equivalence up to a bounded length.
+
+use std::{collections::HashMap, fmt};
+
+use rand::Rng;
+
+use super::{
+    dfa::{distinguishing_word, DFA},
+    regex::RegexError,
+    regex_nfa::NFA,
+};
+
+/// Answers the learner's queries.
+pub trait Teacher {
+    /// Whether `word` is in the language.
+    fn member(&mut self, word: &str) -> bool;
+    /// A word the hypothesis gets wrong, or `None` if it is right.
+    fn counterexample(&mut self, hypothesis: &DFA) -> Option<String>;
+}
+
+/// A teacher that knows the target language as an [`NFA`]; its
+/// equivalence queries are exact.
+#[derive(Clone, Debug)]
+pub struct NfaTeacher {
+    pub target: NFA,
+}
+
+impl NfaTeacher {
+    pub fn new(target: NFA) -> Self { Self { target } }
+
+    pub fn from_regex(pattern: &str) -> Result<Self, RegexError> {
+        Ok(Self::new(NFA::from_regex(pattern)?))
+    }
+}
+
+impl Teacher for NfaTeacher {
+    fn member(&mut self, word: &str) -> bool { self.target.is_match(word) }
+
+    fn counterexample(&mut self, hypothesis: &DFA) -> Option<String> {
+        distinguishing_word(&self.target, &hypothesis.to_nfa())
+    }
+}
+
+/// A black‑box teacher: membership from a closure, equivalence by testing
+/// every word up to `depth` symbols and then `samples` random words up to
+/// `max_len`.
+pub struct ClosureTeacher<F> {
+    pub member:   F,
+    pub alphabet: Vec<char>,
+    pub depth:    usize,
+    pub samples:  usize,
+    pub max_len:  usize,
+}
+
+impl<F: FnMut(&str) -> bool> ClosureTeacher<F> {
+    pub fn new(alphabet: &[char], member: F) -> Self {
+        Self { member, alphabet: alphabet.to_vec(), depth: 6, samples: 1_000, max_len: 20 }
+    }
+}
+
+impl<F: FnMut(&str) -> bool> Teacher for ClosureTeacher<F> {
+    fn member(&mut self, word: &str) -> bool { (self.member)(word) }
+
+    fn counterexample(&mut self, hypothesis: &DFA) -> Option<String> {
+        let mut layer = vec![String::new()];
+        for len in 0..=self.depth {
+            if let Some(w) = layer.iter().find(|w| (self.member)(w) != hypothesis.is_match(w)) {
+                return Some(w.clone());
+            }
+            if len == self.depth || self.alphabet.is_empty() { break; }
+            layer = layer.iter().flat_map(|w| self.alphabet.iter().map(move |&c| format!("{w}{c}"))).collect();
+        }
+        if self.alphabet.is_empty() { return None; }
+        let mut rng = rand::rng();
+        (0..self.samples)
+            .map(|_| {
+                let len = rng.random_range(0..=self.max_len);
+                (0..len).map(|_| self.alphabet[rng.random_range(0..self.alphabet.len())]).collect::<String>()
+            })
+            .find(|w| (self.member)(w) != hypothesis.is_match(w))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Counterexamples {
+    Angluin,
+    MalerPnueli,
+    #[default]
+    RivestSchapire,
+}
+
+/// What a run of [`LStar::learn`] found and what it cost.
+#[derive(Clone, Debug)]
+pub struct Learned {
+    /// The last hypothesis, minimal and numbered as [`DFA::minimise`] does.
+    pub dfa:         DFA,
+    /// Membership queries put to the teacher; repeats are answered from
+    /// the table.
+    pub membership:  u64,
+    pub equivalence: u64,
+    /// State counts of the hypotheses in turn.
+    pub hypotheses:  Vec<usize>,
+    /// Whether the teacher accepted the last hypothesis.
+    pub exact:       bool,
+}
+
+impl fmt::Display for Learned {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} states after {} membership and {} equivalence queries{}",
+            self.dfa.len(),
+            self.membership,
+            self.equivalence,
+            if self.exact { "" } else { " (not confirmed)" },
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LStar {
+    pub alphabet:        Vec<char>,
+    pub counterexamples: Counterexamples,
+    /// Equivalence queries before giving up with the last hypothesis.
+    pub max_rounds:      usize,
+}
+
+/// The observation table and the membership cache behind it.
+struct Table<'t, T> {
+    teacher:  &'t mut T,
+    alphabet: Vec<char>,
+    s:        Vec<String>,
+    e:        Vec<String>,
+    cache:    HashMap<String, bool>,
+    queries:  u64,
+}
+
+impl<T: Teacher> Table<'_, T> {
+    fn member(&mut self, word: &str) -> bool {
+        if let Some(&b) = self.cache.get(word) { return b; }
+        self.queries += 1;
+        let b = self.teacher.member(word);
+        self.cache.insert(word.to_string(), b);
+        b
+    }
+
+    fn row(&mut self, prefix: &str) -> Vec<bool> {
+        (0..self.e.len()).map(|i| self.member(&format!("{prefix}{}", self.e[i]))).collect()
+    }
+
+    /// Extends `S` until every one‑symbol extension has the row of some
+    /// access word.
+    fn close(&mut self) {
+        let mut i = 0;
+        while i < self.s.len() {
+            for k in 0..self.alphabet.len() {
+                let ext = format!("{}{}", self.s[i], self.alphabet[k]);
+                let row = self.row(&ext);
+                if !(0..self.s.len()).any(|j| self.row(&self.s[j].clone()) == row) {
+                    self.s.push(ext);
+                }
+            }
+            i += 1;
+        }
+    }
+
+    /// A suffix telling apart two access words with equal rows, through
+    /// their extensions.
+    fn inconsistency(&mut self) -> Option<String> {
+        for i in 0..self.s.len() {
+            for j in i + 1..self.s.len() {
+                let (a, b) = (self.s[i].clone(), self.s[j].clone());
+                if self.row(&a) != self.row(&b) { continue; }
+                for k in 0..self.alphabet.len() {
+                    let c = self.alphabet[k];
+                    for x in 0..self.e.len() {
+                        let e = self.e[x].clone();
+                        if self.member(&format!("{a}{c}{e}")) != self.member(&format!("{b}{c}{e}")) {
+                            return Some(format!("{c}{e}"));
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// The DFA of the table, with each state's access word.
+    fn hypothesis(&mut self) -> (DFA, Vec<String>) {
+        let mut rows: Vec<Vec<bool>> = Vec::new();
+        let mut access = Vec::new();
+        for i in 0..self.s.len() {
+            let s = self.s[i].clone();
+            let row = self.row(&s);
+            if !rows.contains(&row) {
+                rows.push(row);
+                access.push(s);
+            }
+        }
+        let mut delta = Vec::new();
+        for a in access.clone() {
+            let mut line = Vec::new();
+            for k in 0..self.alphabet.len() {
+                let row = self.row(&format!("{a}{}", self.alphabet[k]));
+                line.push(rows.iter().position(|r| *r == row).expect("the table is closed"));
+            }
+            delta.push(line);
+        }
+        // `e[0]` is the empty suffix.
+        let accept = rows.iter().map(|r| r[0]).collect();
+        (DFA { alphabet: self.alphabet.clone(), delta, start: 0, accept }, access)
+    }
+
+    fn add_suffix(&mut self, e: String) {
+        if !self.e.contains(&e) { self.e.push(e); }
+    }
+
+    fn add_prefix(&mut self, s: String) {
+        if !self.s.contains(&s) { self.s.push(s); }
+    }
+
+    /// Rivest and Schapire: the split of `w` where swapping the prefix for
+    /// its access word changes the answer.
+    fn split(&mut self, dfa: &DFA, access: &[String], w: &[char]) -> String {
+        let alpha = |i: usize, table: &mut Self| {
+            let state = w[..i].iter().try_fold(dfa.start, |s, &c| dfa.step(s, c)).unwrap_or(dfa.start);
+            let rest: String = w[i..].iter().collect();
+            table.member(&format!("{}{rest}", access[state]))
+        };
+        let (mut lo, mut hi) = (0, w.len());
+        let at_lo = alpha(lo, self);
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if alpha(mid, self) == at_lo { lo = mid } else { hi = mid }
+        }
+        w[hi..].iter().collect()
+    }
+}
+
+impl LStar {
+    pub fn new(alphabet: &[char]) -> Self {
+        let mut alphabet = alphabet.to_vec();
+        alphabet.sort_unstable();
+        alphabet.dedup();
+        Self { alphabet, counterexamples: Counterexamples::default(), max_rounds: 1_000 }
+    }
+
+    /// Learns the teacher's language.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::{
+    ///     dfa::DFA,
+    ///     lstar::{ClosureTeacher, Counterexamples, LStar, NfaTeacher},
+    ///     regex_nfa::NFA,
+    /// };
+    ///
+    /// let pattern = "(a|b)*a(a|b)(a|b)";
+    /// let target = DFA::from_nfa(&NFA::from_regex(pattern).unwrap()).minimise();
+    /// for how in [Counterexamples::Angluin, Counterexamples::MalerPnueli, Counterexamples::RivestSchapire] {
+    ///     let learner = LStar { counterexamples: how, ..LStar::new(&['a', 'b']) };
+    ///     let learned = learner.learn(&mut NfaTeacher::from_regex(pattern).unwrap());
+    ///     assert!(learned.exact);
+    ///     assert_eq!(learned.dfa, target);
+    ///     assert_eq!(learned.dfa.len(), 8);
+    /// }
+    ///
+    /// // Words over {0, 1} whose value is a multiple of 3, from a closure.
+    /// let mut teacher = ClosureTeacher::new(&['0', '1'], |w: &str| {
+    ///     w.chars().fold(0, |n, c| (2 * n + (c == '1') as u32) % 3) == 0
+    /// });
+    /// let learned = LStar::new(&['0', '1']).learn(&mut teacher);
+    /// assert_eq!(learned.dfa.len(), 3);
+    /// assert!(learned.dfa.is_match("110") && !learned.dfa.is_match("111"));
+    /// ```
+    pub fn learn<T: Teacher>(&self, teacher: &mut T) -> Learned {
+        let mut table = Table {
+            teacher,
+            alphabet: self.alphabet.clone(),
+            s:        vec![String::new()],
+            e:        vec![String::new()],
+            cache:    HashMap::new(),
+            queries:  0,
+        };
+        let mut hypotheses = Vec::new();
+        let mut equivalence = 0;
+        loop {
+            table.close();
+            if let Some(e) = table.inconsistency() {
+                table.add_suffix(e);
+                continue;
+            }
+            let (dfa, access) = table.hypothesis();
+            hypotheses.push(dfa.len());
+            let counterexample = if equivalence < self.max_rounds {
+                equivalence += 1;
+                table.teacher.counterexample(&dfa)
+            } else {
+                None
+            };
+            // Symbols outside the alphabet cannot be learned from.
+            let usable = counterexample.as_ref().is_none_or(|w| w.chars().all(|c| self.alphabet.contains(&c)));
+            let (Some(w), true) = (&counterexample, usable) else {
+                let exact = counterexample.is_none() && equivalence == hypotheses.len();
+                let (membership, equivalence) = (table.queries, equivalence as u64);
+                return Learned { dfa: dfa.minimise(), membership, equivalence, hypotheses, exact };
+            };
+            let w: Vec<char> = w.chars().collect();
+            match self.counterexamples {
+                Counterexamples::Angluin        => (1..=w.len()).for_each(|i| table.add_prefix(w[..i].iter().collect())),
+                Counterexamples::MalerPnueli    => (0..w.len()).for_each(|i| table.add_suffix(w[i..].iter().collect())),
+                Counterexamples::RivestSchapire => {
+                    let e = table.split(&dfa, &access, &w);
+                    table.add_suffix(e);
+                }
+            }
+        }
+    }
+}
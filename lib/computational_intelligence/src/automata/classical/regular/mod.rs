@@ -1,9 +1,22 @@
 //! Type‑3 regular automata: finite‑state machines, 1D cellular automata, etc.
 //!
-//! Contains implementations for Wolfram 1‑D CA and regex/NFA examples.
+//! Contains implementations for Wolfram 1‑D CA, NFAs compiled from regular
+//! expressions and their minimal DFAs, Mealy / Moore transducers, and
+//! [`lstar`], learning minimal DFAs by querying a teacher, with [`rpni`] for
+//! learning them from labelled examples.  [`pfa`] and [`hmm`] put
+//! probabilities on the moves to model symbol streams, such as the centre
+//! column a running [`wolfram_1d`] automaton records per tick (see
+//! [`wolfram_1d::stream`]).
 
 pub mod wolfram_1d;
+pub mod dfa;
+pub mod hmm;
+pub mod lstar;
+pub mod pfa;
+pub mod rpni;
+pub mod regex;
 pub mod regex_nfa;
+pub mod transducer;
 pub mod plugin;
 
 // Re-export the plugin for external use.
@@ -0,0 +1,172 @@
+//! Probabilistic finite automata: weighted NFAs whose weights are
+//! probabilities, giving a distribution over words.
+//!
+//! Every state splits its mass between stopping and its moves: `stop[q]`
+//! plus the probabilities of all moves out of `q` is 1, and the initial
+//! weights sum to 1.  The probability of a word sums over all paths
+//! reading it (the forward algorithm); [`Pfa::viterbi`] finds the likeliest
+//! single path.  [`Pfa::estimate`] puts maximum‑likelihood weights on a
+//! [`DFA`], for instance one learned by [`lstar`](super::lstar) or
+//! [`rpni`](super::rpni).
+
+use rand::Rng;
+
+use super::{dfa::DFA, hmm::pick};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pfa {
+    pub initial: Vec<f64>,
+    pub stop:    Vec<f64>,
+    /// `moves[q]` – `(symbol, next, probability)` out of `q`.
+    pub moves:   Vec<Vec<(char, usize, f64)>>,
+}
+
+impl Pfa {
+    /// `states` states, all mass on stopping in state 0.
+    pub fn new(states: usize) -> Self {
+        let mut initial = vec![0.0; states];
+        if states > 0 { initial[0] = 1.0; }
+        Self { initial, stop: vec![1.0; states], moves: vec![Vec::new(); states] }
+    }
+
+    pub fn len(&self) -> usize { self.initial.len() }
+    pub fn is_empty(&self) -> bool { self.initial.is_empty() }
+
+    /// Adds a move, taking its probability from `stop[from]`.
+    pub fn add_move(&mut self, from: usize, symbol: char, to: usize, p: f64) {
+        self.stop[from] -= p;
+        self.moves[from].push((symbol, to, p));
+    }
+
+    /// Checks that the weights are probabilities summing as they should.
+    pub fn check(&self) -> Result<(), String> {
+        let ok = |p: f64| (-1e-9..=1.0 + 1e-9).contains(&p);
+        if !self.initial.iter().all(|&p| ok(p)) || (self.initial.iter().sum::<f64>() - 1.0).abs() > 1e-6 {
+            return Err("the initial weights must be a distribution".into());
+        }
+        for q in 0..self.len() {
+            let out: f64 = self.stop[q] + self.moves[q].iter().map(|m| m.2).sum::<f64>();
+            if !ok(self.stop[q]) || !self.moves[q].iter().all(|m| ok(m.2)) || (out - 1.0).abs() > 1e-6 {
+                return Err(format!("state {q}: stopping and moving must add up to 1"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Probability of generating exactly `word`.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::pfa::Pfa;
+    ///
+    /// // a*: each `a` with probability ½, else stop.
+    /// let mut p = Pfa::new(1);
+    /// p.add_move(0, 'a', 0, 0.5);
+    /// assert_eq!(p.probability(""), 0.5);
+    /// assert_eq!(p.probability("aa"), 0.125);
+    /// assert_eq!(p.probability("b"), 0.0);
+    /// assert!((p.perplexity(&["", "a", "aa"]) - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn probability(&self, word: &str) -> f64 {
+        let mut alpha = self.initial.clone();
+        for c in word.chars() {
+            let mut next = vec![0.0; self.len()];
+            for (q, &a) in alpha.iter().enumerate().filter(|(_, a)| **a > 0.0) {
+                for &(s, to, p) in &self.moves[q] {
+                    if s == c { next[to] += a * p; }
+                }
+            }
+            alpha = next;
+        }
+        alpha.iter().zip(&self.stop).map(|(a, s)| a * s).sum()
+    }
+
+    /// The likeliest path reading `word` and its probability, `None` if no
+    /// path reads it.
+    pub fn viterbi(&self, word: &str) -> Option<(Vec<usize>, f64)> {
+        let n = self.len();
+        let mut best: Vec<(f64, Vec<usize>)> = (0..n).map(|q| (self.initial[q], vec![q])).collect();
+        for c in word.chars() {
+            let mut next: Vec<(f64, Vec<usize>)> = vec![(0.0, Vec::new()); n];
+            for (q, (p, path)) in best.iter().enumerate().filter(|(_, b)| b.0 > 0.0) {
+                for &(s, to, w) in &self.moves[q] {
+                    if s == c && p * w > next[to].0 {
+                        let mut path = path.clone();
+                        path.push(to);
+                        next[to] = (p * w, path);
+                    }
+                }
+            }
+            best = next;
+        }
+        best.into_iter()
+            .enumerate()
+            .map(|(q, (p, path))| (p * self.stop[q], path))
+            .filter(|(p, _)| *p > 0.0)
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(p, path)| (path, p))
+    }
+
+    /// A word drawn from the distribution, cut off after `max_len` symbols.
+    pub fn sample(&self, max_len: usize, rng: &mut impl Rng) -> String {
+        let mut q = pick(&self.initial, rng);
+        let mut word = String::new();
+        while word.chars().count() < max_len {
+            let options: Vec<f64> = std::iter::once(self.stop[q]).chain(self.moves[q].iter().map(|m| m.2)).collect();
+            match pick(&options, rng) {
+                0 => break,
+                k => {
+                    let (c, to, _) = self.moves[q][k - 1];
+                    word.push(c);
+                    q = to;
+                }
+            }
+        }
+        word
+    }
+
+    /// `exp` of the negative mean log probability per symbol, the end of
+    /// each word counting as one; infinite if some word is impossible.
+    pub fn perplexity<S: AsRef<str>>(&self, words: &[S]) -> f64 {
+        let symbols: usize = words.iter().map(|w| w.as_ref().chars().count() + 1).sum();
+        let log: f64 = words.iter().map(|w| self.probability(w.as_ref()).ln()).sum();
+        if symbols == 0 { 1.0 } else { (-log / symbols as f64).exp() }
+    }
+
+    /// Maximum‑likelihood weights for `dfa`'s moves from the paths of
+    /// `words`, each count raised by `smoothing` (add‑k) so unseen moves
+    /// keep some mass.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::{dfa::DFA, pfa::Pfa, regex_nfa::NFA};
+    ///
+    /// let dfa = DFA::from_nfa(&NFA::from_regex("(ab)*").unwrap()).minimise();
+    /// let pfa = Pfa::estimate(&dfa, &["", "ab", "ab", "abab"], 0.0);
+    /// assert!(pfa.check().is_ok());
+    /// assert_eq!(pfa.probability("ab"), 0.5 * 0.5);
+    /// assert_eq!(pfa.probability("ba"), 0.0);
+    /// ```
+    pub fn estimate<S: AsRef<str>>(dfa: &DFA, words: &[S], smoothing: f64) -> Self {
+        let k = dfa.alphabet.len();
+        let mut counts = vec![vec![smoothing; k + 1]; dfa.len()];
+        for w in words {
+            let symbols: Option<Vec<usize>> = w.as_ref().chars().map(|c| dfa.alphabet.binary_search(&c).ok()).collect();
+            let Some(symbols) = symbols else { continue };
+            let end = symbols.iter().fold(dfa.start, |q, &c| {
+                counts[q][c] += 1.0;
+                dfa.delta[q][c]
+            });
+            counts[end][k] += 1.0;
+        }
+        let mut pfa = Self::new(dfa.len());
+        pfa.initial = (0..dfa.len()).map(|q| if q == dfa.start { 1.0 } else { 0.0 }).collect();
+        for (q, row) in counts.iter().enumerate() {
+            let total: f64 = row.iter().sum();
+            if total == 0.0 { continue; }
+            for (c, &n) in row[..k].iter().enumerate() {
+                if n > 0.0 { pfa.add_move(q, dfa.alphabet[c], dfa.delta[q][c], n / total); }
+            }
+            pfa.stop[q] = row[k] / total;
+        }
+        pfa
+    }
+}
@@ -2,11 +2,16 @@ use bevy::prelude::*;
 use serde_json::Value;
 use engine_core::{engine::stepper::plugin::StepperPlugin, events::AutomataCommand};
 use engine_core::core::World2D;
+use engine_core::{engine::grid::GridBackend, schedule::MainSet, state::AppState};
  
-use crate::registry::RuleRegistry;
+use crate::{
+    analytics::time_series::TimeSeries,
+    registry::{AutomataRegistry, RuleRegistry},
+};
 use super::wolfram_1d::{
     seed_rule30, 
     seed_rule110, 
+    stream::cell_symbol,
     rules::{
         rule30::Rule30, 
         rule110::Rule110
@@ -31,10 +36,30 @@ impl Plugin for RegularAutomataPlugin {
         Update, 
         Self::on_seed_event
             .run_if(bevy::ecs::schedule::common_conditions::resource_exists::<World2D>)
+        )
+        .add_systems(
+            Update,
+            record_centre_column
+                .after(MainSet::Logic)
+                .run_if(in_state(AppState::InGame)),
         );
      }
  }
 
+/// Per‑tick `centre` series: the cell in the middle of the seeded row, so
+/// the series is the stream that row's centre column carries.
+fn record_centre_column(registry: Res<AutomataRegistry>, mut series: ResMut<TimeSeries>) {
+    for info in registry.list().iter().filter(|i| i.name.starts_with("wolfram:")) {
+        let centre = match &info.grid {
+            GridBackend::Dense(g) => (g.size / 2).as_ivec2(),
+            _                     => IVec2::ZERO,
+        };
+        if let Some(symbol) = cell_symbol(&info.grid, centre) {
+            series.record(info.id, "centre", symbol as f64);
+        }
+    }
+}
+
 impl RegularAutomataPlugin {
     fn on_seed_event(
         mut events: EventReader<AutomataCommand>,
@@ -0,0 +1,262 @@
+//! Regular expressions compiled to an [`NFA`] by Thompson’s construction.
+//!
+//! Syntax: literals, concatenation, `|`, `*`, `+`, `?`, grouping `( … )`,
+//! classes `[abc]`, `[a-z0-9]`, `[^…]`, the wildcard `.`, the shorthands
+//! `\d \w \s` and `\` to escape a metacharacter.  `.` and negated classes
+//! range over printable ASCII plus tab and newline ([`universe`]).
+
+use std::{collections::BTreeSet, fmt};
+
+use super::regex_nfa::NFA;
+
+/// Characters matched by `.` and negated classes.
+pub fn universe() -> impl Iterator<Item = char> {
+    ['\t', '\n'].into_iter().chain(' '..='~')
+}
+
+/// Parsed regular expression.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Regex {
+    /// The empty word ε.
+    Empty,
+    /// Any one character of the set.
+    Class(BTreeSet<char>),
+    Concat(Box<Regex>, Box<Regex>),
+    Alt(Box<Regex>, Box<Regex>),
+    Star(Box<Regex>),
+    Plus(Box<Regex>),
+    Optional(Box<Regex>),
+}
+
+/// Syntax error at character offset `pos`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegexError {
+    pub pos:     usize,
+    pub message: String,
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "regex error at {}: {}", self.pos, self.message)
+    }
+}
+
+impl std::error::Error for RegexError {}
+
+/* ── parser ───────────────────────────────────────────────────────────── */
+
+struct Parser {
+    chars: Vec<char>,
+    pos:   usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> { self.chars.get(self.pos).copied() }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += c.is_some() as usize;
+        c
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, RegexError> {
+        Err(RegexError { pos: self.pos, message: message.into() })
+    }
+
+    /// alt := concat ( '|' concat )*
+    fn alt(&mut self) -> Result<Regex, RegexError> {
+        let mut left = self.concat()?;
+        while self.peek() == Some('|') {
+            self.bump();
+            left = Regex::Alt(Box::new(left), Box::new(self.concat()?));
+        }
+        Ok(left)
+    }
+
+    /// concat := repeat*
+    fn concat(&mut self) -> Result<Regex, RegexError> {
+        let mut parts = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' { break; }
+            parts.push(self.repeat()?);
+        }
+        Ok(parts
+            .into_iter()
+            .reduce(|a, b| Regex::Concat(Box::new(a), Box::new(b)))
+            .unwrap_or(Regex::Empty))
+    }
+
+    /// repeat := atom ( '*' | '+' | '?' )*
+    fn repeat(&mut self) -> Result<Regex, RegexError> {
+        let mut r = self.atom()?;
+        while let Some(op) = self.peek() {
+            r = match op {
+                '*' => Regex::Star(Box::new(r)),
+                '+' => Regex::Plus(Box::new(r)),
+                '?' => Regex::Optional(Box::new(r)),
+                _   => break,
+            };
+            self.bump();
+        }
+        Ok(r)
+    }
+
+    fn atom(&mut self) -> Result<Regex, RegexError> {
+        match self.bump() {
+            Some('(') => {
+                let inner = self.alt()?;
+                if self.bump() != Some(')') { return self.error("missing ')'"); }
+                Ok(inner)
+            }
+            Some('[')  => self.class(),
+            Some('.')  => Ok(Regex::Class(universe().collect())),
+            Some('\\') => self.escape().map(Regex::Class),
+            Some(c @ ('*' | '+' | '?')) => self.error(format!("'{c}' has nothing to repeat")),
+            Some(c)    => Ok(Regex::Class(BTreeSet::from([c]))),
+            None       => self.error("unexpected end of pattern"),
+        }
+    }
+
+    /// The character(s) after a `\`.
+    fn escape(&mut self) -> Result<BTreeSet<char>, RegexError> {
+        Ok(match self.bump() {
+            Some('d') => ('0'..='9').collect(),
+            Some('w') => ('a'..='z').chain('A'..='Z').chain('0'..='9').chain(['_']).collect(),
+            Some('s') => [' ', '\t', '\n'].into(),
+            Some('n') => ['\n'].into(),
+            Some('t') => ['\t'].into(),
+            Some(c)   => [c].into(),
+            None      => return self.error("dangling '\\'"),
+        })
+    }
+
+    /// class := '[' '^'? ( char | char '-' char | escape )+ ']'
+    fn class(&mut self) -> Result<Regex, RegexError> {
+        let negated = self.peek() == Some('^');
+        if negated { self.bump(); }
+        let mut set = BTreeSet::new();
+        loop {
+            let lo = match self.bump() {
+                Some(']')  => break,
+                Some('\\') => { set.extend(self.escape()?); continue; }
+                Some(c)    => c,
+                None       => return self.error("missing ']'"),
+            };
+            if self.peek() == Some('-') && self.chars.get(self.pos + 1).is_some_and(|c| *c != ']') {
+                self.bump();
+                let hi = self.bump().unwrap_or(lo);
+                if hi < lo { return self.error(format!("empty range {lo}-{hi}")); }
+                set.extend(lo..=hi);
+            } else {
+                set.insert(lo);
+            }
+        }
+        if set.is_empty() {
+            return self.error("empty class");
+        }
+        if negated {
+            set = universe().filter(|c| !set.contains(c)).collect();
+        }
+        Ok(Regex::Class(set))
+    }
+}
+
+impl Regex {
+    /// Parses `pattern`.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::regex::Regex;
+    ///
+    /// assert!(Regex::parse("(a|b)*abb").is_ok());
+    /// assert!(Regex::parse("[a-c]+x?").is_ok());
+    /// assert_eq!(Regex::parse("a(b").unwrap_err().pos, 3);
+    /// assert!(Regex::parse("*a").is_err());
+    /// ```
+    pub fn parse(pattern: &str) -> Result<Self, RegexError> {
+        let mut p = Parser { chars: pattern.chars().collect(), pos: 0 };
+        let r = p.alt()?;
+        if p.pos < p.chars.len() {
+            return p.error("unmatched ')'");
+        }
+        Ok(r)
+    }
+
+    /// Thompson’s construction: one start, one accept state, at most two
+    /// ε‑moves out of any state.
+    pub fn to_nfa(&self) -> NFA {
+        let mut nfa = NFA::default();
+        let (start, accept) = thompson(self, &mut nfa, &mut 0);
+        nfa.start = start;
+        nfa.accept_states.insert(accept);
+        nfa
+    }
+}
+
+fn fresh(next: &mut usize) -> usize {
+    *next += 1;
+    *next - 1
+}
+
+/// Builds the fragment of `r`, returning its `(start, accept)`.
+fn thompson(r: &Regex, nfa: &mut NFA, next: &mut usize) -> (usize, usize) {
+    let eps = |nfa: &mut NFA, a: usize, b: usize| nfa.add_transition(a, None, b);
+    match r {
+        Regex::Empty => {
+            let (s, f) = (fresh(next), fresh(next));
+            eps(nfa, s, f);
+            (s, f)
+        }
+        Regex::Class(set) => {
+            let (s, f) = (fresh(next), fresh(next));
+            for &c in set {
+                nfa.add_transition(s, Some(c), f);
+            }
+            (s, f)
+        }
+        Regex::Concat(a, b) => {
+            let (s1, f1) = thompson(a, nfa, next);
+            let (s2, f2) = thompson(b, nfa, next);
+            eps(nfa, f1, s2);
+            (s1, f2)
+        }
+        Regex::Alt(a, b) => {
+            let s = fresh(next);
+            let (s1, f1) = thompson(a, nfa, next);
+            let (s2, f2) = thompson(b, nfa, next);
+            let f = fresh(next);
+            eps(nfa, s, s1);
+            eps(nfa, s, s2);
+            eps(nfa, f1, f);
+            eps(nfa, f2, f);
+            (s, f)
+        }
+        Regex::Star(a) | Regex::Plus(a) | Regex::Optional(a) => {
+            let s = fresh(next);
+            let (s1, f1) = thompson(a, nfa, next);
+            let f = fresh(next);
+            eps(nfa, s, s1);
+            eps(nfa, f1, f);
+            if !matches!(r, Regex::Plus(_)) { eps(nfa, s, f); }
+            if !matches!(r, Regex::Optional(_)) { eps(nfa, f1, s1); }
+            (s, f)
+        }
+    }
+}
+
+impl NFA {
+    /// Compiles `pattern` (see the [module docs](self)).
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::regex_nfa::NFA;
+    ///
+    /// let nfa = NFA::from_regex("(a|b)*abb").unwrap();
+    /// assert!(nfa.is_match("abb") && nfa.is_match("babaabb"));
+    /// assert!(!nfa.is_match("abba") && !nfa.is_match(""));
+    ///
+    /// let id = NFA::from_regex(r"[a-z_]\w*").unwrap();
+    /// assert!(id.is_match("snake_case2") && !id.is_match("2fast"));
+    /// ```
+    pub fn from_regex(pattern: &str) -> Result<Self, RegexError> {
+        Ok(Regex::parse(pattern)?.to_nfa())
+    }
+}
@@ -1,8 +1,20 @@
 //! Nondeterministic Finite Automaton (NFA) implementation and regex example.
+//!
+//! Regexes compile to this type via [`NFA::from_regex`](super::regex);
+//! [`DFA`](super::dfa::DFA) determinises and minimises it.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
-#[derive(Debug)]
+/// One step of [`NFA::trace`]: the symbol just read (`None` for the start)
+/// and the ε‑closed set of states reached.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NfaStep {
+    pub symbol:    Option<char>,
+    pub states:    BTreeSet<usize>,
+    pub accepting: bool,
+}
+
+#[derive(Clone, Debug, Default)]
 pub struct NFA {
     pub transitions: HashMap<(usize, Option<char>), Vec<usize>>,
     pub start: usize,
@@ -10,8 +22,33 @@ pub struct NFA {
 }
 
 impl NFA {
+    /// Adds the move `from –symbol→ to` (`None` = ε).
+    pub fn add_transition(&mut self, from: usize, symbol: Option<char>, to: usize) {
+        let targets = self.transitions.entry((from, symbol)).or_default();
+        if !targets.contains(&to) {
+            targets.push(to);
+        }
+    }
+
+    /// One more than the largest state index in use.
+    pub fn state_count(&self) -> usize {
+        self.transitions
+            .iter()
+            .flat_map(|((from, _), to)| std::iter::once(from).chain(to))
+            .chain(self.accept_states.iter())
+            .chain(std::iter::once(&self.start))
+            .max()
+            .map_or(0, |m| m + 1)
+    }
+
+    /// Every symbol on some transition, sorted.
+    pub fn alphabet(&self) -> Vec<char> {
+        let set: BTreeSet<char> = self.transitions.keys().filter_map(|(_, c)| *c).collect();
+        set.into_iter().collect()
+    }
+
     /// Compute the epsilon-closure of a set of states (all states reachable via ε-moves).
-    fn epsilon_closure(&self, states: &HashSet<usize>) -> HashSet<usize> {
+    pub fn epsilon_closure(&self, states: &HashSet<usize>) -> HashSet<usize> {
         let mut closure = states.clone();
         let mut stack: Vec<usize> = states.iter().cloned().collect();
         while let Some(s) = stack.pop() {
@@ -52,6 +89,44 @@ impl NFA {
         // after consuming input, check if any state is accepting
         current_states.intersection(&self.accept_states).next().is_some()
     }
+
+    /// States reachable from `states` by one `symbol` move, ε‑closed.
+    pub fn step_set(&self, states: &HashSet<usize>, symbol: char) -> HashSet<usize> {
+        let next = states
+            .iter()
+            .filter_map(|&s| self.transitions.get(&(s, Some(symbol))))
+            .flatten()
+            .copied()
+            .collect();
+        self.epsilon_closure(&next)
+    }
+
+    /// The run on `input` one symbol at a time, for step‑by‑step display.
+    /// Stops early once no state is left.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::regex_nfa::example_nfa;
+    ///
+    /// let trace = example_nfa().trace("aab");
+    /// assert_eq!(trace.len(), 4);
+    /// assert_eq!(trace[3].symbol, Some('b'));
+    /// assert!(trace[3].accepting && !trace[2].accepting);
+    /// ```
+    pub fn trace(&self, input: &str) -> Vec<NfaStep> {
+        let snapshot = |symbol, states: &HashSet<usize>| NfaStep {
+            symbol,
+            states:    states.iter().copied().collect(),
+            accepting: !states.is_disjoint(&self.accept_states),
+        };
+        let mut current = self.epsilon_closure(&HashSet::from([self.start]));
+        let mut steps   = vec![snapshot(None, &current)];
+        for ch in input.chars() {
+            if current.is_empty() { break; }
+            current = self.step_set(&current, ch);
+            steps.push(snapshot(Some(ch), &current));
+        }
+        steps
+    }
 }
 
 /// Constructs a simple NFA that matches the regex `a* b` (any number of 'a's followed by a 'b').
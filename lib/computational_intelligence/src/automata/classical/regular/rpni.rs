@@ -0,0 +1,332 @@
+//! Passive DFA inference by state merging: RPNI and EDSM.
+//!
+//! Both start from the prefix tree of a labelled [`Sample`] and merge its
+//! states along the red–blue frontier: red states are final, blue ones are
+//! their children still heading subtrees.  Merging a blue state into a red
+//! one folds its subtree in, and is refused if an accepted and a rejected
+//! word would meet.
+//!
+//! * [`rpni`] takes the blue states in shortlex order and merges each into
+//!   the first red state that allows it, else makes it red.  It is exact in
+//!   the limit: from a sample containing a characteristic set it finds the
+//!   minimal DFA of the target.
+//! * [`edsm`] scores every red–blue pair by the labelled states the merge
+//!   would unite and takes the best, promoting first any blue state that
+//!   merges nowhere.  It needs fewer examples in practice.
+//!
+//! [`evaluate`] compares a learned DFA with a target [`NFA`] on held‑out
+//! words.
+
+use std::collections::{btree_map::Entry, BTreeMap};
+
+use rand::Rng;
+
+use super::{
+    dfa::{distinguishing_word, DFA},
+    regex_nfa::NFA,
+};
+
+/// Accepted and rejected example words.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Sample {
+    pub positive: Vec<String>,
+    pub negative: Vec<String>,
+}
+
+impl Sample {
+    pub fn new(positive: &[&str], negative: &[&str]) -> Self {
+        let own = |w: &[&str]| w.iter().map(|s| s.to_string()).collect();
+        Self { positive: own(positive), negative: own(negative) }
+    }
+
+    /// `words` split by whether `target` accepts them.
+    pub fn labelled<S: AsRef<str>>(target: &NFA, words: &[S]) -> Self {
+        let (positive, negative) = words.iter().map(|w| w.as_ref().to_string()).partition(|w| target.is_match(w));
+        Self { positive, negative }
+    }
+
+    /// Every symbol of the examples, sorted.
+    pub fn alphabet(&self) -> Vec<char> {
+        let mut a: Vec<char> = self.positive.iter().chain(&self.negative).flat_map(|w| w.chars()).collect();
+        a.sort_unstable();
+        a.dedup();
+        a
+    }
+}
+
+/// Every word over `alphabet` up to `len` symbols, in shortlex order.
+pub fn words_up_to(alphabet: &[char], len: usize) -> Vec<String> {
+    let mut out = vec![String::new()];
+    let mut layer = out.clone();
+    for _ in 0..len {
+        layer = layer.iter().flat_map(|w| alphabet.iter().map(move |&c| format!("{w}{c}"))).collect();
+        out.extend(layer.iter().cloned());
+    }
+    out
+}
+
+/// `count` random words over `alphabet` of up to `max_len` symbols.
+pub fn random_words(alphabet: &[char], count: usize, max_len: usize) -> Vec<String> {
+    if alphabet.is_empty() { return vec![String::new(); count]; }
+    let mut rng = rand::rng();
+    (0..count)
+        .map(|_| {
+            let len = rng.random_range(0..=max_len);
+            (0..len).map(|_| alphabet[rng.random_range(0..alphabet.len())]).collect()
+        })
+        .collect()
+}
+
+/* ───── prefix tree and merging ───── */
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    next:  BTreeMap<char, usize>,
+    /// `Some(true)` if a positive example ends here, `Some(false)` if a
+    /// negative one does.
+    label: Option<bool>,
+}
+
+/// The automaton being merged, with its red states.
+#[derive(Clone, Debug)]
+struct Merger {
+    nodes: Vec<Node>,
+    red:   Vec<usize>,
+}
+
+impl Merger {
+    /// The prefix tree, numbered in shortlex order of access words.
+    fn new(sample: &Sample) -> Result<Self, String> {
+        let mut trie = vec![Node::default()];
+        for (words, label) in [(&sample.positive, true), (&sample.negative, false)] {
+            for w in words {
+                let mut at = 0;
+                for c in w.chars() {
+                    at = match trie[at].next.get(&c) {
+                        Some(&n) => n,
+                        None => {
+                            trie.push(Node::default());
+                            let n = trie.len() - 1;
+                            trie[at].next.insert(c, n);
+                            n
+                        }
+                    };
+                }
+                if trie[at].label.is_some_and(|l| l != label) {
+                    return Err(format!("\"{w}\" is both a positive and a negative example"));
+                }
+                trie[at].label = Some(label);
+            }
+        }
+        // Renumber breadth‑first; children are already in symbol order.
+        let mut order = vec![0];
+        let mut index = vec![usize::MAX; trie.len()];
+        index[0] = 0;
+        let mut i = 0;
+        while i < order.len() {
+            for &c in trie[order[i]].next.values() {
+                index[c] = order.len();
+                order.push(c);
+            }
+            i += 1;
+        }
+        let nodes = order
+            .iter()
+            .map(|&o| Node {
+                next:  trie[o].next.iter().map(|(&c, &n)| (c, index[n])).collect(),
+                label: trie[o].label,
+            })
+            .collect();
+        Ok(Self { nodes, red: vec![0] })
+    }
+
+    /// Children of red states that are not red, lowest (shortlex) first.
+    fn blue(&self) -> Vec<usize> {
+        let mut blue: Vec<usize> = self
+            .red
+            .iter()
+            .flat_map(|&r| self.nodes[r].next.values().copied())
+            .filter(|n| !self.red.contains(n))
+            .collect();
+        blue.sort_unstable();
+        blue.dedup();
+        blue
+    }
+
+    /// Merges blue state `b` into red state `r`: the automaton after the
+    /// merge and the number of labelled states united, or `None` on a
+    /// conflict.
+    fn merge(&self, r: usize, b: usize) -> Option<(Self, usize)> {
+        let mut m = self.clone();
+        for &red in &self.red {
+            for n in m.nodes[red].next.values_mut() {
+                if *n == b { *n = r; }
+            }
+        }
+        let mut score = 0;
+        let mut stack = vec![(r, b)];
+        while let Some((r, b)) = stack.pop() {
+            match (m.nodes[r].label, m.nodes[b].label) {
+                (Some(x), Some(y)) if x != y => return None,
+                (Some(_), Some(_))           => score += 1,
+                (None, l)                    => m.nodes[r].label = l,
+                _                            => {}
+            }
+            for (c, child) in std::mem::take(&mut m.nodes[b].next) {
+                match m.nodes[r].next.get(&c) {
+                    Some(&rc) => stack.push((rc, child)),
+                    None      => { m.nodes[r].next.insert(c, child); }
+                }
+            }
+        }
+        Some((m, score))
+    }
+
+    /// The complete DFA over `alphabet`, unlabelled states rejecting and a
+    /// dead state for missing moves, minimised.
+    fn dfa(&self, alphabet: &[char]) -> DFA {
+        let mut index = BTreeMap::from([(0, 0)]);
+        let mut order = vec![0];
+        let mut i = 0;
+        while i < order.len() {
+            for &n in self.nodes[order[i]].next.values() {
+                if let Entry::Vacant(slot) = index.entry(n) {
+                    slot.insert(order.len());
+                    order.push(n);
+                }
+            }
+            i += 1;
+        }
+        let dead = order.len();
+        let mut delta: Vec<Vec<usize>> = order
+            .iter()
+            .map(|&s| alphabet.iter().map(|c| self.nodes[s].next.get(c).map_or(dead, |n| index[n])).collect())
+            .collect();
+        delta.push(vec![dead; alphabet.len()]);
+        let mut accept: Vec<bool> = order.iter().map(|&s| self.nodes[s].label == Some(true)).collect();
+        accept.push(false);
+        DFA { alphabet: alphabet.to_vec(), delta, start: 0, accept }.minimise()
+    }
+}
+
+/// Regular positive and negative inference (Oncina and García).
+///
+/// ```
+/// use computational_intelligence::automata::classical::regular::{
+///     dfa::DFA,
+///     regex_nfa::NFA,
+///     rpni::{rpni, words_up_to, Sample},
+/// };
+///
+/// let target = NFA::from_regex("(a|b)*abb").unwrap();
+/// let sample = Sample::labelled(&target, &words_up_to(&['a', 'b'], 6));
+/// let learned = rpni(&sample).unwrap();
+/// assert_eq!(learned, DFA::from_nfa(&target).minimise());
+///
+/// assert!(rpni(&Sample::new(&["ab"], &["ab"])).is_err());
+/// ```
+pub fn rpni(sample: &Sample) -> Result<DFA, String> {
+    let mut m = Merger::new(sample)?;
+    while let Some(&b) = m.blue().first() {
+        match m.red.iter().find_map(|&r| m.merge(r, b)) {
+            Some((merged, _)) => m = merged,
+            None              => m.red.push(b),
+        }
+    }
+    Ok(m.dfa(&sample.alphabet()))
+}
+
+/// Evidence‑driven state merging, blue‑fringe version (Lang, Pearlmutter
+/// and Price).
+///
+/// ```
+/// use computational_intelligence::automata::classical::regular::{
+///     dfa::DFA,
+///     regex_nfa::NFA,
+///     rpni::{edsm, words_up_to, Sample},
+/// };
+///
+/// let target = NFA::from_regex("(a|b)*abb").unwrap();
+/// let sample = Sample::labelled(&target, &words_up_to(&['a', 'b'], 6));
+/// assert_eq!(edsm(&sample).unwrap(), DFA::from_nfa(&target).minimise());
+/// ```
+pub fn edsm(sample: &Sample) -> Result<DFA, String> {
+    let mut m = Merger::new(sample)?;
+    loop {
+        let blue = m.blue();
+        if blue.is_empty() { break; }
+        let mut best: Option<(Merger, usize)> = None;
+        let mut stranded = None;
+        for &b in &blue {
+            let options: Vec<(Merger, usize)> = m.red.iter().filter_map(|&r| m.merge(r, b)).collect();
+            if options.is_empty() {
+                stranded = Some(b);
+                break;
+            }
+            for (merged, score) in options {
+                if best.as_ref().is_none_or(|(_, s)| score > *s) {
+                    best = Some((merged, score));
+                }
+            }
+        }
+        match (stranded, best) {
+            (Some(b), _)              => m.red.push(b),
+            (None, Some((merged, _))) => m = merged,
+            (None, None)              => break,
+        }
+    }
+    Ok(m.dfa(&sample.alphabet()))
+}
+
+/* ───── evaluation ───── */
+
+/// How a learned DFA fares against the target language.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Evaluation {
+    pub true_positive:  usize,
+    pub false_positive: usize,
+    pub true_negative:  usize,
+    pub false_negative: usize,
+    /// A shortest word the two disagree on, `None` if they are equivalent.
+    pub counterexample: Option<String>,
+}
+
+impl Evaluation {
+    /// Share of held‑out words classified right.
+    pub fn accuracy(&self) -> f64 {
+        let right = self.true_positive + self.true_negative;
+        let all = right + self.false_positive + self.false_negative;
+        if all == 0 { 1.0 } else { right as f64 / all as f64 }
+    }
+
+    pub fn equivalent(&self) -> bool { self.counterexample.is_none() }
+}
+
+/// Classifies `held_out` with `learned` against `target`, and checks the
+/// two for equivalence.
+///
+/// ```
+/// use computational_intelligence::automata::classical::regular::{
+///     regex_nfa::NFA,
+///     rpni::{evaluate, rpni, Sample},
+/// };
+///
+/// let target = NFA::from_regex("a*b").unwrap();
+/// let learned = rpni(&Sample::new(&["b", "ab"], &["", "a"])).unwrap();
+/// let report = evaluate(&learned, &target, &["aab", "ba", "aaab", "bb"]);
+/// assert_eq!(report.accuracy(), 1.0);
+/// assert!(report.equivalent());
+/// ```
+pub fn evaluate<S: AsRef<str>>(learned: &DFA, target: &NFA, held_out: &[S]) -> Evaluation {
+    let mut e = Evaluation { counterexample: distinguishing_word(target, &learned.to_nfa()), ..Default::default() };
+    for w in held_out {
+        let w = w.as_ref();
+        match (target.is_match(w), learned.is_match(w)) {
+            (true, true)   => e.true_positive += 1,
+            (false, true)  => e.false_positive += 1,
+            (false, false) => e.true_negative += 1,
+            (true, false)  => e.false_negative += 1,
+        }
+    }
+    e
+}
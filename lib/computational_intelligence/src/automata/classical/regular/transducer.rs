@@ -0,0 +1,556 @@
+//! Finite‑state **transducers**: Mealy machines (output on transitions)
+//! and Moore machines (output on states).
+//!
+//! Both are deterministic and may be partial – a run fails on a symbol a
+//! state has no move for.  Outputs are generic; the text format and
+//! sequential composition use `String` outputs (output *words*).
+//!
+//! Text format, one item per line, `#` starts a comment:
+//!
+//! ```text
+//! mealy                 moore
+//! start even            start even
+//! even 1 -> odd / 1     output even = e
+//! even 0 -> even / 0    output odd  = o
+//! odd  1 -> even / 0    even 1 -> odd
+//! odd  0 -> odd  / 1    odd  1 -> even
+//! ```
+//!
+//! An empty output word is written as nothing after `/` (or `=`), or `ε`.
+
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap, VecDeque},
+    fmt,
+    hash::Hash,
+};
+
+/// One step of a transducer run: the symbol read, the move it took and
+/// the output emitted (the target’s output for a Moore machine).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransducerStep<O> {
+    pub symbol: char,
+    pub from:   usize,
+    pub to:     usize,
+    pub output: O,
+}
+
+/* ── Mealy ────────────────────────────────────────────────────────────── */
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mealy<O> {
+    /// Display names, one per state.
+    pub names: Vec<String>,
+    pub start: usize,
+    /// `delta[state][symbol] = (target, output)`.
+    pub delta: Vec<BTreeMap<char, (usize, O)>>,
+}
+
+impl<O: Clone> Mealy<O> {
+    /// Machine with the named states and no transitions yet.
+    pub fn new(names: Vec<String>, start: usize) -> Self {
+        let delta = vec![BTreeMap::new(); names.len()];
+        Self { names, start, delta }
+    }
+
+    pub fn len(&self) -> usize { self.names.len() }
+    pub fn is_empty(&self) -> bool { self.names.is_empty() }
+
+    pub fn add_transition(&mut self, from: usize, symbol: char, to: usize, output: O) {
+        self.delta[from].insert(symbol, (to, output));
+    }
+
+    pub fn step(&self, state: usize, symbol: char) -> Option<(usize, &O)> {
+        self.delta[state].get(&symbol).map(|(t, o)| (*t, o))
+    }
+
+    /// Every move of the run on `input`; `None` if it gets stuck.
+    pub fn trace(&self, input: &str) -> Option<Vec<TransducerStep<O>>> {
+        let mut state = self.start;
+        input
+            .chars()
+            .map(|symbol| {
+                let (to, output) = self.step(state, symbol)?;
+                let step = TransducerStep { symbol, from: state, to, output: output.clone() };
+                state = to;
+                Some(step)
+            })
+            .collect()
+    }
+
+    /// The outputs of the run on `input`, one per symbol.
+    pub fn outputs(&self, input: &str) -> Option<Vec<O>> {
+        Some(self.trace(input)?.into_iter().map(|s| s.output).collect())
+    }
+
+    /// Both machines read the same input; outputs are paired.  Only the
+    /// reachable pairs of states are built.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::transducer::Mealy;
+    ///
+    /// let parity = Mealy::parse(PARITY).unwrap();
+    /// let echo   = Mealy::parse("mealy\nstart s\ns 0 -> s / 0\ns 1 -> s / 1").unwrap();
+    /// let both   = parity.product(&echo);
+    /// assert_eq!(both.outputs("110").unwrap(), vec![
+    ///     ("1".to_string(), "1".to_string()),
+    ///     ("0".to_string(), "1".to_string()),
+    ///     ("0".to_string(), "0".to_string()),
+    /// ]);
+    /// # const PARITY: &str = "mealy\nstart even\neven 1 -> odd / 1\neven 0 -> even / 0\nodd 1 -> even / 0\nodd 0 -> odd / 1";
+    /// ```
+    pub fn product<P: Clone>(&self, other: &Mealy<P>) -> Mealy<(O, P)> {
+        let mut index = HashMap::from([((self.start, other.start), 0)]);
+        let mut pairs = vec![(self.start, other.start)];
+        let mut delta = Vec::new();
+        let mut i = 0;
+        while i < pairs.len() {
+            let (p, q) = pairs[i];
+            let mut row = BTreeMap::new();
+            for (&c, (tp, op)) in &self.delta[p] {
+                let Some((tq, oq)) = other.delta[q].get(&c) else { continue };
+                let next = *index.entry((*tp, *tq)).or_insert_with(|| {
+                    pairs.push((*tp, *tq));
+                    pairs.len() - 1
+                });
+                row.insert(c, (next, (op.clone(), oq.clone())));
+            }
+            delta.push(row);
+            i += 1;
+        }
+        let names = pairs.iter().map(|&(p, q)| format!("({},{})", self.names[p], other.names[q])).collect();
+        Mealy { names, start: 0, delta }
+    }
+
+    /// Equivalent Moore machine: one state per (state, incoming output),
+    /// the start keeping `initial` as its output.
+    pub fn to_moore(&self, initial: O) -> Moore<O>
+    where
+        O: Eq + Hash,
+    {
+        let mut index: HashMap<(usize, O), usize> = HashMap::from([((self.start, initial.clone()), 0)]);
+        let mut states = vec![(self.start, initial)];
+        let mut delta  = Vec::new();
+        let mut i = 0;
+        while i < states.len() {
+            let s = states[i].0;
+            let mut row = BTreeMap::new();
+            for (&c, (t, o)) in &self.delta[s] {
+                let next = *index.entry((*t, o.clone())).or_insert_with(|| {
+                    states.push((*t, o.clone()));
+                    states.len() - 1
+                });
+                row.insert(c, next);
+            }
+            delta.push(row);
+            i += 1;
+        }
+        let names  = states.iter().enumerate().map(|(k, (s, _))| format!("{}.{k}", self.names[*s])).collect();
+        let output = states.into_iter().map(|(_, o)| o).collect();
+        Moore { names, start: 0, delta, output }
+    }
+
+    /// The smallest machine with the same input/output behaviour from the
+    /// start: unreachable states dropped, states with equal futures merged
+    /// (partition refinement), numbered breadth‑first.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::transducer::Mealy;
+    ///
+    /// // two copies of the parity machine glued together
+    /// let m = Mealy::parse("mealy\nstart a
+    ///     a 1 -> b / 1\na 0 -> c / 0\nb 1 -> c / 0\nb 0 -> d / 1
+    ///     c 1 -> d / 1\nc 0 -> a / 0\nd 1 -> a / 0\nd 0 -> b / 1").unwrap();
+    /// let min = m.minimise();
+    /// assert_eq!(min.len(), 2);
+    /// assert_eq!(min.outputs("1101"), m.outputs("1101"));
+    /// ```
+    pub fn minimise(&self) -> Self
+    where
+        O: Eq + Hash,
+    {
+        let reachable = reachable(self.start, |s| self.delta[s].values().map(|(t, _)| *t).collect());
+        let block = refine(&reachable, |s, block| {
+            self.delta[s].iter().map(|(c, (t, o))| (*c, o.clone(), block[*t])).collect::<Vec<_>>()
+        });
+        let (order, reps) = renumber(self.start, &block, |s| self.delta[s].values().map(|(t, _)| *t).collect());
+        let delta = reps
+            .iter()
+            .map(|&s| self.delta[s].iter().map(|(c, (t, o))| (*c, (order[&block[*t]], o.clone()))).collect())
+            .collect();
+        let names = reps.iter().map(|&s| self.names[s].clone()).collect();
+        Self { names, start: 0, delta }
+    }
+}
+
+impl Mealy<String> {
+    /// The translation of `input`: all output words concatenated.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::transducer::Mealy;
+    ///
+    /// let parity = Mealy::parse("mealy
+    ///     start even
+    ///     even 1 -> odd  / 1
+    ///     even 0 -> even / 0
+    ///     odd  1 -> even / 0
+    ///     odd  0 -> odd  / 1").unwrap();
+    /// assert_eq!(parity.run("1101").as_deref(), Some("1001"));
+    /// assert_eq!(parity.run("12"), None);
+    /// ```
+    pub fn run(&self, input: &str) -> Option<String> {
+        Some(self.outputs(input)?.concat())
+    }
+
+    /// Sequential composition: `self`’s output words are fed to `next`,
+    /// whose outputs become the result (`next ∘ self`).
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::transducer::Mealy;
+    ///
+    /// // a → xy, b → ε   then   x → 1, y → 22
+    /// let first  = Mealy::parse("mealy\nstart s\ns a -> s / xy\ns b -> s /").unwrap();
+    /// let second = Mealy::parse("mealy\nstart t\nt x -> t / 1\nt y -> t / 22").unwrap();
+    /// let both   = first.then(&second);
+    /// assert_eq!(both.run("aba").as_deref(), Some("122122"));
+    /// assert_eq!(both.run("aba"), first.run("aba").and_then(|w| second.run(&w)));
+    /// ```
+    pub fn then(&self, next: &Mealy<String>) -> Mealy<String> {
+        let mut index = HashMap::from([((self.start, next.start), 0)]);
+        let mut pairs = vec![(self.start, next.start)];
+        let mut delta = Vec::new();
+        let mut i = 0;
+        while i < pairs.len() {
+            let (p, q) = pairs[i];
+            let mut row = BTreeMap::new();
+            for (&c, (tp, word)) in &self.delta[p] {
+                /* run `next` over the whole intermediate word */
+                let mut tq  = q;
+                let mut out = String::new();
+                let ok = word.chars().all(|d| match next.step(tq, d) {
+                    Some((t, o)) => { tq = t; out.push_str(o); true }
+                    None         => false,
+                });
+                if !ok { continue; }
+                let target = *index.entry((*tp, tq)).or_insert_with(|| {
+                    pairs.push((*tp, tq));
+                    pairs.len() - 1
+                });
+                row.insert(c, (target, out));
+            }
+            delta.push(row);
+            i += 1;
+        }
+        let names = pairs.iter().map(|&(p, q)| format!("({},{})", self.names[p], next.names[q])).collect();
+        Mealy { names, start: 0, delta }
+    }
+
+    /// Reads the text format (see the [module docs](self)).
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let def = Definition::read(text, "mealy")?;
+        let mut m = Mealy::new(def.names.clone(), def.start()?);
+        for (line, from, symbol, to, output) in &def.moves {
+            let (from, to) = (def.index[from], def.index[to]);
+            if m.delta[from].insert(*symbol, (to, output.clone().unwrap_or_default())).is_some() {
+                return Err(format!("line {line}: second move on '{symbol}'"));
+            }
+        }
+        Ok(m)
+    }
+}
+
+impl fmt::Display for Mealy<String> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "mealy")?;
+        writeln!(f, "start {}", self.names[self.start])?;
+        for (s, row) in self.delta.iter().enumerate() {
+            for (c, (t, o)) in row {
+                writeln!(f, "{} {} -> {} / {}", self.names[s], c, self.names[*t], o)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* ── Moore ────────────────────────────────────────────────────────────── */
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Moore<O> {
+    pub names:  Vec<String>,
+    pub start:  usize,
+    /// `delta[state][symbol] = target`.
+    pub delta:  Vec<BTreeMap<char, usize>>,
+    /// Output of every state.
+    pub output: Vec<O>,
+}
+
+impl<O: Clone> Moore<O> {
+    pub fn len(&self) -> usize { self.names.len() }
+    pub fn is_empty(&self) -> bool { self.names.is_empty() }
+
+    pub fn step(&self, state: usize, symbol: char) -> Option<usize> {
+        self.delta[state].get(&symbol).copied()
+    }
+
+    /// Every move of the run on `input`, each with its target’s output;
+    /// `None` if it gets stuck.
+    pub fn trace(&self, input: &str) -> Option<Vec<TransducerStep<O>>> {
+        let mut state = self.start;
+        input
+            .chars()
+            .map(|symbol| {
+                let to   = self.step(state, symbol)?;
+                let step = TransducerStep { symbol, from: state, to, output: self.output[to].clone() };
+                state = to;
+                Some(step)
+            })
+            .collect()
+    }
+
+    /// The start state’s output followed by one output per symbol.
+    pub fn outputs(&self, input: &str) -> Option<Vec<O>> {
+        let trace = self.trace(input)?;
+        Some(std::iter::once(self.output[self.start].clone()).chain(trace.into_iter().map(|s| s.output)).collect())
+    }
+
+    /// Equivalent Mealy machine emitting the target’s output on each move
+    /// (the start state’s own output is dropped).
+    pub fn to_mealy(&self) -> Mealy<O> {
+        let delta = self.delta
+            .iter()
+            .map(|row| row.iter().map(|(c, t)| (*c, (*t, self.output[*t].clone()))).collect())
+            .collect();
+        Mealy { names: self.names.clone(), start: self.start, delta }
+    }
+
+    /// Both machines read the same input; state outputs are paired.
+    pub fn product<P: Clone>(&self, other: &Moore<P>) -> Moore<(O, P)> {
+        let mut index = HashMap::from([((self.start, other.start), 0)]);
+        let mut pairs = vec![(self.start, other.start)];
+        let mut delta = Vec::new();
+        let mut i = 0;
+        while i < pairs.len() {
+            let (p, q) = pairs[i];
+            let mut row = BTreeMap::new();
+            for (&c, &tp) in &self.delta[p] {
+                let Some(&tq) = other.delta[q].get(&c) else { continue };
+                let next = *index.entry((tp, tq)).or_insert_with(|| {
+                    pairs.push((tp, tq));
+                    pairs.len() - 1
+                });
+                row.insert(c, next);
+            }
+            delta.push(row);
+            i += 1;
+        }
+        let names  = pairs.iter().map(|&(p, q)| format!("({},{})", self.names[p], other.names[q])).collect();
+        let output = pairs.iter().map(|&(p, q)| (self.output[p].clone(), other.output[q].clone())).collect();
+        Moore { names, start: 0, delta, output }
+    }
+
+    /// The smallest machine with the same behaviour from the start.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::regular::transducer::Moore;
+    ///
+    /// // counts 1s mod 2, but with the even state duplicated
+    /// let m = Moore::parse("moore\nstart e0
+    ///     output e0 = e\noutput e1 = e\noutput o = o
+    ///     e0 1 -> o\ne0 0 -> e1\ne1 1 -> o\ne1 0 -> e0\no 1 -> e1\no 0 -> o").unwrap();
+    /// let min = m.minimise();
+    /// assert_eq!(min.len(), 2);
+    /// assert_eq!(min.outputs("0110").unwrap().concat(), "eeoee");
+    /// ```
+    pub fn minimise(&self) -> Self
+    where
+        O: Eq + Hash,
+    {
+        let reachable = reachable(self.start, |s| self.delta[s].values().copied().collect());
+        let block = refine(&reachable, |s, block| {
+            (self.output[s].clone(), self.delta[s].iter().map(|(c, t)| (*c, block[*t])).collect::<Vec<_>>())
+        });
+        let (order, reps) = renumber(self.start, &block, |s| self.delta[s].values().copied().collect());
+        let delta = reps
+            .iter()
+            .map(|&s| self.delta[s].iter().map(|(c, t)| (*c, order[&block[*t]])).collect())
+            .collect();
+        Self {
+            names:  reps.iter().map(|&s| self.names[s].clone()).collect(),
+            start:  0,
+            delta,
+            output: reps.iter().map(|&s| self.output[s].clone()).collect(),
+        }
+    }
+}
+
+impl Moore<String> {
+    /// The translation of `input`: all state outputs concatenated, the
+    /// start state’s first.
+    pub fn run(&self, input: &str) -> Option<String> {
+        Some(self.outputs(input)?.concat())
+    }
+
+    /// Reads the text format (see the [module docs](self)); states without
+    /// an `output` line output the empty word.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let def = Definition::read(text, "moore")?;
+        let mut delta = vec![BTreeMap::new(); def.names.len()];
+        for (line, from, symbol, to, output) in &def.moves {
+            if output.is_some() {
+                return Err(format!("line {line}: Moore moves carry no output"));
+            }
+            if delta[def.index[from]].insert(*symbol, def.index[to]).is_some() {
+                return Err(format!("line {line}: second move on '{symbol}'"));
+            }
+        }
+        let mut output = vec![String::new(); def.names.len()];
+        for (name, word) in &def.outputs {
+            output[def.index[name]] = word.clone();
+        }
+        Ok(Self { names: def.names.clone(), start: def.start()?, delta, output })
+    }
+}
+
+impl fmt::Display for Moore<String> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "moore")?;
+        writeln!(f, "start {}", self.names[self.start])?;
+        for (name, o) in self.names.iter().zip(&self.output) {
+            writeln!(f, "output {name} = {o}")?;
+        }
+        for (s, row) in self.delta.iter().enumerate() {
+            for (c, t) in row {
+                writeln!(f, "{} {} -> {}", self.names[s], c, self.names[*t])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* ── shared helpers ───────────────────────────────────────────────────── */
+
+/// States reachable from `start`, sorted.
+fn reachable(start: usize, succ: impl Fn(usize) -> Vec<usize>) -> Vec<usize> {
+    let mut seen  = std::collections::BTreeSet::from([start]);
+    let mut stack = vec![start];
+    while let Some(s) = stack.pop() {
+        for t in succ(s) {
+            if seen.insert(t) { stack.push(t); }
+        }
+    }
+    seen.into_iter().collect()
+}
+
+/// Moore’s partition refinement: states share a block iff their
+/// `signature` (outputs and successor blocks) agrees, iterated to a fixed
+/// point.  Returns the block of every state (`usize::MAX` if unreachable).
+fn refine<K: Eq + Hash>(states: &[usize], signature: impl Fn(usize, &[usize]) -> K) -> Vec<usize> {
+    let size = states.iter().max().map_or(0, |m| m + 1);
+    let mut block = vec![usize::MAX; size];
+    states.iter().for_each(|&s| block[s] = 0);
+    let mut count = 1;
+    loop {
+        let mut ids: HashMap<(usize, K), usize> = HashMap::new();
+        let mut next = vec![usize::MAX; size];
+        for &s in states {
+            let key = (block[s], signature(s, &block));
+            let n   = ids.len();
+            next[s] = *ids.entry(key).or_insert(n);
+        }
+        block = next;
+        if ids.len() == count { return block; }
+        count = ids.len();
+    }
+}
+
+/// Breadth‑first numbering of the blocks from `start`; returns the new
+/// index of every block and one representative state per new index.
+fn renumber(start: usize, block: &[usize], succ: impl Fn(usize) -> Vec<usize>) -> (HashMap<usize, usize>, Vec<usize>) {
+    let mut order = HashMap::from([(block[start], 0)]);
+    let mut reps  = vec![start];
+    let mut queue = VecDeque::from([start]);
+    while let Some(s) = queue.pop_front() {
+        for t in succ(s) {
+            if let Entry::Vacant(slot) = order.entry(block[t]) {
+                slot.insert(reps.len());
+                reps.push(t);
+                queue.push_back(t);
+            }
+        }
+    }
+    (order, reps)
+}
+
+/// A move as read: line, from, symbol, to, optional output word.
+type Move = (usize, String, char, String, Option<String>);
+
+/// Names, moves and outputs of a text definition before resolution.
+struct Definition {
+    names:   Vec<String>,
+    index:   HashMap<String, usize>,
+    start:   Option<String>,
+    moves:   Vec<Move>,
+    outputs: Vec<(String, String)>,
+}
+
+impl Definition {
+    fn read(text: &str, kind: &str) -> Result<Self, String> {
+        let word = |w: &str| if w == "ε" { String::new() } else { w.to_string() };
+        let mut def = Definition {
+            names: Vec::new(), index: HashMap::new(), start: None, moves: Vec::new(), outputs: Vec::new(),
+        };
+        let mut header = false;
+        for (n, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() { continue; }
+            let n = n + 1;
+            if !header {
+                if line != kind { return Err(format!("line {n}: expected '{kind}'")); }
+                header = true;
+                continue;
+            }
+            if let Some(name) = line.strip_prefix("start ") {
+                def.start = Some(name.trim().to_string());
+                def.state(name.trim());
+            } else if let Some(rest) = line.strip_prefix("output ") {
+                let (name, out) = rest.split_once('=').ok_or(format!("line {n}: expected 'output <state> = <word>'"))?;
+                def.state(name.trim());
+                def.outputs.push((name.trim().to_string(), word(out.trim())));
+            } else {
+                let (lhs, rhs) = line.split_once("->").ok_or(format!("line {n}: expected '<state> <symbol> -> <state>'"))?;
+                let mut lhs = lhs.split_whitespace();
+                let (Some(from), Some(symbol), None) = (lhs.next(), lhs.next(), lhs.next()) else {
+                    return Err(format!("line {n}: expected '<state> <symbol>' before '->'"));
+                };
+                let mut chars = symbol.chars();
+                let (Some(symbol), None) = (chars.next(), chars.next()) else {
+                    return Err(format!("line {n}: symbol '{symbol}' is not one character"));
+                };
+                let (to, output) = match rhs.split_once('/') {
+                    Some((to, out)) => (to.trim(), Some(word(out.trim()))),
+                    None            => (rhs.trim(), None),
+                };
+                if to.is_empty() || to.contains(char::is_whitespace) {
+                    return Err(format!("line {n}: bad target state '{to}'"));
+                }
+                def.state(from);
+                def.state(to);
+                def.moves.push((n, from.to_string(), symbol, to.to_string(), output));
+            }
+        }
+        if !header { return Err(format!("empty definition, expected '{kind}'")); }
+        Ok(def)
+    }
+
+    /// Index of state `name`, created on first mention.
+    fn state(&mut self, name: &str) -> usize {
+        if let Some(&i) = self.index.get(name) { return i; }
+        self.names.push(name.to_string());
+        self.index.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    fn start(&self) -> Result<usize, String> {
+        let name = self.start.as_ref().ok_or("missing 'start' line")?;
+        Ok(self.index[name])
+    }
+}
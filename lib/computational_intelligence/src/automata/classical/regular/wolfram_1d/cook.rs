@@ -0,0 +1,131 @@
+//! Rule 110 initial conditions for a cyclic tag system, laid out after
+//! Cook’s universality proof (*Universality in Elementary Cellular
+//! Automata*, 2004).
+//!
+//! The row is the periodic background (the *ether*) carrying three kinds
+//! of gliders:
+//!
+//! * **ossifiers** on the left – groups of four right‑moving A gliders;
+//! * the **tape** in the middle – one pair of stationary C gliders per bit
+//!   of the word, close together for `1` and further apart for `0`;
+//! * the **tables** on the right – the productions, repeated `cycles`
+//!   times, as left‑moving Ē gliders: a leader of three per production,
+//!   then one pair per bit, spaced like the tape pairs.
+//!
+//! Every glider is a word that starts and ends in phase with the ether, so
+//! the pieces concatenate freely.  [`read_tape`] reads the word back off a
+//! row, at start or any multiple of seven steps later, until the first
+//! glider reaches the tape.
+//!
+//! **Not done:** this is not a translation that demonstrates universality.
+//! Only Cook’s layout is reproduced, not the glider spacings that make the
+//! collisions carry out the tag system.  Once the tables reach the tape the
+//! row no longer follows [`CyclicTag::run`], and nothing here checks that
+//! it does.
+
+use std::ops::Range;
+
+use crate::automata::classical::turing::cyclic_tag::CyclicTag;
+
+/// One period of the ether.
+pub const ETHER: &str = "11111000100110";
+
+/// Right‑moving A glider (2 cells every 3 steps).
+const A: &str = "11111000111011100010011011111000100110";
+/// Stationary C glider (period 7).
+const C: &str = "11111000111000110001111111100010011011111000100110";
+/// Left‑moving Ē glider (8 cells every 30 steps).
+const E_BAR: &str = "01110111001100010011001110111001100010011011111000100110";
+
+/// A laid out row and where its tape lies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CookRow {
+    pub cells: Vec<bool>,
+    pub tape:  Range<usize>,
+}
+
+/// Ether periods either side of the pattern, clear of the dead border.
+const PAD: usize = 40;
+/// Ether periods between the ossifiers / tables and the tape.
+const GAP: usize = 20;
+
+fn ether(n: usize) -> String { ETHER.repeat(n) }
+
+/// Two gliders one ether apart for `1`, three for `0`.
+fn pair(glider: &str, bit: bool) -> String {
+    [glider, &ether(if bit { 1 } else { 3 }), glider].concat()
+}
+
+/// `system` and `word` as a Rule 110 row, with `ossifiers` groups of A
+/// gliders and `cycles` copies of the tables.
+///
+/// ```
+/// use computational_intelligence::automata::classical::{
+///     regular::wolfram_1d::{cook::{cyclic_tag_row, read_tape}, rules::rule110::Rule110, stream::evolve},
+///     turing::cyclic_tag::{bits, CyclicTag},
+/// };
+///
+/// let system = CyclicTag::parse("011, 10, 101").unwrap();
+/// let word   = bits("10110").unwrap();
+/// let mut row = cyclic_tag_row(&system, &word, 3, 2);
+/// assert_eq!(read_tape(&row.cells, row.tape.clone()), Some(word.clone()));
+///
+/// // Under the registered Rule 110 the tape stays where it is.
+/// for _ in 0..14 * 7 {
+///     row.cells = evolve(&Rule110, &row.cells);
+/// }
+/// assert_eq!(read_tape(&row.cells, row.tape.clone()), Some(word));
+/// ```
+pub fn cyclic_tag_row(system: &CyclicTag, word: &[bool], ossifiers: usize, cycles: usize) -> CookRow {
+    let ossifier = [A; 4].join(ETHER);
+    let left = [
+        ether(PAD),
+        vec![ossifier.as_str(); ossifiers].join(&ether(10)),
+        ether(GAP),
+    ].concat();
+
+    let tape = word.iter().map(|&b| pair(C, b)).collect::<Vec<_>>().join(&ether(4));
+
+    let mut tables = Vec::new();
+    for _ in 0..cycles {
+        for production in &system.productions {
+            tables.push([E_BAR; 3].join(ETHER));
+            tables.extend(production.iter().map(|&b| pair(E_BAR, b)));
+        }
+    }
+    let right = [ether(GAP), tables.join(&ether(6)), ether(PAD)].concat();
+
+    let tape_at = left.len()..left.len() + tape.len();
+    CookRow {
+        cells: [left, tape, right].concat().bytes().map(|b| b == b'1').collect(),
+        tape:  tape_at,
+    }
+}
+
+/// The word on the tape of `row`: cells in `tape` (give or take half an
+/// ether) not covered by ether are grouped into C gliders and read pairwise
+/// by their distance.  `None` if they do not pair up.
+pub fn read_tape(row: &[bool], tape: Range<usize>) -> Option<Vec<bool>> {
+    let n = ETHER.len();
+    let rotations: Vec<Vec<bool>> = (0..n)
+        .map(|k| ETHER.bytes().cycle().skip(k).take(n).map(|b| b == b'1').collect())
+        .collect();
+    let mut covered = vec![false; row.len()];
+    for i in 0..row.len().saturating_sub(n - 1) {
+        if rotations.iter().any(|r| r[..] == row[i..i + n]) {
+            covered[i..i + n].fill(true);
+        }
+    }
+
+    let (lo, hi) = (tape.start.saturating_sub(n / 2), (tape.end + n / 2).min(row.len()));
+    let mut starts: Vec<usize> = Vec::new();
+    let mut last = None;
+    for i in (lo..hi).filter(|&i| !covered[i]) {
+        if last.is_none_or(|l| i - l > 8) {
+            starts.push(i);
+        }
+        last = Some(i);
+    }
+    if !starts.len().is_multiple_of(2) { return None; }
+    Some(starts.chunks(2).map(|p| p[1] - p[0] < C.len() + 2 * n).collect())
+}
@@ -1,5 +1,7 @@
 //! One‑dimensional, two‑state, radius‑1 **Wolfram elementary CA**.
+pub mod cook;
 pub mod rules;
 pub mod seed;
+pub mod stream;
 
 pub use seed::{seed_rule30, seed_rule110};
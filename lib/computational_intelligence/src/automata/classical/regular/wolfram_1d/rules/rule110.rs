@@ -6,7 +6,7 @@ use engine_core::core::{
 use serde_json::Value;
 
 /// Hard‑coded rule table for Wolfram 110.
-const RULE_110: [u8; 8] = [0,1,1,0,1,1,1,0];
+const RULE_110: [u8; 8] = [0,1,1,1,0,1,1,0];
 
 #[derive(Clone, Resource)]
 pub struct Rule110;
@@ -23,7 +23,7 @@ impl AutomatonRule for Rule110 {
         // Similar neighborhood interpretation as Rule30.
         let l = matches!(ctx.neighbourhood[3], CellState::Alive(_)) as u8;
         let c = matches!(ctx.self_state,       CellState::Alive(_)) as u8; // centre
-        let r = matches!(ctx.neighbourhood[5], CellState::Alive(_)) as u8;
+        let r = matches!(ctx.neighbourhood[4], CellState::Alive(_)) as u8;
         let idx = (l << 2) | (c << 1) | r;
         let next_state = if RULE_110[idx as usize] == 1 {
             CellState::Alive(255)
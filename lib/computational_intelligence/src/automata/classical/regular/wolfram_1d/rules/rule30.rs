@@ -24,7 +24,7 @@ impl AutomatonRule for Rule30 {
         // Interpret 3 neighbors in a horizontal line: left (W), center (self), right (E).
         let l = matches!(ctx.neighbourhood[3], CellState::Alive(_)) as u8;
         let c = matches!(ctx.self_state,       CellState::Alive(_)) as u8; // centre
-        let r = matches!(ctx.neighbourhood[5], CellState::Alive(_)) as u8;
+        let r = matches!(ctx.neighbourhood[4], CellState::Alive(_)) as u8;
         let idx = (l << 2) | (c << 1) | r;
         let next_state = if RULE_30[idx as usize] == 1 {
             CellState::Alive(255)
@@ -32,6 +32,10 @@ fn seed_middle_band_backend(grid: &mut GridBackend) {
                 s.set_state(IVec2::new(x, 0), CellState::Alive(255));
             }
         }
+        GridBackend::Graph(g) => {
+            // no rows on a graph – light the first node
+            if let Some(c) = g.cells.first_mut() { c.state = CellState::Alive(255); }
+        }
     }
 }
 
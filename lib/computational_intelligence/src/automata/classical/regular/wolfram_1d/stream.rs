@@ -0,0 +1,72 @@
+//! Symbol streams read out of elementary CA runs.
+//!
+//! A spawned automaton's stream is sampled per tick by the
+//! `regular` plugin into [`TimeSeries`](crate::analytics::time_series::TimeSeries)
+//! (series `centre`, via [`cell_symbol`]).  For offline runs [`rows`] and
+//! [`column`] step a one‑row grid with the same rule implementations the
+//! registry hands out, so both paths share a single copy of each rule.
+
+use bevy::math::{IVec2, UVec2};
+use engine_core::{
+    core::{cell::CellState, dim::Dim2, AutomatonRule},
+    engine::{grid::{DenseGrid, GridBackend}, stepper::dense::step_lattice},
+};
+use serde_json::Value;
+
+/// Symbol of cell `p` of a spawned automaton's grid: 1 if alive, 0 if dead
+/// (`None` outside the grid).
+pub fn cell_symbol(grid: &GridBackend, p: IVec2) -> Option<usize> {
+    let state = match grid {
+        GridBackend::Dense(g)  => g.get(p)?.state,
+        GridBackend::Sparse(s) => s.get(p).map_or(CellState::Dead, |c| c.state),
+        GridBackend::Graph(_)  => return None,
+    };
+    Some(usize::from(state != CellState::Dead))
+}
+
+/// The next row under `rule`; cells past either end count as dead.
+pub fn evolve<R>(rule: &R, row: &[bool]) -> Vec<bool>
+where
+    R: AutomatonRule<D = Dim2> + ?Sized,
+{
+    let mut grid = DenseGrid::blank(UVec2::new(row.len() as u32, 1));
+    for (cell, &alive) in grid.cells.iter_mut().zip(row) {
+        cell.state = if alive { CellState::Alive(255) } else { CellState::Dead };
+    }
+    step_lattice(&mut grid, rule, &Value::Null);
+    grid.cells.iter().map(|c| c.state != CellState::Dead).collect()
+}
+
+/// The first `steps` rows of `width` cells from a single live cell in the
+/// middle.
+pub fn rows<R>(rule: &R, width: usize, steps: usize) -> Vec<Vec<bool>>
+where
+    R: AutomatonRule<D = Dim2> + ?Sized,
+{
+    let mut row = vec![false; width];
+    if width > 0 { row[width / 2] = true; }
+    let mut out = Vec::with_capacity(steps);
+    for _ in 0..steps {
+        let next = evolve(rule, &row);
+        out.push(std::mem::replace(&mut row, next));
+    }
+    out
+}
+
+/// Cell `col` through the first `steps` rows, as symbols 0 and 1.
+///
+/// ```
+/// use computational_intelligence::automata::classical::regular::wolfram_1d::{
+///     rules::rule30::Rule30, stream::column,
+/// };
+///
+/// // The centre column of Rule 30.
+/// let bits: String = column(&Rule30, 101, 16, 50).iter().map(|b| b.to_string()).collect();
+/// assert_eq!(bits, "1101110011000101");
+/// ```
+pub fn column<R>(rule: &R, width: usize, steps: usize, col: usize) -> Vec<usize>
+where
+    R: AutomatonRule<D = Dim2> + ?Sized,
+{
+    rows(rule, width, steps).iter().map(|r| usize::from(r.get(col).copied().unwrap_or(false))).collect()
+}
@@ -0,0 +1,316 @@
+//! Space‑time diagrams of tape machines: one tape per grid row, time
+//! running downwards, scrolling once the grid is full.
+//!
+//! Rows hold symbol codes – `0` is blank and drawn dead, code `k` is drawn
+//! as `Alive(k + 1)` – and the head is drawn as `Alive(1)`.
+//!
+//! [`TapeSessionPlugin`] runs one [`TapeSession`] per spawned automaton of
+//! its presets and keeps the grid showing that machine’s history; the
+//! Turing machine and LBA presets both go through it.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    marker::PhantomData,
+};
+
+use bevy::prelude::*;
+use engine_core::{
+    core::cell::CellState,
+    engine::grid::{DenseGrid, GridBackend},
+    events::{AutomatonAdded, AutomatonId, AutomatonRemoved},
+    schedule::MainSet,
+    state::AppState,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{
+    agents::HabitatRule,
+    analytics::time_series::TimeSeries,
+    registry::{AutomataRegistry, RuleRegistry},
+};
+
+/// One snapshot: `cells[i]` is tape position `origin + i`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TapeRow {
+    pub origin: i64,
+    pub cells:  Vec<u8>,
+    pub head:   i64,
+}
+
+impl TapeRow {
+    pub fn code(&self, pos: i64) -> u8 {
+        usize::try_from(pos - self.origin).ok().and_then(|i| self.cells.get(i)).copied().unwrap_or(0)
+    }
+}
+
+/// Palette room for this many tape symbols.
+const SYMBOLS: usize = 16;
+
+/// Symbol codes for drawing: `symbols[i]` is code `i + 1`, anything else
+/// (the blank) code `0`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TapeAlphabet(pub Vec<char>);
+
+impl TapeAlphabet {
+    /// The distinct `symbols` in order of first appearance, without `blank`;
+    /// an error past the 16 the palette has colours for.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::tape::TapeAlphabet;
+    ///
+    /// let a = TapeAlphabet::new("_ab_ba".chars(), Some('_')).unwrap();
+    /// assert_eq!(a.0, ['a', 'b']);
+    /// assert_eq!(a.row(-1, &['b', '_', 'a'], 0).cells, [2, 0, 1]);
+    /// assert!(TapeAlphabet::new('a'..='q', None).is_err());
+    /// ```
+    pub fn new(symbols: impl IntoIterator<Item = char>, blank: Option<char>) -> Result<Self, String> {
+        let mut out = Vec::new();
+        for c in symbols {
+            if Some(c) != blank && !out.contains(&c) { out.push(c); }
+        }
+        if out.len() > SYMBOLS {
+            return Err(format!("{} tape symbols, at most {SYMBOLS} can be drawn", out.len()));
+        }
+        Ok(Self(out))
+    }
+
+    pub fn code(&self, c: char) -> u8 {
+        self.0.iter().position(|&a| a == c).map_or(0, |i| i as u8 + 1)
+    }
+
+    /// Snapshot of a tape whose first cell is position `origin`.
+    pub fn row<'a>(&self, origin: i64, cells: impl IntoIterator<Item = &'a char>, head: i64) -> TapeRow {
+        TapeRow { origin, cells: cells.into_iter().map(|&c| self.code(c)).collect(), head }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TapeHistory {
+    pub rows:     VecDeque<TapeRow>,
+    pub capacity: usize,
+}
+
+impl TapeHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self { rows: VecDeque::with_capacity(capacity), capacity: capacity.max(1) }
+    }
+
+    pub fn push(&mut self, row: TapeRow) {
+        if self.rows.len() == self.capacity {
+            self.rows.pop_front();
+        }
+        self.rows.push_back(row);
+    }
+
+    /// Draws the rows top‑down, each tape cell `grid.x / width` columns
+    /// wide.  The window spans every position seen, or follows the newest
+    /// head when that is wider than the grid.
+    ///
+    /// ```
+    /// use bevy::math::{IVec2, UVec2};
+    /// use engine_core::{core::cell::CellState, engine::grid::DenseGrid};
+    /// use computational_intelligence::automata::classical::tape::{TapeHistory, TapeRow};
+    ///
+    /// let mut h = TapeHistory::new(4);
+    /// h.push(TapeRow { origin: 0, cells: vec![1, 2], head: 0 });
+    /// h.push(TapeRow { origin: 0, cells: vec![2, 2], head: 1 });
+    /// let mut grid = DenseGrid::blank(UVec2::new(4, 4));
+    /// h.draw(&mut grid);
+    /// let at = |x, y| grid.get(IVec2::new(x, y)).unwrap().state;
+    /// assert_eq!(at(0, 3), CellState::Alive(1));   // head, first row
+    /// assert_eq!(at(3, 3), CellState::Alive(3));
+    /// assert_eq!(at(2, 2), CellState::Alive(1));   // head, second row
+    /// assert_eq!(at(0, 1), CellState::Dead);       // not yet written
+    /// ```
+    pub fn draw(&self, grid: &mut DenseGrid) {
+        grid.cells.iter_mut().for_each(|c| c.state = CellState::Dead);
+        let Some(last) = self.rows.back() else { return };
+        let lo = self.rows.iter().map(|r| r.origin.min(r.head)).min().unwrap_or(0);
+        let hi = self.rows.iter().map(|r| (r.origin + r.cells.len() as i64).max(r.head + 1)).max().unwrap_or(1);
+        let columns = grid.size.x as i64;
+        let (lo, width) = if hi - lo <= columns {
+            (lo, hi - lo)
+        } else {
+            (last.head - columns / 2, columns)
+        };
+        let scale = (columns / width.max(1)).max(1);
+        let left  = (columns - width * scale) / 2;
+        let top   = grid.size.y as i64 - 1;
+        for (t, row) in self.rows.iter().enumerate() {
+            let y = top - t as i64;
+            if y < 0 { break; }
+            for pos in lo..lo + width {
+                let state = match (pos == row.head, row.code(pos)) {
+                    (true, _) => CellState::Alive(1),
+                    (_, 0)    => CellState::Dead,
+                    (_, k)    => CellState::Alive(k.saturating_add(1)),
+                };
+                for dx in 0..scale {
+                    let x = left + (pos - lo) * scale + dx;
+                    if let Some(cell) = grid.get_mut(IVec2::new(x as i32, y as i32)) {
+                        cell.state = state;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Head colour first, then `symbols` distinct symbol colours.
+pub fn tape_palette(symbols: usize) -> Vec<Color> {
+    std::iter::once(Color::srgb(0.95, 0.25, 0.20))
+        .chain((0..symbols).map(|k| {
+            let hue = (200.0 + 137.5 * k as f32) % 360.0;
+            Color::hsl(hue, 0.45, 0.35 + 0.3 * (k % 2) as f32)
+        }))
+        .collect()
+}
+
+/* ───── text formats ───── */
+
+/// Position of `name` in `names`, appended when first seen – the state
+/// tables of the machine text formats.
+pub(crate) fn intern(names: &mut Vec<String>, name: &str) -> usize {
+    match names.iter().position(|n| n == name) {
+        Some(i) => i,
+        None => {
+            names.push(name.to_string());
+            names.len() - 1
+        }
+    }
+}
+
+/// The character `s` consists of, if it is exactly one.
+pub(crate) fn single(s: &str) -> Option<char> {
+    let mut it = s.chars();
+    it.next().filter(|_| it.next().is_none())
+}
+
+/* ───── sessions ───── */
+
+/// A machine run on behalf of a spawned automaton, drawn as its tape
+/// history.
+pub trait TapeSession: Sized + Send + Sync + 'static {
+    type Params: Serialize + DeserializeOwned + Default;
+
+    /// What log lines call the machine.
+    const KIND: &'static str;
+
+    /// Every preset id with its defaults.
+    fn presets() -> Vec<(&'static str, Self::Params)>;
+
+    /// Loads the machine, keeping the last `rows` tapes.
+    fn start(p: &Self::Params, rows: usize) -> Result<Self, String>;
+
+    fn steps_per_tick(p: &Self::Params) -> u32;
+
+    /// Up to `steps` moves.  False once the run is over.
+    fn advance(&mut self, steps: u32) -> bool;
+
+    /// Moves made so far.
+    fn steps(&self) -> u64;
+
+    fn history(&self) -> &TapeHistory;
+
+    /// Head position on the drawn tape.
+    fn head(&self) -> i64;
+
+    /// How the run ended, once it has.
+    fn outcome(&self) -> Option<String>;
+}
+
+/// Sessions of every spawned preset of `S`.
+#[derive(Resource)]
+pub struct TapeSessions<S: TapeSession>(pub HashMap<AutomatonId, S>);
+
+impl<S: TapeSession> Default for TapeSessions<S> {
+    fn default() -> Self { Self(HashMap::new()) }
+}
+
+/// Registers the presets of `S` and runs their sessions: started on spawn,
+/// stepped `steps_per_tick` moves a tick, dropped with their automaton.
+pub struct TapeSessionPlugin<S>(PhantomData<fn() -> S>);
+
+impl<S> Default for TapeSessionPlugin<S> {
+    fn default() -> Self { Self(PhantomData) }
+}
+
+impl<S: TapeSession> Plugin for TapeSessionPlugin<S> {
+    fn build(&self, app: &mut App) {
+        // The machine lives in `TapeSessions<S>`; the grid shows its history.
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        for (id, defaults) in S::presets() {
+            reg.register(id, HabitatRule::boxed());
+            reg.register_params(id, &defaults);
+            reg.register_palette(id, tape_palette(SYMBOLS));
+        }
+        app.insert_resource(reg);
+
+        app.init_resource::<TapeSessions<S>>()
+           .add_systems(Update, (start_sessions::<S>, drop_sessions::<S>))
+           .add_systems(
+               Update,
+               step_sessions::<S>
+                   .in_set(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           )
+           .add_systems(
+               Update,
+               record_head::<S>
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+fn params<S: TapeSession>(value: &Value) -> S::Params {
+    serde_json::from_value(value.clone()).unwrap_or_default()
+}
+
+fn start_sessions<S: TapeSession>(
+    mut added:    EventReader<AutomatonAdded>,
+    mut registry: ResMut<AutomataRegistry>,
+    mut sessions: ResMut<TapeSessions<S>>,
+) {
+    for ev in added.read() {
+        let Some(info) = registry.get_mut(ev.id) else { continue };
+        if !S::presets().iter().any(|(id, _)| *id == info.name) { continue; }
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        match S::start(&params::<S>(&info.params), grid.size.y as usize) {
+            Ok(session) => {
+                session.history().draw(grid);
+                sessions.0.insert(ev.id, session);
+            }
+            Err(e) => warn!("{} “{}” does not parse: {e}", S::KIND, info.name),
+        }
+    }
+}
+
+fn drop_sessions<S: TapeSession>(mut removed: EventReader<AutomatonRemoved>, mut sessions: ResMut<TapeSessions<S>>) {
+    for ev in removed.read() {
+        sessions.0.remove(&ev.id);
+    }
+}
+
+fn step_sessions<S: TapeSession>(mut registry: ResMut<AutomataRegistry>, mut sessions: ResMut<TapeSessions<S>>) {
+    for (id, session) in sessions.0.iter_mut() {
+        let Some(info) = registry.get_mut(*id) else { continue };
+        if session.outcome().is_some() { continue; }
+        let before = session.steps();
+        session.advance(S::steps_per_tick(&params::<S>(&info.params)).max(1));
+        if let Some(outcome) = session.outcome() {
+            info!("{} “{}” {outcome}", S::KIND, info.name);
+        }
+        if session.steps() == before { continue; }
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        session.history().draw(grid);
+    }
+}
+
+/// Per‑tick head position on the drawn tape.
+fn record_head<S: TapeSession>(sessions: Res<TapeSessions<S>>, mut series: ResMut<TimeSeries>) {
+    for (id, session) in sessions.0.iter() {
+        series.record(*id, "head", session.head() as f64);
+    }
+}
@@ -0,0 +1,308 @@
+//! Busy beaver search over n‑state, 2‑symbol machines.
+//!
+//! Machines are enumerated in tree normal form: every rule starts
+//! undefined, and a machine is only extended at the rule it first reaches,
+//! with next states limited to those already used plus the first unused
+//! one.  The first rule always moves right, which drops mirror images.
+//! Reaching an undefined rule is where a halting transition `1RZ` would go,
+//! so each such point yields one halting machine and the children that
+//! continue instead.
+//!
+//! Machines that never reach an undefined rule are run through two
+//! deciders before giving up:
+//!
+//! * **cyclers** repeat a whole configuration (found with Brent's
+//!   algorithm);
+//! * **translated cyclers** reach a new extreme cell twice in the same
+//!   state with the same tape behind the head, as far back as the head
+//!   reached in between, so the run repeats shifted forever.
+
+use std::{collections::VecDeque, fmt};
+
+use super::universal_tm::{Direction, Halt, TMTransition, TuringMachine};
+
+/// How far back translated cycler detection compares records.
+const RECORD_WINDOW: usize = 256;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// Halted after `steps`, leaving `ones` marks.
+    Halts { steps: u64, ones: usize },
+    /// Reached a missing rule for `(state, symbol)` after `steps`.
+    Stuck { state: usize, symbol: char, steps: u64 },
+    /// Repeats a configuration every `period` steps.
+    Cycler { period: u64 },
+    /// Repeats every `period` steps, `shift` cells further along.
+    TranslatedCycler { period: u64, shift: i64 },
+    /// Still running at the step limit.
+    Undecided,
+}
+
+impl Verdict {
+    /// Proven to run forever.
+    pub fn never_halts(self) -> bool { matches!(self, Verdict::Cycler { .. } | Verdict::TranslatedCycler { .. }) }
+}
+
+/// Non‑blank cells from `left`, for comparing tapes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Snapshot {
+    state: usize,
+    head:  i64,
+    left:  i64,
+    cells: Vec<char>,
+}
+
+impl Snapshot {
+    fn of(tm: &TuringMachine) -> Self {
+        let (left, cells) = trimmed(tm);
+        Self { state: tm.state, head: tm.position(), left, cells: cells.to_vec() }
+    }
+
+    fn matches(&self, tm: &TuringMachine) -> bool {
+        if self.state != tm.state || self.head != tm.position() { return false; }
+        let (left, cells) = trimmed(tm);
+        left == self.left && cells == self.cells.as_slice()
+    }
+
+    fn get(&self, pos: i64, blank: char) -> char {
+        usize::try_from(pos - self.left).ok().and_then(|i| self.cells.get(i)).copied().unwrap_or(blank)
+    }
+}
+
+/// The tape between its outermost non‑blank cells, and where it starts.
+fn trimmed(tm: &TuringMachine) -> (i64, &[char]) {
+    let Some(first) = tm.tape.iter().position(|&c| c != tm.blank) else { return (0, &[]) };
+    let last = tm.tape.iter().rposition(|&c| c != tm.blank).unwrap_or(first);
+    (tm.origin + first as i64, &tm.tape[first..=last])
+}
+
+fn cell(tm: &TuringMachine, pos: i64) -> char {
+    usize::try_from(pos - tm.origin).ok().and_then(|i| tm.tape.get(i)).copied().unwrap_or(tm.blank)
+}
+
+/// A visit to a new extreme cell.
+#[derive(Clone, Debug)]
+struct Record {
+    step:     u64,
+    tape:     Snapshot,
+    /// Furthest back the head went since the previous record.
+    back_min: i64,
+}
+
+/// Records on one side; positions are multiplied by `sign` so that "ahead"
+/// is always larger.
+struct Records {
+    sign:     i64,
+    extreme:  i64,
+    back_min: i64,
+    records:  VecDeque<Record>,
+}
+
+impl Records {
+    fn new(sign: i64) -> Self {
+        Self { sign, extreme: 0, back_min: 0, records: VecDeque::new() }
+    }
+
+    /// Notes the head after a step; on a new record, looks for an earlier
+    /// one it repeats.
+    fn observe(&mut self, tm: &TuringMachine, step: u64) -> Option<Verdict> {
+        let x = self.sign * tm.position();
+        self.back_min = self.back_min.min(x);
+        if x <= self.extreme { return None; }
+        self.extreme = x;
+
+        let mut reach = self.back_min;
+        let mut found = None;
+        for r in self.records.iter().rev() {
+            if r.tape.state == tm.state {
+                let rx = self.sign * r.tape.head;
+                let depth = rx - reach;
+                let same = (0..=depth).all(|d| {
+                    r.tape.get(self.sign * (rx - d), tm.blank) == cell(tm, self.sign * (x - d))
+                });
+                if same {
+                    found = Some(Verdict::TranslatedCycler { period: step - r.step, shift: tm.position() - r.tape.head });
+                    break;
+                }
+            }
+            reach = reach.min(r.back_min);
+        }
+
+        if self.records.len() == RECORD_WINDOW { self.records.pop_front(); }
+        self.records.push_back(Record { step, tape: Snapshot::of(tm), back_min: self.back_min });
+        self.back_min = x;
+        found
+    }
+}
+
+/// Runs `tm` from a blank tape for at most `limit` steps.
+///
+/// ```
+/// use computational_intelligence::automata::classical::turing::{
+///     busy_beaver::{decide, Verdict}, universal_tm::TuringMachine,
+/// };
+///
+/// let verdict = |m| decide(&TuringMachine::parse(m).unwrap(), 10_000);
+/// assert_eq!(verdict("1RB1LB_1LA0LC_1RZ1LD_1RD0RA"), Verdict::Halts { steps: 107, ones: 13 });
+/// assert_eq!(verdict("1RB---_1LA---"), Verdict::Stuck { state: 0, symbol: '1', steps: 2 });
+/// assert_eq!(verdict("1RB1RB_1LA0LA"), Verdict::Cycler { period: 4 });
+/// assert_eq!(verdict("0RA---"), Verdict::TranslatedCycler { period: 1, shift: 1 });
+/// assert_eq!(verdict("1RB0RA_1LA---"), Verdict::TranslatedCycler { period: 4, shift: 2 });
+/// ```
+pub fn decide(tm: &TuringMachine, limit: u64) -> Verdict {
+    let mut tm = tm.clone();
+    tm.load("");
+    let (mut saved, mut saved_at, mut power) = (Snapshot::of(&tm), 0u64, 1u64);
+    let (mut right, mut left) = (Records::new(1), Records::new(-1));
+    let mut steps = 0;
+    loop {
+        match tm.halted() {
+            Some(Halt::Undefined) => return Verdict::Stuck { state: tm.state, symbol: tm.read(), steps },
+            Some(_)               => return Verdict::Halts { steps, ones: tm.marks() },
+            None                  => {}
+        }
+        if steps == limit { return Verdict::Undecided; }
+        tm.step();
+        steps += 1;
+
+        if saved.matches(&tm) {
+            return Verdict::Cycler { period: steps - saved_at };
+        }
+        if steps - saved_at == power {
+            (saved, saved_at, power) = (Snapshot::of(&tm), steps, power * 2);
+        }
+        if let Some(v) = right.observe(&tm, steps).or_else(|| left.observe(&tm, steps)) {
+            return v;
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Champion {
+    /// Standard notation.
+    pub machine: String,
+    pub steps:   u64,
+    pub ones:    usize,
+}
+
+/// What the enumeration of one state count found.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Census {
+    pub states:     usize,
+    pub halting:    u64,
+    pub cyclers:    u64,
+    pub translated: u64,
+    /// Machines left without an undefined rule, so with no way to halt.
+    pub no_halt:    u64,
+    /// Standard notation of every machine the deciders could not settle.
+    pub undecided:  Vec<String>,
+    pub most_steps: Option<Champion>,
+    pub most_ones:  Option<Champion>,
+}
+
+impl Census {
+    /// Every machine counted.
+    pub fn machines(&self) -> u64 {
+        self.halting + self.cyclers + self.translated + self.no_halt + self.undecided.len() as u64
+    }
+
+    /// The undecided machines, one per line, for other tools.
+    pub fn export_undecided(&self) -> String {
+        self.undecided.iter().map(|m| format!("{m}\n")).collect()
+    }
+
+    fn crown(&mut self, c: Champion) {
+        if self.most_steps.as_ref().is_none_or(|b| c.steps > b.steps) {
+            self.most_steps = Some(c.clone());
+        }
+        if self.most_ones.as_ref().is_none_or(|b| c.ones > b.ones) {
+            self.most_ones = Some(c);
+        }
+    }
+}
+
+impl fmt::Display for Census {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}-state machines: {}", self.states, self.machines())?;
+        writeln!(
+            f,
+            "halting {}, cyclers {}, translated cyclers {}, without halt {}, undecided {}",
+            self.halting, self.cyclers, self.translated, self.no_halt, self.undecided.len(),
+        )?;
+        if let Some(c) = &self.most_steps {
+            writeln!(f, "most steps: {} ({} steps, {} ones)", c.machine, c.steps, c.ones)?;
+        }
+        if let Some(c) = &self.most_ones {
+            writeln!(f, "most ones:  {} ({} steps, {} ones)", c.machine, c.steps, c.ones)?;
+        }
+        Ok(())
+    }
+}
+
+/// Enumerates the `states`‑state, 2‑symbol machines in tree normal form,
+/// giving each `limit` steps.
+///
+/// ```
+/// use computational_intelligence::automata::classical::turing::busy_beaver::enumerate;
+///
+/// let census = enumerate(2, 1_000);
+/// assert_eq!(census.most_steps.as_ref().map(|c| c.steps), Some(6));
+/// assert_eq!(census.most_ones.as_ref().map(|c| c.ones), Some(4));
+/// assert!(census.undecided.is_empty());
+///
+/// let census = enumerate(3, 1_000);
+/// assert_eq!(census.most_steps.map(|c| c.steps), Some(21));
+/// assert_eq!(census.most_ones.map(|c| c.ones), Some(6));
+/// ```
+pub fn enumerate(states: usize, limit: u64) -> Census {
+    let states = states.clamp(1, 25);
+    let mut root = TuringMachine::new(0, states, states + 1, '0');
+    root.names = (0..states).map(|q| ((b'A' + q as u8) as char).to_string()).chain(["Z".into(), "reject".into()]).collect();
+    let mut census = Census { states, ..Default::default() };
+    explore(&root, states, limit, &mut census);
+    census
+}
+
+fn explore(tm: &TuringMachine, states: usize, limit: u64, census: &mut Census) {
+    let (state, symbol, steps) = match decide(tm, limit) {
+        Verdict::Stuck { state, symbol, steps } => (state, symbol, steps),
+        Verdict::Halts { .. }                   => unreachable!("only undefined rules halt"),
+        Verdict::Cycler { .. }                  => return census.cyclers += 1,
+        Verdict::TranslatedCycler { .. }        => return census.translated += 1,
+        Verdict::Undecided                      => {
+            return census.undecided.push(tm.to_standard().expect("tree normal form is standard"));
+        }
+    };
+
+    // Halting here: write a 1 and stop.
+    let mut halting = tm.clone();
+    halting.transitions.insert((state, symbol), TMTransition { write: '1', new_state: states, direction: Direction::Right });
+    let mut run = halting.clone();
+    run.load("");
+    let report = run.run_report(steps + 1);
+    census.halting += 1;
+    census.crown(Champion {
+        machine: halting.to_standard().expect("tree normal form is standard"),
+        steps:   report.steps,
+        ones:    report.marks,
+    });
+
+    // Or carry on, through a used state or the first unused one.
+    let used = tm.transitions.values().map(|t| t.new_state).chain([0]).max().unwrap_or(0);
+    let last_gap = tm.transitions.len() + 1 == 2 * states;
+    let first = tm.transitions.is_empty();
+    for next in 0..=(used + 1).min(states - 1) {
+        for write in ['0', '1'] {
+            for direction in [Direction::Left, Direction::Right] {
+                if first && direction == Direction::Left { continue; }
+                if last_gap {
+                    census.no_halt += 1;
+                    continue;
+                }
+                let mut child = tm.clone();
+                child.transitions.insert((state, symbol), TMTransition { write, new_state: next, direction });
+                explore(&child, states, limit, census);
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Cyclic tag systems, and their translation from tag systems.
+//!
+//! A cyclic tag system cycles through a list of binary productions, one
+//! per step: the first bit of the word is deleted and, if it was a `1`, the
+//! current production is appended.  It halts once the word is empty.
+//! Productions are written comma‑separated, `ε` for an empty one:
+//! `011, 10, 101`.
+
+use std::{collections::VecDeque, fmt};
+
+use super::tag::TagSystem;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CyclicRun {
+    pub word:   VecDeque<bool>,
+    pub steps:  u64,
+    /// The word ran out.
+    pub halted: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CyclicTag {
+    pub productions: Vec<Vec<bool>>,
+}
+
+impl CyclicTag {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let productions = text
+            .split(',')
+            .map(|p| match p.trim() {
+                "ε" | "" => Ok(Vec::new()),
+                p => bits(p),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { productions })
+    }
+
+    /// One step, the `step`th of the run.  False on an empty word.
+    pub fn step(&self, word: &mut VecDeque<bool>, step: u64) -> bool {
+        let Some(first) = word.pop_front() else { return false };
+        if first && !self.productions.is_empty() {
+            word.extend(&self.productions[(step % self.productions.len() as u64) as usize]);
+        }
+        true
+    }
+
+    /// Runs at most `limit` steps.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::cyclic_tag::{bits, CyclicTag};
+    ///
+    /// let ct = CyclicTag::parse("011, 10, 101").unwrap();
+    /// let word = bits("1").unwrap();
+    /// assert_eq!(ct.trace(&word, 5), ["1", "011", "11", "1101", "101011", "0101110"]);
+    /// let run = ct.run(&word, 100);
+    /// assert!(!run.halted && run.steps == 100);
+    /// assert!(CyclicTag::parse("0, ε").unwrap().run(&bits("1101").unwrap(), 100).halted);
+    /// ```
+    pub fn run(&self, word: &[bool], limit: u64) -> CyclicRun {
+        let mut word: VecDeque<bool> = word.iter().copied().collect();
+        let mut steps = 0;
+        while steps < limit {
+            if !self.step(&mut word, steps) { break; }
+            steps += 1;
+        }
+        CyclicRun { halted: word.is_empty(), word, steps }
+    }
+
+    /// The word before each of at most `steps` steps, and after the last.
+    pub fn trace(&self, word: &[bool], steps: u64) -> Vec<String> {
+        let mut word: VecDeque<bool> = word.iter().copied().collect();
+        let mut out = vec![show(&word)];
+        for s in 0..steps {
+            if !self.step(&mut word, s) { break; }
+            out.push(show(&word));
+        }
+        out
+    }
+
+    /// Cook's translation of an m‑tag system over n symbols.
+    ///
+    /// Symbol `k` becomes the n bits with a `1` at `k`, and the productions
+    /// are the encoded tag productions in symbol order followed by
+    /// `n·(m−1)` empty ones: reading a symbol's bits appends its production
+    /// at the `1`, and the empty ones delete the next `m − 1` symbols.  One
+    /// tag step is so [`steps_per_tag_step`] cyclic steps.  Halting symbols
+    /// get empty productions: the cyclic system runs on where the tag
+    /// system stops.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::{
+    ///     cyclic_tag::{decode_word, encode_word, steps_per_tag_step, CyclicTag},
+    ///     tag::TagSystem,
+    /// };
+    ///
+    /// let collatz = TagSystem::parse("a -> bc\nb -> a\nc -> aaa").unwrap();
+    /// let ct = CyclicTag::from_tag(&collatz);
+    /// assert_eq!(ct.to_string(), "010001, 100, 100100100, ε, ε, ε");
+    /// let word = collatz.word("aaa").unwrap();
+    /// let run = ct.run(&encode_word(&collatz, &word), 7 * steps_per_tag_step(&collatz));
+    /// let tag = collatz.run(&word, 7);
+    /// assert_eq!(decode_word(&collatz, &run.word), Some(tag.word.into()));
+    /// ```
+    pub fn from_tag(tag: &TagSystem) -> Self {
+        let mut productions: Vec<Vec<bool>> = tag
+            .productions
+            .iter()
+            .map(|p| p.as_ref().map_or_else(Vec::new, |p| encode_word(tag, p)))
+            .collect();
+        productions.resize(tag.names.len() * tag.m, Vec::new());
+        Self { productions }
+    }
+}
+
+/// Cyclic steps per step of `tag` under [`CyclicTag::from_tag`].
+pub fn steps_per_tag_step(tag: &TagSystem) -> u64 { (tag.m * tag.names.len()) as u64 }
+
+/// `word` of `tag` one‑hot, as [`CyclicTag::from_tag`] expects it.
+pub fn encode_word(tag: &TagSystem, word: &[usize]) -> Vec<bool> {
+    let n = tag.names.len();
+    word.iter().flat_map(|&s| (0..n).map(move |i| i == s)).collect()
+}
+
+/// The tag word back, if `bits` is a whole number of one‑hot symbols.
+pub fn decode_word(tag: &TagSystem, bits: &VecDeque<bool>) -> Option<Vec<usize>> {
+    let n = tag.names.len();
+    if n == 0 || !bits.len().is_multiple_of(n) { return None; }
+    let bits: Vec<bool> = bits.iter().copied().collect();
+    bits.chunks(n)
+        .map(|c| (c.iter().filter(|&&b| b).count() == 1).then(|| c.iter().position(|&b| b)).flatten())
+        .collect()
+}
+
+/// `0`s and `1`s as bits.
+pub fn bits(text: &str) -> Result<Vec<bool>, String> {
+    text.trim()
+        .chars()
+        .map(|c| match c {
+            '0' => Ok(false),
+            '1' => Ok(true),
+            c   => Err(format!("'{c}' is not a bit")),
+        })
+        .collect()
+}
+
+fn show<'a>(word: impl IntoIterator<Item = &'a bool>) -> String {
+    word.into_iter().map(|&b| if b { '1' } else { '0' }).collect()
+}
+
+impl fmt::Display for CyclicTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self
+            .productions
+            .iter()
+            .map(|p| if p.is_empty() { "ε".to_string() } else { show(p) })
+            .collect();
+        write!(f, "{}", parts.join(", "))
+    }
+}
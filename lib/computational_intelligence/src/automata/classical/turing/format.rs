@@ -0,0 +1,168 @@
+//! Text formats for [`TuringMachine`].
+//!
+//! * **Standard notation**, as used for busy beavers: one `_`‑separated
+//!   group per state `A`, `B`, …, three characters per symbol `0`, `1`, …
+//!   giving the symbol to write, the move and the next state.  A next state
+//!   past the last one (conventionally `Z`) halts, and `---` leaves the rule
+//!   undefined.  The blank is `0`.
+//!
+//!   ```text
+//!   1RB1LB_1LA0LC_1RZ1LD_1RD0RA
+//!   ```
+//!
+//! * **Tables**, the one‑tape case of the [`multi_tape`](super::multi_tape)
+//!   format without wildcards: `q a -> b M r` per rule, with `start`,
+//!   `accept` and `reject` lines and `_` for the blank.
+
+use std::{collections::HashMap, fmt};
+
+use super::{
+    multi_tape::{MultiTapeMachine, BLANK},
+    universal_tm::{Direction, TMTransition, TuringMachine},
+};
+
+/// Whether `text` looks like standard notation rather than a table.
+pub fn is_standard(text: &str) -> bool {
+    let text = text.trim();
+    !text.is_empty() && !text.contains(char::is_whitespace)
+}
+
+impl TuringMachine {
+    /// Reads either format, telling them apart with [`is_standard`].
+    pub fn parse(text: &str) -> Result<Self, String> {
+        if is_standard(text) { Self::from_standard(text) } else { Self::from_table(text) }
+    }
+
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::universal_tm::TuringMachine;
+    ///
+    /// let tm = TuringMachine::from_standard("1RB1LC_1RC1RB_1RD0LE_1LA1LD_1RZ0LA").unwrap();
+    /// assert_eq!(tm.live_states().len(), 5);
+    /// assert_eq!(tm.to_standard().unwrap(), "1RB1LC_1RC1RB_1RD0LE_1LA1LD_1RZ0LA");
+    /// assert!(TuringMachine::from_standard("1RB1LB_1LA").is_err());
+    /// ```
+    pub fn from_standard(text: &str) -> Result<Self, String> {
+        let groups: Vec<Vec<char>> = text.trim().split('_').map(|g| g.chars().collect()).collect();
+        let width = groups[0].len();
+        if width == 0 || !width.is_multiple_of(3) || groups.iter().any(|g| g.len() != width) {
+            return Err("every state needs three characters per symbol".into());
+        }
+        let (states, symbols) = (groups.len(), width / 3);
+        if states > 25 || symbols > 10 {
+            return Err("standard notation covers at most 25 states and 10 symbols".into());
+        }
+        let mut tm = TuringMachine::new(0, states, states + 1, '0');
+        tm.names = (0..states).map(|q| letter(q).to_string()).chain(["Z".into(), "reject".into()]).collect();
+        for (q, group) in groups.iter().enumerate() {
+            for (s, rule) in group.chunks(3).enumerate() {
+                if rule == ['-', '-', '-'] { continue; }
+                let bad = || format!("state {}: '{}' is not 'write move next'", letter(q), rule.iter().collect::<String>());
+                let write = rule[0].to_digit(10).filter(|&d| (d as usize) < symbols).ok_or_else(bad)?;
+                let direction = Direction::from_letter(rule[1]).filter(|&d| d != Direction::Stay).ok_or_else(bad)?;
+                let next = rule[2].is_ascii_uppercase().then(|| (rule[2] as u8 - b'A') as usize).ok_or_else(bad)?;
+                tm.transitions.insert((q, digit(s)), TMTransition {
+                    write:     digit(write as usize),
+                    new_state: next.min(states),
+                    direction,
+                });
+            }
+        }
+        Ok(tm)
+    }
+
+    /// Standard notation, for machines over the digits with blank `0` that
+    /// move on every step and never reject.
+    pub fn to_standard(&self) -> Result<String, String> {
+        let live = self.live_states();
+        let symbols = self.symbols();
+        if self.blank != '0' || symbols.iter().any(|c| !c.is_ascii_digit()) {
+            return Err("standard notation needs digit symbols with blank 0".into());
+        }
+        if live.len() > 25 {
+            return Err("standard notation covers at most 25 states".into());
+        }
+        let count = symbols.iter().map(|c| c.to_digit(10).unwrap_or(0) as usize + 1).max().unwrap_or(1).max(2);
+        let index: HashMap<usize, usize> = live.iter().enumerate().map(|(i, &q)| (q, i)).collect();
+        let mut groups = Vec::new();
+        for &q in &live {
+            let mut group = String::new();
+            for s in 0..count {
+                match self.transitions.get(&(q, digit(s))) {
+                    None => group += "---",
+                    Some(t) if t.new_state == self.reject_state || t.direction == Direction::Stay => {
+                        return Err(format!("{} cannot be written in standard notation", self.name(q)));
+                    }
+                    Some(t) => {
+                        let next = index.get(&t.new_state).map_or('Z', |&i| letter(i));
+                        group += &format!("{}{}{next}", t.write, t.direction.letter());
+                    }
+                }
+            }
+            groups.push(group);
+        }
+        Ok(groups.join("_"))
+    }
+
+    /// Reads a one‑tape table; missing `accept`/`reject` states are added
+    /// unreachable.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::universal_tm::TuringMachine;
+    ///
+    /// let mut tm = TuringMachine::from_table("start q0\naccept qA\nq0 a -> X R q0\nq0 _ -> _ L qA").unwrap();
+    /// tm.load("aa");
+    /// assert!(tm.run(10));
+    /// assert_eq!(tm.contents(), "XX");
+    /// let again = TuringMachine::from_table(&tm.to_string()).unwrap();
+    /// assert_eq!(again.to_string(), tm.to_string());
+    /// ```
+    pub fn from_table(text: &str) -> Result<Self, String> {
+        let mut m = MultiTapeMachine::parse(text)?;
+        if m.tapes != 1 {
+            return Err(format!("a one‑tape table was expected, not {} tapes", m.tapes));
+        }
+        let accept = m.accept.unwrap_or_else(|| m.state("accept"));
+        let reject = m.reject.unwrap_or_else(|| m.state("reject"));
+        let mut tm = TuringMachine::new(m.start, accept, reject, BLANK);
+        for (&q, rules) in &m.rules {
+            for r in rules {
+                let (Some(read), Some(write)) = (r.read[0], r.write[0]) else {
+                    return Err(format!("{}: wildcards need the multi‑tape format", m.names[q]));
+                };
+                if tm.transitions.insert((q, read), TMTransition { write, new_state: r.to, direction: r.moves[0] }).is_some() {
+                    return Err(format!("{} has two rules for '{read}'", m.names[q]));
+                }
+            }
+        }
+        tm.names = m.names;
+        Ok(tm)
+    }
+}
+
+fn letter(q: usize) -> char { (b'A' + q as u8) as char }
+
+fn digit(s: usize) -> char { char::from_digit(s as u32, 10).expect("at most 10 symbols") }
+
+/// The table format, the blank written `_`.
+impl fmt::Display for TuringMachine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sym = |c: char| if c == self.blank { BLANK } else { c };
+        writeln!(f, "start {}", self.name(self.start_state))?;
+        writeln!(f, "accept {}", self.name(self.accept_state))?;
+        writeln!(f, "reject {}", self.name(self.reject_state))?;
+        let mut rules: Vec<_> = self.transitions.iter().collect();
+        rules.sort_by_key(|(k, _)| **k);
+        for (&(q, read), t) in rules {
+            writeln!(
+                f,
+                "{} {} -> {} {} {}",
+                self.name(q),
+                sym(read),
+                sym(t.write),
+                t.direction.letter(),
+                self.name(t.new_state),
+            )?;
+        }
+        Ok(())
+    }
+}
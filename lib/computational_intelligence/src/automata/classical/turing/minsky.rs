@@ -0,0 +1,270 @@
+//! Minsky register machines, and their compilation from Turing machines.
+//!
+//! Registers hold natural numbers; a program is a list of instructions
+//! that increment a register, or decrement it unless it is zero, each then
+//! jumping on.  The text format has one instruction per line, an optional
+//! `label:` in front, registers `r0`, `r1`, … and targets by label or line
+//! number (from 0):
+//!
+//! ```text
+//! loop: dec r1 move done    # r0 += r1
+//! move: inc r0 loop
+//! done: halt
+//! ```
+
+use std::{collections::BTreeMap, fmt};
+
+use super::universal_tm::{check_binary, Direction, Halt, TuringMachine};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instr {
+    Inc { reg: usize, next: usize },
+    /// Decrements `reg` and goes to `next`, or goes to `zero` if it is 0.
+    Dec { reg: usize, next: usize, zero: usize },
+    Halt,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MinskyRun {
+    pub registers: Vec<u64>,
+    pub pc:        usize,
+    pub steps:     u64,
+    /// At a `halt`, or past the end of the program.
+    pub halted:    bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Minsky {
+    pub registers: usize,
+    pub program:   Vec<Instr>,
+    pub labels:    BTreeMap<String, usize>,
+}
+
+impl Minsky {
+    /// Reads the text format (see the [module docs](self)).
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let lines: Vec<(usize, &str)> = text
+            .lines()
+            .enumerate()
+            .map(|(n, l)| (n + 1, l.split('#').next().unwrap_or("").trim()))
+            .filter(|(_, l)| !l.is_empty())
+            .collect();
+        let mut m = Minsky::default();
+        let mut bodies = Vec::new();
+        for (pc, &(n, line)) in lines.iter().enumerate() {
+            let body = match line.split_once(':') {
+                Some((label, body)) => {
+                    if m.labels.insert(label.trim().to_string(), pc).is_some() {
+                        return Err(format!("line {n}: label '{}' is used twice", label.trim()));
+                    }
+                    body.trim()
+                }
+                None => line,
+            };
+            bodies.push((n, body));
+        }
+        for (n, body) in bodies {
+            let words: Vec<&str> = body.split_whitespace().collect();
+            let err = |e: String| format!("line {n}: {e}");
+            let reg = |w: &str| {
+                w.strip_prefix('r').and_then(|r| r.parse::<usize>().ok()).ok_or(err(format!("'{w}' is not a register")))
+            };
+            let target = |w: &str| {
+                m.labels.get(w).copied().or_else(|| w.parse().ok()).ok_or(err(format!("unknown target '{w}'")))
+            };
+            let instr = match words.as_slice() {
+                ["inc", r, next]       => Instr::Inc { reg: reg(r)?, next: target(next)? },
+                ["dec", r, next, zero] => Instr::Dec { reg: reg(r)?, next: target(next)?, zero: target(zero)? },
+                ["halt"]               => Instr::Halt,
+                _                      => return Err(err("expected 'inc r a', 'dec r a b' or 'halt'".into())),
+            };
+            if let Instr::Inc { reg, .. } | Instr::Dec { reg, .. } = instr {
+                m.registers = m.registers.max(reg + 1);
+            }
+            m.program.push(instr);
+        }
+        Ok(m)
+    }
+
+    pub fn label(&self, name: &str) -> Option<usize> { self.labels.get(name).copied() }
+
+    /// One instruction.  False once halted.
+    pub fn step(&self, registers: &mut [u64], pc: &mut usize) -> bool {
+        match self.program.get(*pc) {
+            Some(&Instr::Inc { reg, next }) => {
+                registers[reg] += 1;
+                *pc = next;
+            }
+            Some(&Instr::Dec { reg, next, zero }) => {
+                if registers[reg] == 0 {
+                    *pc = zero;
+                } else {
+                    registers[reg] -= 1;
+                    *pc = next;
+                }
+            }
+            Some(Instr::Halt) | None => return false,
+        }
+        true
+    }
+
+    /// Runs from the first instruction for at most `limit` steps; missing
+    /// registers start at 0.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::minsky::Minsky;
+    ///
+    /// let add = Minsky::parse("loop: dec r1 move done\nmove: inc r0 loop\ndone: halt").unwrap();
+    /// let run = add.run(&[3, 4], 100);
+    /// assert!(run.halted);
+    /// assert_eq!((run.registers, run.steps), (vec![7, 0], 9));
+    /// assert_eq!(add.trace(&[1, 1], 10), [(0, vec![1, 1]), (1, vec![1, 0]), (0, vec![2, 0]), (2, vec![2, 0])]);
+    /// ```
+    pub fn run(&self, registers: &[u64], limit: u64) -> MinskyRun {
+        let mut regs = self.start(registers);
+        let (mut pc, mut steps) = (0, 0);
+        while steps < limit && self.step(&mut regs, &mut pc) {
+            steps += 1;
+        }
+        let halted = !matches!(self.program.get(pc), Some(Instr::Inc { .. } | Instr::Dec { .. }));
+        MinskyRun { registers: regs, pc, steps, halted }
+    }
+
+    /// The instruction and registers before each of at most `steps` steps,
+    /// and after the last.
+    pub fn trace(&self, registers: &[u64], steps: u64) -> Vec<(usize, Vec<u64>)> {
+        let mut regs = self.start(registers);
+        let mut pc = 0;
+        let mut out = vec![(pc, regs.clone())];
+        for _ in 0..steps {
+            if !self.step(&mut regs, &mut pc) { break; }
+            out.push((pc, regs.clone()));
+        }
+        out
+    }
+
+    fn start(&self, registers: &[u64]) -> Vec<u64> {
+        let mut regs = registers.to_vec();
+        regs.resize(regs.len().max(self.registers), 0);
+        regs
+    }
+}
+
+/// The text format, labels kept and targets by label where there is one.
+impl fmt::Display for Minsky {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = BTreeMap::new();
+        for (name, &pc) in &self.labels {
+            names.entry(pc).or_insert(name.as_str());
+        }
+        let target = |pc: usize| names.get(&pc).map_or(pc.to_string(), |n| n.to_string());
+        for (pc, instr) in self.program.iter().enumerate() {
+            if let Some(name) = names.get(&pc) { write!(f, "{name}: ")?; }
+            match *instr {
+                Instr::Inc { reg, next }       => writeln!(f, "inc r{reg} {}", target(next))?,
+                Instr::Dec { reg, next, zero } => writeln!(f, "dec r{reg} {} {}", target(next), target(zero))?,
+                Instr::Halt                    => writeln!(f, "halt")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/* ───── Turing machines as register machines ───── */
+
+/// A three‑register machine simulating a binary Turing machine.
+///
+/// `r0` and `r1` hold the tape as the numbers of
+/// [`tape_numbers`](super::universal_tm::tape_numbers), `r2` is scratch.
+/// Each state halves `r1` through `r2` to read the head cell from its
+/// parity, then doubles and adds to write and move: moving right pushes the
+/// written cell onto `r0`, moving left pushes it onto `r1` and then moves
+/// one cell over by halving `r0` the same way.  The program halts at
+/// `accept` for the machine's halting state and at `stuck` for a missing
+/// rule.
+#[derive(Clone, Debug)]
+pub struct TuringMinsky {
+    pub machine: Minsky,
+}
+
+/// `{p}`: moves `reg` into `r2` by halves, then back, ending at `even` or
+/// `odd` by the parity it had; `reg` is left halved.
+fn halve(out: &mut String, reg: &str, p: &str, even: &str, odd: &str) {
+    *out += &format!("{p}: dec {reg} {p}/h1 {p}/even\n");
+    *out += &format!("{p}/h1: dec {reg} {p}/h2 {p}/odd\n");
+    *out += &format!("{p}/h2: inc r2 {p}\n");
+    for (side, exit) in [("even", even), ("odd", odd)] {
+        *out += &format!("{p}/{side}: dec r2 {p}/{side}+ {exit}\n");
+        *out += &format!("{p}/{side}+: inc {reg} {p}/{side}\n");
+    }
+}
+
+/// `{p}`: doubles `reg` through `r2`, plus `bit`, then goes to `exit`.
+fn double(out: &mut String, reg: &str, p: &str, bit: bool, exit: &str) {
+    *out += &format!("{p}: dec {reg} {p}/d1 {p}/back\n");
+    *out += &format!("{p}/d1: inc r2 {p}/d2\n");
+    *out += &format!("{p}/d2: inc r2 {p}\n");
+    *out += &format!("{p}/back: dec r2 {p}/back+ {}\n", if bit { format!("{p}/bit") } else { exit.to_string() });
+    *out += &format!("{p}/back+: inc {reg} {p}/back\n");
+    if bit { *out += &format!("{p}/bit: inc {reg} {exit}\n"); }
+}
+
+impl TuringMinsky {
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::{
+    ///     minsky::TuringMinsky, universal_tm::{tape_numbers, Halt, TuringMachine},
+    /// };
+    ///
+    /// let mut tm = TuringMachine::parse("1RB1LB_1LA0LC_1RZ1LD_1RD0RA").unwrap();
+    /// let compiled = TuringMinsky::compile(&tm).unwrap();
+    /// let run = compiled.machine.run(&[0, 0], 1_000_000);
+    /// tm.run(1_000);
+    /// assert_eq!(compiled.halt(&run), Some(Halt::Accept));
+    /// assert_eq!((run.registers[0], run.registers[1]), tape_numbers(&tm));
+    /// ```
+    pub fn compile(tm: &TuringMachine) -> Result<Self, String> {
+        check_binary(tm)?;
+        let live = tm.live_states();
+        let label = |q: usize| live.iter().position(|&l| l == q).map_or("accept".to_string(), |i| format!("q{i}"));
+        let mut out = String::new();
+        for &q in &live {
+            let s = label(q);
+            let rule = |bit: char| tm.transitions.get(&(q, bit)).filter(|r| r.new_state != tm.reject_state);
+            halve(&mut out, "r1", &s, &format!("{s}.0"), &format!("{s}.1"));
+            for bit in ['0', '1'] {
+                let Some(r) = rule(bit) else {
+                    // Put the cell back before giving up.
+                    double(&mut out, "r1", &format!("{s}.{bit}"), bit == '1', "stuck");
+                    continue;
+                };
+                let (p, next, w) = (format!("{s}.{bit}"), label(r.new_state), r.write == '1');
+                match r.direction {
+                    Direction::Right => double(&mut out, "r0", &p, w, &next),
+                    Direction::Stay  => double(&mut out, "r1", &p, w, &next),
+                    Direction::Left  => {
+                        let pop = format!("{p}.pop");
+                        double(&mut out, "r1", &p, w, &pop);
+                        halve(&mut out, "r0", &pop, &format!("{pop}.0"), &format!("{pop}.1"));
+                        double(&mut out, "r1", &format!("{pop}.0"), false, &next);
+                        double(&mut out, "r1", &format!("{pop}.1"), true, &next);
+                    }
+                }
+            }
+        }
+        out += "accept: halt\nstuck: halt\n";
+        let mut machine = Minsky::parse(&out)?;
+        machine.registers = 3;
+        Ok(Self { machine })
+    }
+
+    /// How the machine stopped, if the run is over.
+    pub fn halt(&self, run: &MinskyRun) -> Option<Halt> {
+        if !run.halted {
+            None
+        } else if Some(run.pc) == self.machine.label("accept") {
+            Some(Halt::Accept)
+        } else {
+            Some(Halt::Undefined)
+        }
+    }
+}
@@ -1,13 +1,219 @@
 //! Type‑0 **Turing‑complete** automata.
+//!
+//! [`universal_tm`] holds single‑tape machines and a universal machine that
+//! runs them from an encoding, [`multi_tape`] k‑tape machines,
+//! [`format`] the standard notation and table formats and [`busy_beaver`]
+//! the enumeration of small machines with their halting analysis.
+//! [`tag`], [`cyclic_tag`] and [`minsky`] hold the other classic universal
+//! models – Post tag systems, cyclic tag systems and register machines –
+//! each with a compilation that carries binary machines over.  Spawned as
+//! automata, the presets run their machine and draw one tape, one row per
+//! step:
+//!
+//! * `tm:busy_beaver` – the four‑state busy beaver champion;
+//! * `tm:palindrome`  – a two‑tape palindrome decider, copying its input
+//!   and comparing it backwards;
+//! * `tm:universal`   – the universal machine running the three‑state
+//!   champion, one row per simulated step.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::tape::{TapeAlphabet, TapeHistory, TapeRow, TapeSession, TapeSessionPlugin, TapeSessions};
+use multi_tape::{MultiConfig, MultiTapeMachine, BLANK};
+use universal_tm::{RunReport, TuringMachine, UNIVERSAL_FETCH};
 
 pub struct TuringAutomataPlugin;
 
 impl Plugin for TuringAutomataPlugin {
-    fn build(&self, _app: &mut App) {
-        // TODO: register Universal TM, tag‑systems, etc.
+    fn build(&self, app: &mut App) {
+        app.add_plugins(TapeSessionPlugin::<TmSession>::default());
     }
 }
 
+/* ───── sub‑modules ───── */
+pub mod busy_beaver;
+pub mod cyclic_tag;
+pub mod format;
+pub mod minsky;
+pub mod multi_tape;
+pub mod tag;
 pub mod universal_tm;
+
+pub const TM_BUSY_BEAVER_ID: &str = "tm:busy_beaver";
+pub const TM_PALINDROME_ID: &str  = "tm:palindrome";
+pub const TM_UNIVERSAL_ID: &str   = "tm:universal";
+
+/// Runs 107 steps and leaves 13 ones.
+pub const BUSY_BEAVER_4: &str = "1RB1LB_1LA0LC_1RZ1LD_1RD0RA";
+
+/// Runs 21 steps and leaves 5 ones.
+pub const BUSY_BEAVER_3: &str = "1RB1RZ_1LB0RC_1LC1LA";
+
+/// Accepts the palindromes over `a` and `b` in about three passes.
+pub const PALINDROME_TM: &str = "\
+tapes 2
+start copy
+accept yes
+reject no
+copy a,_ -> a,a R,R copy
+copy b,_ -> b,b R,R copy
+copy _,_ -> _,_ L,L rewind
+rewind _,* -> _,* R,S compare
+rewind *,* -> *,* L,S rewind
+compare a,a -> a,a R,L compare
+compare b,b -> b,b R,L compare
+compare _,_ -> _,_ S,S yes
+compare *,* -> *,* S,S no";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TmParams {
+    /// Standard notation or a (multi‑tape) table.
+    pub machine:        String,
+    pub input:          String,
+    /// Run `machine` on the universal machine, drawing its simulated tape.
+    pub universal:      bool,
+    /// The tape drawn, for multi‑tape machines.
+    pub tape:           usize,
+    pub steps_per_tick: u32,
+    /// Steps before the run is given up as undecided.
+    pub max_steps:      u64,
+}
+
+impl Default for TmParams {
+    fn default() -> Self {
+        Self {
+            machine:        BUSY_BEAVER_4.into(),
+            input:          String::new(),
+            universal:      false,
+            tape:           0,
+            steps_per_tick: 1,
+            max_steps:      1_000_000,
+        }
+    }
+}
+
+impl TmParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+
+    /// Every preset id with its defaults.
+    pub fn presets() -> [(&'static str, Self); 3] {
+        [
+            (TM_BUSY_BEAVER_ID, Self::default()),
+            (TM_PALINDROME_ID, Self { machine: PALINDROME_TM.into(), input: "abbabaababba".into(), ..default() }),
+            (TM_UNIVERSAL_ID, Self { machine: BUSY_BEAVER_3.into(), universal: true, steps_per_tick: 200, ..default() }),
+        ]
+    }
+}
+
+/// A machine being run, with the history of the tape drawn.
+#[derive(Clone, Debug)]
+pub struct TmSession {
+    pub machine:   MultiTapeMachine,
+    pub config:    MultiConfig,
+    pub shown:     usize,
+    /// When set, rows are only added on entering this state.
+    pub mark:      Option<usize>,
+    /// Symbol codes, the blank excluded.
+    pub alphabet:  TapeAlphabet,
+    pub history:   TapeHistory,
+    pub max_steps: u64,
+    /// Set once the machine halts or runs out of steps.
+    pub report:    Option<RunReport>,
+}
+
+impl TmSession {
+    /// Keeps the last `rows` tapes.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::{TmParams, TmSession};
+    /// use computational_intelligence::automata::classical::turing::universal_tm::Halt;
+    ///
+    /// let mut s = TmSession::new(&TmParams::default(), 256).unwrap();
+    /// while s.advance(10) {}
+    /// let report = s.report.unwrap();
+    /// assert_eq!((report.halt, report.steps, report.marks), (Some(Halt::Accept), 107, 13));
+    /// assert_eq!(s.history.rows.len(), 108);
+    ///
+    /// let p = TmParams { universal: true, ..Default::default() };
+    /// let mut u = TmSession::new(&p, 256).unwrap();
+    /// while u.advance(1_000) {}
+    /// assert_eq!(u.report.unwrap().halt, Some(Halt::Accept));
+    /// assert_eq!(u.history.rows.len(), 108);    // one row per simulated step
+    /// ```
+    pub fn new(p: &TmParams, rows: usize) -> Result<Self, String> {
+        let (machine, config, shown, mark) = if p.universal {
+            let (utm, config) = universal_tm::simulate(&TuringMachine::parse(&p.machine)?, &p.input)?;
+            let fetch = utm.names.iter().position(|n| n == UNIVERSAL_FETCH);
+            (utm, config, 2, fetch)
+        } else {
+            let machine = if format::is_standard(&p.machine) {
+                MultiTapeMachine::from_single(&TuringMachine::from_standard(&p.machine)?)
+            } else {
+                MultiTapeMachine::parse(&p.machine)?
+            };
+            if p.tape >= machine.tapes {
+                return Err(format!("there is no tape {} of {}", p.tape, machine.tapes));
+            }
+            let config = machine.load(&[&p.input]);
+            (machine, config, p.tape, None)
+        };
+        let written  = machine.rules.values().flatten().flat_map(|r| r.read.iter().chain(&r.write).flatten());
+        let alphabet = TapeAlphabet::new(written.copied().chain(p.input.chars()), Some(BLANK))?;
+        let mut s = Self {
+            machine,
+            config,
+            shown,
+            mark,
+            alphabet,
+            history: TapeHistory::new(rows),
+            max_steps: p.max_steps,
+            report: None,
+        };
+        s.history.push(s.row());
+        Ok(s)
+    }
+
+    pub fn row(&self) -> TapeRow {
+        let tape = &self.config.tapes[self.shown];
+        self.alphabet.row(tape.origin, &tape.cells, tape.head)
+    }
+
+    /// Up to `steps` moves.  False once halted or out of steps.
+    pub fn advance(&mut self, steps: u32) -> bool {
+        for _ in 0..steps {
+            if self.report.is_some() { return false; }
+            let moved = self.config.steps < self.max_steps && self.machine.step(&mut self.config);
+            let halted = !moved || self.machine.halted(&self.config).is_some();
+            if moved && (halted || self.mark.is_none_or(|m| m == self.config.state)) {
+                self.history.push(self.row());
+            }
+            if halted {
+                self.report = Some(self.machine.run(&mut self.config, 0));
+            }
+        }
+        self.report.is_none()
+    }
+}
+
+impl TapeSession for TmSession {
+    type Params = TmParams;
+
+    const KIND: &'static str = "Turing machine";
+
+    fn presets() -> Vec<(&'static str, TmParams)> { TmParams::presets().into() }
+    fn start(p: &TmParams, rows: usize) -> Result<Self, String> { Self::new(p, rows) }
+    fn steps_per_tick(p: &TmParams) -> u32 { p.steps_per_tick }
+    fn advance(&mut self, steps: u32) -> bool { self.advance(steps) }
+    fn steps(&self) -> u64 { self.config.steps }
+    fn history(&self) -> &TapeHistory { &self.history }
+    fn head(&self) -> i64 { self.config.tapes[self.shown].head }
+    fn outcome(&self) -> Option<String> { self.report.as_ref().map(ToString::to_string) }
+}
+
+/// Turing machines of every spawned preset.
+pub type TmSessions = TapeSessions<TmSession>;
@@ -0,0 +1,290 @@
+//! Deterministic k‑tape Turing machines.
+//!
+//! Text format, one line each, `#` comments; a rule reads one symbol per
+//! tape and the first rule that matches wins:
+//!
+//! ```text
+//! tapes  2                  # optional, else taken from the rules
+//! start  q0
+//! accept qA                 # optional, as is `reject`
+//! q0 a,_ -> a,a R,R q0      # state reads -> writes moves next
+//! q0 *,* -> *,* S,S qA      # `*` reads anything, or writes back what was read
+//! ```
+//!
+//! `_` is the blank, and moves are `L`, `R` or `S`(tay).  A one‑tape table
+//! is also the readable format of [`TuringMachine`].
+
+use std::{collections::BTreeMap, fmt};
+
+use super::universal_tm::{Direction, Halt, RunReport, TuringMachine};
+use crate::automata::classical::tape::{intern, single};
+
+pub const BLANK: char    = '_';
+pub const WILDCARD: char = '*';
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiRule {
+    /// Per tape; `None` matches any symbol.
+    pub read:  Vec<Option<char>>,
+    /// Per tape; `None` leaves the symbol.
+    pub write: Vec<Option<char>>,
+    pub moves: Vec<Direction>,
+    pub to:    usize,
+}
+
+/// One tape: `cells[i]` is position `origin + i`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tape {
+    pub cells:  Vec<char>,
+    pub origin: i64,
+    pub head:   i64,
+}
+
+impl Tape {
+    pub fn new(input: &str) -> Self { Self { cells: input.chars().collect(), origin: 0, head: 0 } }
+
+    pub fn get(&self, pos: i64) -> char {
+        usize::try_from(pos - self.origin).ok().and_then(|i| self.cells.get(i)).copied().unwrap_or(BLANK)
+    }
+
+    pub fn read(&self) -> char { self.get(self.head) }
+
+    pub fn write(&mut self, c: char) {
+        if self.head < self.origin {
+            let grow = (self.origin - self.head) as usize;
+            self.cells.splice(0..0, std::iter::repeat_n(BLANK, grow));
+            self.origin = self.head;
+        }
+        let i = (self.head - self.origin) as usize;
+        if i >= self.cells.len() {
+            self.cells.resize(i + 1, BLANK);
+        }
+        self.cells[i] = c;
+    }
+
+    pub fn shift(&mut self, d: Direction) {
+        match d {
+            Direction::Left  => self.head -= 1,
+            Direction::Right => self.head += 1,
+            Direction::Stay  => {}
+        }
+    }
+
+    /// Non‑blank cells.
+    pub fn marks(&self) -> usize { self.cells.iter().filter(|&&c| c != BLANK).count() }
+
+    /// The tape between its outermost non‑blank cells.
+    pub fn contents(&self) -> String {
+        let s: String = self.cells.iter().collect();
+        s.trim_matches(BLANK).to_string()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiConfig {
+    pub state: usize,
+    pub tapes: Vec<Tape>,
+    pub steps: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultiTapeMachine {
+    pub names:  Vec<String>,
+    pub tapes:  usize,
+    pub start:  usize,
+    pub accept: Option<usize>,
+    pub reject: Option<usize>,
+    /// Rules per state, in the order they are tried.
+    pub rules:  BTreeMap<usize, Vec<MultiRule>>,
+}
+
+impl MultiTapeMachine {
+    /// State called `name`, created on first use.
+    pub fn state(&mut self, name: &str) -> usize { intern(&mut self.names, name) }
+
+    /// Reads the text format (see the [module docs](self)).
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut m = MultiTapeMachine::default();
+        let mut start = None;
+        for (n, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() { continue; }
+            let err = |e: &str| format!("line {}: {e}", n + 1);
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                ["tapes", k] => m.tapes = k.parse().ok().filter(|&k| k > 0).ok_or(err("tapes must be a positive number"))?,
+                ["start", q] => start = Some(m.state(q)),
+                ["accept", q] => m.accept = Some(m.state(q)),
+                ["reject", q] => m.reject = Some(m.state(q)),
+                [q, reads, "->", writes, moves, to] => {
+                    let symbols = |s: &str| -> Result<Vec<Option<char>>, String> {
+                        s.split(',')
+                            .map(|c| match single(c) {
+                                Some(WILDCARD) => Ok(None),
+                                Some(c)        => Ok(Some(c)),
+                                None           => Err(err("symbols are single characters")),
+                            })
+                            .collect()
+                    };
+                    let (read, write) = (symbols(reads)?, symbols(writes)?);
+                    let moves = moves
+                        .split(',')
+                        .map(|d| single(d).and_then(Direction::from_letter).ok_or(err("moves are L, R or S")))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    if m.tapes == 0 { m.tapes = read.len(); }
+                    if [read.len(), write.len(), moves.len()].iter().any(|&k| k != m.tapes) {
+                        return Err(err(&format!("expected {} symbols, writes and moves", m.tapes)));
+                    }
+                    let (from, to) = (m.state(q), m.state(to));
+                    m.rules.entry(from).or_default().push(MultiRule { read, write, moves, to });
+                }
+                _ => return Err(err("expected 'tapes k', 'start q', 'accept q', 'reject q' or 'q a,… -> b,… M,… r'")),
+            }
+        }
+        m.start = start.ok_or("missing 'start'")?;
+        m.tapes = m.tapes.max(1);
+        Ok(m)
+    }
+
+    /// The same machine as a one‑tape table, its blank written `_`.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::{
+    ///     multi_tape::MultiTapeMachine, universal_tm::{Halt, TuringMachine},
+    /// };
+    ///
+    /// let bb = TuringMachine::parse("1RB1LB_1LA0LC_1RZ1LD_1RD0RA").unwrap();
+    /// let m = MultiTapeMachine::from_single(&bb);
+    /// let mut c = m.load(&[""]);
+    /// let report = m.run(&mut c, 1_000);
+    /// assert_eq!((report.halt, report.steps), (Some(Halt::Accept), 107));
+    /// assert_eq!(c.tapes[0].contents(), "1_111111111111");
+    /// ```
+    pub fn from_single(tm: &TuringMachine) -> Self {
+        let sym = |c: char| if c == tm.blank { BLANK } else { c };
+        let mut m = MultiTapeMachine {
+            names:  (0..=tm.live_states().into_iter().chain([tm.accept_state, tm.reject_state]).max().unwrap_or(0))
+                .map(|q| tm.name(q))
+                .collect(),
+            tapes:  1,
+            start:  tm.start_state,
+            accept: Some(tm.accept_state),
+            reject: Some(tm.reject_state),
+            rules:  BTreeMap::new(),
+        };
+        let mut keys: Vec<_> = tm.transitions.keys().copied().collect();
+        keys.sort();
+        for (q, read) in keys {
+            let t = tm.transitions[&(q, read)];
+            m.rules.entry(q).or_default().push(MultiRule {
+                read:  vec![Some(sym(read))],
+                write: vec![Some(sym(t.write))],
+                moves: vec![t.direction],
+                to:    t.new_state,
+            });
+        }
+        m
+    }
+
+    /// Tape `i` holds `inputs[i]` (blank if missing), every head at 0.
+    pub fn load(&self, inputs: &[&str]) -> MultiConfig {
+        let tapes = (0..self.tapes).map(|i| Tape::new(inputs.get(i).copied().unwrap_or(""))).collect();
+        MultiConfig { state: self.start, tapes, steps: 0 }
+    }
+
+    /// The first rule matching `c`.
+    pub fn rule(&self, c: &MultiConfig) -> Option<&MultiRule> {
+        self.rules.get(&c.state)?.iter().find(|r| {
+            r.read.iter().zip(&c.tapes).all(|(s, t)| s.is_none_or(|s| s == t.read()))
+        })
+    }
+
+    pub fn halted(&self, c: &MultiConfig) -> Option<Halt> {
+        if Some(c.state) == self.accept {
+            Some(Halt::Accept)
+        } else if Some(c.state) == self.reject {
+            Some(Halt::Reject)
+        } else if self.rule(c).is_none() {
+            Some(Halt::Undefined)
+        } else {
+            None
+        }
+    }
+
+    /// One move; false once halted.
+    pub fn step(&self, c: &mut MultiConfig) -> bool {
+        if self.halted(c).is_some() { return false; }
+        let Some(r) = self.rule(c).cloned() else { return false };
+        for ((tape, w), d) in c.tapes.iter_mut().zip(&r.write).zip(&r.moves) {
+            if let Some(w) = w { tape.write(*w); }
+            tape.shift(*d);
+        }
+        c.state = r.to;
+        c.steps += 1;
+        true
+    }
+
+    /// Runs at most `limit` steps and reports how the machine stopped.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::{
+    ///     multi_tape::MultiTapeMachine, universal_tm::Halt, PALINDROME_TM,
+    /// };
+    ///
+    /// let m = MultiTapeMachine::parse(PALINDROME_TM).unwrap();
+    /// for (w, ok) in [("abba", true), ("aba", true), ("", true), ("abb", false)] {
+    ///     let mut c = m.load(&[w]);
+    ///     let accepted = m.run(&mut c, 1_000).halt == Some(Halt::Accept);
+    ///     assert_eq!(accepted, ok, "{w}");
+    ///     assert!(c.steps <= 3 * w.len() as u64 + 3);
+    /// }
+    /// ```
+    pub fn run(&self, c: &mut MultiConfig, limit: u64) -> RunReport {
+        let end = c.steps.saturating_add(limit);
+        while c.steps < end && self.step(c) {}
+        RunReport {
+            halt:  self.halted(c),
+            state: self.names[c.state].clone(),
+            steps: c.steps,
+            marks: c.tapes.iter().map(Tape::marks).sum(),
+        }
+    }
+
+    /// `state: tape | tape` with each head symbol bracketed.
+    pub fn describe(&self, c: &MultiConfig) -> String {
+        let tapes: Vec<String> = c.tapes
+            .iter()
+            .map(|t| {
+                let lo = t.origin.min(t.head);
+                let hi = (t.origin + t.cells.len() as i64).max(t.head + 1);
+                (lo..hi).map(|p| if p == t.head { format!("[{}]", t.get(p)) } else { t.get(p).to_string() }).collect()
+            })
+            .collect();
+        format!("{}: {}", self.names[c.state], tapes.join(" | "))
+    }
+}
+
+impl fmt::Display for MultiTapeMachine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |v: Vec<char>| v.iter().map(char::to_string).collect::<Vec<_>>().join(",");
+        let symbols = |v: &[Option<char>]| join(v.iter().map(|s| s.unwrap_or(WILDCARD)).collect());
+        writeln!(f, "tapes {}", self.tapes)?;
+        writeln!(f, "start {}", self.names[self.start])?;
+        if let Some(q) = self.accept { writeln!(f, "accept {}", self.names[q])?; }
+        if let Some(q) = self.reject { writeln!(f, "reject {}", self.names[q])?; }
+        for (q, rules) in &self.rules {
+            for r in rules {
+                writeln!(
+                    f,
+                    "{} {} -> {} {} {}",
+                    self.names[*q],
+                    symbols(&r.read),
+                    symbols(&r.write),
+                    join(r.moves.iter().map(|d| d.letter()).collect()),
+                    self.names[r.to],
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,322 @@
+//! Post m‑tag systems, and 2‑tag systems compiled from Turing machines.
+//!
+//! Each step reads the first symbol, deletes the first `m` and appends the
+//! symbol's production.  A run halts on reaching a symbol without a
+//! production, or once the word is shorter than `m`.
+//!
+//! Text format, one line each, `#` comments; words are split on spaces if
+//! they have any, else into characters, and `ε` is the empty word:
+//!
+//! ```text
+//! m 2
+//! a -> bc
+//! b -> a
+//! c -> aaa
+//! H                    # a halting symbol
+//! ```
+
+use std::{collections::VecDeque, fmt};
+
+use super::universal_tm::{check_binary, Direction, TuringMachine};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TagHalt {
+    /// Shorter than the deletion number.
+    Short,
+    /// Read a symbol without a production.
+    Symbol(usize),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TagRun {
+    pub word:  VecDeque<usize>,
+    pub steps: u64,
+    /// `None` when the step limit came first.
+    pub halt:  Option<TagHalt>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TagSystem {
+    /// Deletion number.
+    pub m:           usize,
+    pub names:       Vec<String>,
+    /// Per symbol; `None` halts.
+    pub productions: Vec<Option<Vec<usize>>>,
+}
+
+impl TagSystem {
+    pub fn new(m: usize) -> Self { Self { m: m.max(1), ..Default::default() } }
+
+    /// Index of symbol `name`, adding it (halting) if new.
+    pub fn symbol(&mut self, name: &str) -> usize {
+        match self.names.iter().position(|n| n == name) {
+            Some(i) => i,
+            None => {
+                self.names.push(name.to_string());
+                self.productions.push(None);
+                self.names.len() - 1
+            }
+        }
+    }
+
+    /// Gives `symbol` a production, adding any new symbols.
+    pub fn set<S: AsRef<str>>(&mut self, symbol: &str, production: &[S]) {
+        let i = self.symbol(symbol);
+        let p = production.iter().map(|s| self.symbol(s.as_ref())).collect();
+        self.productions[i] = Some(p);
+    }
+
+    /// Reads the text format (see the [module docs](self)); what
+    /// [`Display`](fmt::Display) writes reads back unchanged.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::tag::TagSystem;
+    ///
+    /// let t = TagSystem::parse("m 2\nm -> ab\na -> ε\nb").unwrap();
+    /// assert_eq!((t.m, t.names.len()), (2, 3));
+    /// assert_eq!(t.productions[1], Some(vec![]));
+    /// assert_eq!(TagSystem::parse(&t.to_string()).unwrap(), t);
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut t = TagSystem::new(2);
+        for (n, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            if line.is_empty() { continue; }
+            let err = |e: &str| format!("line {}: {e}", n + 1);
+            if let Some((lhs, rhs)) = line.split_once("->") {
+                let lhs = lhs.trim();
+                if lhs.is_empty() || lhs.contains(char::is_whitespace) {
+                    return Err(err("expected one symbol before '->'"));
+                }
+                let rhs = if rhs.trim() == "ε" { Vec::new() } else { split_word(rhs) };
+                t.set(lhs, &rhs);
+            } else if let Some(m) = line.strip_prefix("m ") {
+                t.m = m.trim().parse().ok().filter(|&m| m > 0).ok_or(err("m must be a positive number"))?;
+            } else if !line.contains(char::is_whitespace) {
+                t.symbol(line);
+            } else {
+                return Err(err("expected 'm k', 'a -> word' or a halting symbol"));
+            }
+        }
+        Ok(t)
+    }
+
+    /// Symbols of `text`, split as in the text format.
+    pub fn word(&self, text: &str) -> Result<Vec<usize>, String> {
+        split_word(text)
+            .into_iter()
+            .map(|s| self.names.iter().position(|n| n == s).ok_or(format!("unknown symbol '{s}'")))
+            .collect()
+    }
+
+    pub fn show(&self, word: impl IntoIterator<Item = usize>) -> String {
+        let sep = if self.names.iter().all(|n| n.chars().count() == 1) { "" } else { " " };
+        word.into_iter().map(|s| self.names[s].as_str()).collect::<Vec<_>>().join(sep)
+    }
+
+    /// One step; the reason it cannot move otherwise.
+    pub fn step(&self, word: &mut VecDeque<usize>) -> Result<(), TagHalt> {
+        if word.len() < self.m { return Err(TagHalt::Short); }
+        let first = word[0];
+        let Some(p) = &self.productions[first] else { return Err(TagHalt::Symbol(first)) };
+        word.drain(..self.m);
+        word.extend(p);
+        Ok(())
+    }
+
+    /// Runs at most `limit` steps.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::tag::{TagHalt, TagSystem};
+    ///
+    /// // De Mol's 2‑tag system for the Collatz map on aⁿ.
+    /// let collatz = TagSystem::parse("a -> bc\nb -> a\nc -> aaa").unwrap();
+    /// let run = collatz.run(&collatz.word("aaa").unwrap(), 1_000);
+    /// assert_eq!(run.halt, Some(TagHalt::Short));
+    /// assert_eq!(collatz.show(run.word), "a");
+    /// assert_eq!(collatz.trace(&collatz.word("aaa").unwrap(), 3), ["aaa", "abc", "cbc", "caaa"]);
+    /// ```
+    pub fn run(&self, word: &[usize], limit: u64) -> TagRun {
+        let mut word: VecDeque<usize> = word.iter().copied().collect();
+        let mut steps = 0;
+        while steps < limit {
+            if let Err(h) = self.step(&mut word) {
+                return TagRun { word, steps, halt: Some(h) };
+            }
+            steps += 1;
+        }
+        let halt = self.step(&mut word.clone()).err();
+        TagRun { word, steps, halt }
+    }
+
+    /// The word before each of at most `steps` steps, and after the last.
+    pub fn trace(&self, word: &[usize], steps: u64) -> Vec<String> {
+        let mut word: VecDeque<usize> = word.iter().copied().collect();
+        let mut out = vec![self.show(word.iter().copied())];
+        for _ in 0..steps {
+            if self.step(&mut word).is_err() { break; }
+            out.push(self.show(word.iter().copied()));
+        }
+        out
+    }
+}
+
+fn split_word(text: &str) -> Vec<&str> {
+    let text = text.trim();
+    if text.contains(char::is_whitespace) {
+        text.split_whitespace().collect()
+    } else {
+        text.char_indices().map(|(i, c)| &text[i..i + c.len_utf8()]).collect()
+    }
+}
+
+impl fmt::Display for TagSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "m {}", self.m)?;
+        for (s, p) in self.productions.iter().enumerate() {
+            match p {
+                Some(p) if p.is_empty() => writeln!(f, "{} -> ε", self.names[s])?,
+                Some(p)                 => writeln!(f, "{} -> {}", self.names[s], self.show(p.iter().copied()))?,
+                None                    => writeln!(f, "{}", self.names[s])?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/* ───── Turing machines as 2‑tag systems ───── */
+
+/// A 2‑tag system simulating a binary Turing machine (after Cocke and
+/// Minsky).
+///
+/// With the tape as the two numbers of
+/// [`tape_numbers`](super::universal_tm::tape_numbers), live state `i` is
+/// the word `Ai x (ai x)^left Bi x (bi x)^right`.  A machine step takes
+/// three passes over the word: the first two halve `right`, leaving its
+/// parity – the head cell – as the choice of which symbols the third pass
+/// reads, and the third writes the next word.  Moving left takes three more
+/// passes, halving `left` the same way.  Runs stop on `AZ`, for the
+/// halting state, or on a symbol for a rule the machine lacks.
+#[derive(Clone, Debug)]
+pub struct TuringTag {
+    pub system: TagSystem,
+    /// Machine state names by index, the halting state last.
+    pub states: Vec<String>,
+}
+
+/// `sym x`, `k` times.
+fn pairs(sym: &str, k: usize) -> Vec<String> {
+    [sym.to_string(), "x".to_string()].into_iter().cycle().take(2 * k).collect()
+}
+
+/// `head` followed by `tail`.
+fn then(head: Vec<String>, tail: Vec<String>) -> Vec<String> {
+    head.into_iter().chain(tail).collect()
+}
+
+impl TuringTag {
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::{
+    ///     tag::TuringTag, universal_tm::{tape_numbers, TuringMachine},
+    /// };
+    ///
+    /// let mut tm = TuringMachine::parse("1RB1LB_1LA0LC_1RZ1LD_1RD0RA").unwrap();
+    /// let tag = TuringTag::compile(&tm).unwrap();
+    /// let run = tag.system.run(&tag.word(0, 0, 0), 10_000_000);
+    /// tm.run(1_000);
+    /// assert_eq!(tag.decode(&run.word), Some(("Z".to_string(), tape_numbers(&tm))));
+    /// assert_eq!(tag.decode(&Default::default()), None);
+    /// ```
+    pub fn compile(tm: &TuringMachine) -> Result<Self, String> {
+        check_binary(tm)?;
+        let live = tm.live_states();
+        let label = |q: usize| live.iter().position(|&l| l == q).map_or("Z".to_string(), |i| i.to_string());
+        let mut t = TagSystem::new(2);
+        t.symbol("x");
+
+        for &q in &live {
+            let s = label(q);
+            let sym = |f: &str| format!("{f}{s}");
+            // Pass 1: `a` stays in pairs, `b` goes single.
+            t.set(&sym("A"), &pairs(&sym("C"), 1));
+            t.set(&sym("a"), &pairs(&sym("c"), 1));
+            t.set(&sym("B"), &[sym("D")]);
+            t.set(&sym("b"), &[sym("d")]);
+            // Pass 2: both readings, odd first; the parity of `right` picks one.
+            for f in ["C", "c", "D", "d"] {
+                t.set(&sym(f), &[format!("{f}{s}_1"), format!("{f}{s}_0")]);
+            }
+            // Pass 3: the rule for the head cell, after a pad to realign.
+            for bit in ['0', '1'] {
+                let Some(r) = tm.transitions.get(&(q, bit)).filter(|r| r.new_state != tm.reject_state) else {
+                    continue;
+                };
+                let next = label(r.new_state);
+                let n = |f: &str| format!("{f}{next}");
+                let w = usize::from(r.write == '1');
+                let [marker, a, marker_b, b] = match r.direction {
+                    Direction::Right => [then(pairs(&n("A"), 1), pairs(&n("a"), w)), pairs(&n("a"), 2), pairs(&n("B"), 1), pairs(&n("b"), 1)],
+                    Direction::Stay  => [pairs(&n("A"), 1), pairs(&n("a"), 1), then(pairs(&n("B"), 1), pairs(&n("b"), w)), pairs(&n("b"), 2)],
+                    Direction::Left  => [pairs(&n("E"), 1), pairs(&n("e"), 1), then(pairs(&n("F"), 1), pairs(&n("f"), w)), pairs(&n("f"), 2)],
+                };
+                let pad = if bit == '0' { vec!["x".to_string()] } else { vec![] };
+                let sb = |f: &str| format!("{f}{s}_{bit}");
+                t.set(&sb("C"), &then(pad, marker));
+                t.set(&sb("c"), &a);
+                t.set(&sb("D"), &marker_b);
+                t.set(&sb("d"), &b);
+            }
+        }
+
+        // Moving left into each state: take the cell off `left` by halving
+        // it, and push it onto `right`, which the step already doubled.
+        for s in (0..live.len()).map(|i| i.to_string()).chain(["Z".to_string()]) {
+            let sym = |f: &str| format!("{f}{s}");
+            t.set(&sym("E"), &[sym("G")]);
+            t.set(&sym("e"), &[sym("g")]);
+            t.set(&sym("F"), &[sym("H"), sym("H")]);
+            t.set(&sym("f"), &[sym("h"), sym("h")]);
+            for f in ["G", "g", "H", "h"] {
+                t.set(&sym(f), &[format!("{f}{s}_1"), format!("{f}{s}_0")]);
+            }
+            for bit in [0, 1] {
+                let pad = if bit == 0 { vec!["x".to_string()] } else { vec![] };
+                let sb = |f: &str| format!("{f}{s}_{bit}");
+                t.set(&sb("G"), &then(pad, pairs(&sym("A"), 1)));
+                t.set(&sb("g"), &pairs(&sym("a"), 1));
+                t.set(&sb("H"), &then(pairs(&sym("B"), 1), pairs(&sym("b"), bit)));
+                t.set(&sb("h"), &pairs(&sym("b"), 2));
+            }
+        }
+        for f in ["A", "a", "B", "b"] {
+            t.symbol(&format!("{f}Z"));
+        }
+
+        let states = live.iter().chain([&tm.accept_state]).map(|&q| tm.name(q)).collect();
+        Ok(Self { system: t, states })
+    }
+
+    /// The word for state `state`, an index into [`states`](Self::states),
+    /// with the tape numbers `left` and `right`.
+    pub fn word(&self, state: usize, left: u64, right: u64) -> Vec<usize> {
+        let s = if state + 1 == self.states.len() { "Z".to_string() } else { state.to_string() };
+        let find = |name: &str| self.system.names.iter().position(|n| n == name).expect("compiled symbol");
+        let sym = |f: &str| find(&format!("{f}{s}"));
+        let x = find("x");
+        let mut w = vec![sym("A"), x];
+        for _ in 0..left { w.extend([sym("a"), x]); }
+        w.extend([sym("B"), x]);
+        for _ in 0..right { w.extend([sym("b"), x]); }
+        w
+    }
+
+    /// The state name and tape numbers of a word about to start a machine
+    /// step, or to halt.
+    pub fn decode(&self, word: &VecDeque<usize>) -> Option<(String, (u64, u64))> {
+        let name = |i: usize| self.system.names[word[i]].as_str();
+        let s = self.system.names[*word.front()?].strip_prefix('A')?;
+        let state = if s == "Z" { self.states.len() - 1 } else { s.parse().ok()? };
+        let count = |f: &str| (0..word.len()).filter(|&i| name(i).strip_prefix(f) == Some(s)).count() as u64;
+        Some((self.states[state].clone(), (count("a"), count("b"))))
+    }
+}
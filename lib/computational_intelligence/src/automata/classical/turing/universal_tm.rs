@@ -1,68 +1,169 @@
-//! A simple example Turing machine (not truly universal, but illustrative).
+//! Single‑tape deterministic Turing machines, and a universal machine that
+//! runs them from an encoding on its own tape.
+//!
+//! The tape is unbounded both ways and grows as the head moves.  A machine
+//! stops in its accept or reject state, or when no rule covers the current
+//! state and symbol; [`TuringMachine::run_report`] says which, or that the
+//! step limit came first.
+
+use std::{collections::{BTreeSet, HashMap}, fmt};
+
+use super::multi_tape::{MultiConfig, MultiTapeMachine};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-pub enum Direction { Left, Right }
+pub enum Direction { Left, Right, Stay }
+
+impl Direction {
+    pub fn letter(self) -> char {
+        match self {
+            Direction::Left  => 'L',
+            Direction::Right => 'R',
+            Direction::Stay  => 'S',
+        }
+    }
+
+    pub fn from_letter(c: char) -> Option<Self> {
+        match c {
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            'S' => Some(Direction::Stay),
+            _   => None,
+        }
+    }
+}
 
 /// A single transition rule for a Turing machine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct TMTransition {
     pub write: char,
     pub new_state: usize,
     pub direction: Direction,
 }
 
-/// A deterministic Turing machine with a single tape (semi-infinite to the right).
+/// Why a machine stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Halt {
+    Accept,
+    Reject,
+    /// No rule for the current state and symbol.
+    Undefined,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunReport {
+    /// `None` when the step limit came first.
+    pub halt:  Option<Halt>,
+    pub state: String,
+    pub steps: u64,
+    /// Non‑blank cells left on the tape(s).
+    pub marks: usize,
+}
+
+impl fmt::Display for RunReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.halt {
+            Some(Halt::Accept)    => write!(f, "accepted in {}", self.state)?,
+            Some(Halt::Reject)    => write!(f, "rejected in {}", self.state)?,
+            Some(Halt::Undefined) => write!(f, "halted with no rule in {}", self.state)?,
+            None                  => write!(f, "still running in {}", self.state)?,
+        }
+        write!(f, " after {} steps, {} marks", self.steps, self.marks)
+    }
+}
+
+/// A deterministic Turing machine with a single tape, unbounded both ways.
+#[derive(Clone, Debug)]
 pub struct TuringMachine {
     pub tape: Vec<char>,
     pub head: usize,
+    /// Tape position of `tape[0]`; negative once the tape has grown left.
+    pub origin: i64,
+    pub blank: char,
+    /// State names, for the text formats; missing ones print as `q<n>`.
+    pub names: Vec<String>,
+    pub start_state: usize,
     pub state: usize,
     pub accept_state: usize,
     pub reject_state: usize,
-    pub transitions: std::collections::HashMap<(usize, char), TMTransition>,
+    pub transitions: HashMap<(usize, char), TMTransition>,
 }
 
 impl TuringMachine {
-    /// Step the Turing machine by one transition. Returns false if a halting state is reached.
+    /// A machine with no rules and an empty tape, in `start`.
+    pub fn new(start: usize, accept: usize, reject: usize, blank: char) -> Self {
+        Self {
+            tape: Vec::new(),
+            head: 0,
+            origin: 0,
+            blank,
+            names: Vec::new(),
+            start_state: start,
+            state: start,
+            accept_state: accept,
+            reject_state: reject,
+            transitions: HashMap::new(),
+        }
+    }
+
+    pub fn name(&self, q: usize) -> String {
+        self.names.get(q).cloned().unwrap_or_else(|| format!("q{q}"))
+    }
+
+    /// Writes `input` from position 0 and puts the head there in the start
+    /// state.
+    pub fn load(&mut self, input: &str) {
+        self.tape   = input.chars().collect();
+        self.head   = 0;
+        self.origin = 0;
+        self.state  = self.start_state;
+    }
+
+    pub fn read(&self) -> char { self.tape.get(self.head).copied().unwrap_or(self.blank) }
+
+    /// Absolute head position.
+    pub fn position(&self) -> i64 { self.origin + self.head as i64 }
+
+    /// Why the machine cannot move, if it cannot.
+    pub fn halted(&self) -> Option<Halt> {
+        if self.state == self.accept_state {
+            Some(Halt::Accept)
+        } else if self.state == self.reject_state {
+            Some(Halt::Reject)
+        } else if !self.transitions.contains_key(&(self.state, self.read())) {
+            Some(Halt::Undefined)
+        } else {
+            None
+        }
+    }
+
+    /// Step the Turing machine by one transition. Returns false if it has
+    /// halted (see [`halted`](Self::halted)); a missing rule leaves the state
+    /// as it is.
     pub fn step(&mut self) -> bool {
         if self.state == self.accept_state || self.state == self.reject_state {
             return false;
         }
-        // Read current tape symbol (blank if head is beyond tape end)
-        let current_symbol = if self.head < self.tape.len() {
-            self.tape[self.head]
-        } else {
-            ' ' // blank symbol
-        };
-        if let Some(trans) = self.transitions.get(&(self.state, current_symbol)) {
-            // Write symbol
-            if self.head < self.tape.len() {
-                self.tape[self.head] = trans.write;
-            } else {
-                // extend tape if head is at new cell
-                self.tape.push(trans.write);
-            }
-            // Move head
-            match trans.direction {
-                Direction::Left => {
-                    if self.head > 0 {
-                        self.head -= 1;
-                    } else {
-                        // extend tape on left (unbounded to left) by adding blank at front
-                        self.tape.insert(0, ' ');
-                        // head stays at 0
-                    }
-                }
-                Direction::Right => {
-                    self.head += 1;
+        let Some(&trans) = self.transitions.get(&(self.state, self.read())) else { return false };
+        if self.head == self.tape.len() {
+            self.tape.push(self.blank);
+        }
+        self.tape[self.head] = trans.write;
+        match trans.direction {
+            Direction::Left => {
+                if self.head == 0 {
+                    // Grow left in chunks so long leftward runs stay linear.
+                    let grow = self.tape.len().max(8);
+                    self.tape.splice(0..0, std::iter::repeat_n(self.blank, grow));
+                    self.head   = grow;
+                    self.origin -= grow as i64;
                 }
+                self.head -= 1;
             }
-            // Update state
-            self.state = trans.new_state;
-            true
-        } else {
-            // No transition defined for this state/symbol (implicit reject)
-            self.state = self.reject_state;
-            false
+            Direction::Right => self.head += 1,
+            Direction::Stay  => {}
         }
+        self.state = trans.new_state;
+        true
     }
 
     /// Runs the Turing machine until halt (accept or reject) or until max_steps exceeded.
@@ -75,6 +176,60 @@ impl TuringMachine {
         // Return true if accepted
         self.state == self.accept_state
     }
+
+    /// Runs at most `limit` steps and reports how the machine stopped.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::classical::turing::universal_tm::{Halt, TuringMachine};
+    ///
+    /// let mut bb = TuringMachine::parse("1RB1LB_1LA0LC_1RZ1LD_1RD0RA").unwrap();
+    /// let report = bb.run_report(1_000);
+    /// assert_eq!(report.halt, Some(Halt::Accept));
+    /// assert_eq!((report.steps, report.marks), (107, 13));
+    /// assert_eq!(report.to_string(), "accepted in Z after 107 steps, 13 marks");
+    ///
+    /// bb.load("");
+    /// assert_eq!(bb.run_report(100).halt, None);
+    /// ```
+    pub fn run_report(&mut self, limit: u64) -> RunReport {
+        let mut steps = 0;
+        while steps < limit && self.step() {
+            steps += 1;
+        }
+        RunReport { halt: self.halted(), state: self.name(self.state), steps, marks: self.marks() }
+    }
+
+    /// Non‑blank cells on the tape.
+    pub fn marks(&self) -> usize { self.tape.iter().filter(|&&c| c != self.blank).count() }
+
+    /// The tape between its outermost non‑blank cells.
+    pub fn contents(&self) -> String {
+        let s: String = self.tape.iter().collect();
+        s.trim_matches(self.blank).to_string()
+    }
+
+    /// Every symbol the rules read or write, blank included.
+    pub fn symbols(&self) -> BTreeSet<char> {
+        self.transitions
+            .iter()
+            .flat_map(|(&(_, read), t)| [read, t.write])
+            .chain(std::iter::once(self.blank))
+            .collect()
+    }
+
+    /// Named states and those the rules mention, other than accept and
+    /// reject, the start state first.
+    pub fn live_states(&self) -> Vec<usize> {
+        let mut states: BTreeSet<usize> = self.transitions
+            .iter()
+            .flat_map(|(&(q, _), t)| [q, t.new_state])
+            .chain(0..self.names.len())
+            .collect();
+        states.remove(&self.start_state);
+        states.remove(&self.accept_state);
+        states.remove(&self.reject_state);
+        std::iter::once(self.start_state).chain(states).collect()
+    }
 }
 
 /// Constructs a simple Turing machine that replaces all 'a' characters with 'X' then halts.
@@ -82,18 +237,167 @@ pub fn example_tm_replace_a() -> TuringMachine {
     // States: 0 = initial, 1 = accept
     let accept = 1;
     let reject = 2;
-    let mut transitions = std::collections::HashMap::new();
+    let mut tm = TuringMachine::new(0, accept, reject, ' ');
     // In state 0:
     //  - on 'a': write 'X', stay in 0, move Right
     //  - on blank: write blank, go to accept state (1), move Left (head goes back one)
-    transitions.insert((0, 'a'), TMTransition { write: 'X', new_state: 0, direction: Direction::Right });
-    transitions.insert((0, ' ',), TMTransition { write: ' ', new_state: accept, direction: Direction::Left });
-    TuringMachine {
-        tape: Vec::new(),
-        head: 0,
-        state: 0,
-        accept_state: accept,
-        reject_state: reject,
-        transitions,
+    tm.transitions.insert((0, 'a'), TMTransition { write: 'X', new_state: 0, direction: Direction::Right });
+    tm.transitions.insert((0, ' ',), TMTransition { write: ' ', new_state: accept, direction: Direction::Left });
+    tm
+}
+
+/* ───── universal machine ───── */
+
+/// A three‑tape universal machine.  Tape 1 holds the program from
+/// [`encode`], tape 2 the simulated state after a `$` marker and tape 3 the
+/// simulated tape, blank cells reading as `0`.
+///
+/// Each program entry is `Iᵖ r w m Iⁿ ;`: in state `p` (counted from one)
+/// reading `r`, write `w`, move `m` and enter `n`, where zero `I`s is the
+/// halting state.  Every simulated step starts in `fetch`; the machine
+/// accepts when the simulated one halts and rejects when no entry matches.
+pub const UNIVERSAL_TM: &str = "\
+tapes 3
+start fetch
+accept halt
+reject stuck
+
+fetch  *,$,* -> *,*,* S,R,S scan
+
+# compare the entry's state with tape 2, then its symbol with tape 3
+scan   I,I,* -> *,*,* R,R,S scan
+scan   0,_,0 -> *,*,* R,S,S write
+scan   0,_,_ -> *,*,* R,S,S write
+scan   1,_,1 -> *,*,* R,S,S write
+scan   _,*,* -> *,*,* S,S,S stuck
+scan   *,*,* -> *,*,* S,S,S skip
+
+# no match: on to the next entry with tape 2 back at its start
+skip   ;,*,* -> *,*,* R,S,S back
+skip   *,*,* -> *,*,* R,S,S skip
+back   *,$,* -> *,*,* S,R,S scan
+back   *,*,* -> *,*,* S,L,S back
+
+# match: write, move, then copy the next state onto tape 2
+write  0,*,* -> *,*,0 R,S,S move
+write  1,*,* -> *,*,1 R,S,S move
+move   L,*,* -> *,*,* R,S,L rewind
+move   R,*,* -> *,*,* R,S,R rewind
+move   S,*,* -> *,*,* R,S,S rewind
+rewind *,$,* -> *,*,* S,R,S copy0
+rewind *,*,* -> *,*,* S,L,S rewind
+copy0  ;,*,* -> *,*,* S,S,S halt
+copy0  I,*,* -> *,I,* R,R,S copy
+copy   I,*,* -> *,I,* R,R,S copy
+copy   ;,*,* -> *,*,* S,S,S erase
+erase  *,I,* -> *,_,* S,R,S erase
+erase  *,*,* -> *,*,* S,S,S home
+
+# both heads home for the next step
+home   _,$,* -> *,*,* R,S,S fetch
+home   _,*,* -> *,*,* S,L,S home
+home   *,$,* -> *,*,* L,S,S home
+home   *,*,* -> *,*,* L,L,S home";
+
+/// The state the universal machine enters once per simulated step.
+pub const UNIVERSAL_FETCH: &str = "fetch";
+
+pub fn universal_machine() -> MultiTapeMachine {
+    MultiTapeMachine::parse(UNIVERSAL_TM).expect("the universal machine parses")
+}
+
+/// The program tape for `tm`, which must use the symbols `0` and `1` with
+/// `0` blank.  Rules into the reject state are left out, so the simulation
+/// stops there as it would on a missing rule.
+///
+/// ```
+/// use computational_intelligence::automata::classical::turing::universal_tm::{encode, TuringMachine};
+///
+/// let tm = TuringMachine::parse("1RB---_0LA1RZ").unwrap();
+/// assert_eq!(encode(&tm).unwrap(), "I01RII;II00LI;II11R;");
+/// ```
+pub fn encode(tm: &TuringMachine) -> Result<String, String> {
+    check_binary(tm)?;
+    let live = tm.live_states();
+    let unary = |q: usize| match live.iter().position(|&l| l == q) {
+        Some(i) => "I".repeat(i + 1),
+        None    => String::new(),
+    };
+    let mut program = String::new();
+    for &q in &live {
+        for read in ['0', '1'] {
+            let Some(t) = tm.transitions.get(&(q, read)) else { continue };
+            if t.new_state == tm.reject_state { continue; }
+            program += &format!("{}{read}{}{}{};", unary(q), t.write, t.direction.letter(), unary(t.new_state));
+        }
     }
+    Ok(program)
+}
+
+/// The universal machine loaded with `tm`'s program and `input`.
+///
+/// ```
+/// use computational_intelligence::automata::classical::turing::universal_tm::{
+///     decode, simulate, Halt, TuringMachine,
+/// };
+///
+/// let tm = TuringMachine::parse("1RB1LB_1LA0LC_1RZ1LD_1RD0RA").unwrap();
+/// let (utm, mut config) = simulate(&tm, "").unwrap();
+/// let report = utm.run(&mut config, 1_000_000);
+/// assert_eq!(report.halt, Some(Halt::Accept));
+/// assert_eq!(decode(&config), "10111111111111");
+/// ```
+pub fn simulate(tm: &TuringMachine, input: &str) -> Result<(MultiTapeMachine, MultiConfig), String> {
+    if input.chars().any(|c| !matches!(c, '0' | '1')) {
+        return Err("the input must be over 0 and 1".into());
+    }
+    let utm = universal_machine();
+    let config = utm.load(&[&encode(tm)?, "$I", input]);
+    Ok((utm, config))
+}
+
+/// The simulated tape between its outermost `1`s.
+pub fn decode(config: &MultiConfig) -> String {
+    let work: String = config.tapes[2].cells.iter().map(|&c| if c == '1' { '1' } else { '0' }).collect();
+    work.trim_matches('0').to_string()
+}
+
+/* ───── binary tapes as numbers ───── */
+
+/// Fails unless `tm` uses the symbols `0` and `1` with `0` blank, as the
+/// encodings and compilations of binary machines need.
+pub fn check_binary(tm: &TuringMachine) -> Result<(), String> {
+    if tm.blank != '0' || tm.symbols().iter().any(|c| !matches!(c, '0' | '1')) {
+        return Err("only machines over 0 and 1 with blank 0 can be encoded".into());
+    }
+    Ok(())
+}
+
+/// A binary tape as two numbers: `left` from the cells left of the head,
+/// nearest the lowest digit, and `right` from the head cell rightwards.
+/// Cells past 64 on either side are lost.
+///
+/// ```
+/// use computational_intelligence::automata::classical::turing::universal_tm::{show_numbers, tape_numbers, TuringMachine};
+///
+/// let mut tm = TuringMachine::parse("1RB---_1LA---").unwrap();
+/// tm.load("1101");
+/// tm.head = 2;
+/// assert_eq!(tape_numbers(&tm), (0b11, 0b10));
+/// assert_eq!(show_numbers(0b11, 0b10), "11[0]1");
+/// ```
+pub fn tape_numbers(tm: &TuringMachine) -> (u64, u64) {
+    let bit = |i: usize| u64::from(tm.tape.get(i) == Some(&'1'));
+    let left = (0..tm.head.min(64)).fold(0, |n, d| n | bit(tm.head - 1 - d) << d);
+    let right = (0..64).fold(0, |n, d| n | bit(tm.head + d) << d);
+    (left, right)
+}
+
+/// The tape of [`tape_numbers`] with the head cell bracketed, e.g. `01[1]0`.
+pub fn show_numbers(left: u64, right: u64) -> String {
+    let bits = |n: u64| (0..64 - n.leading_zeros()).map(move |d| if n >> d & 1 == 1 { '1' } else { '0' });
+    let mut right: Vec<char> = bits(right).collect();
+    if right.is_empty() { right.push('0'); }
+    let left: String = bits(left).collect::<Vec<_>>().into_iter().rev().collect();
+    format!("{left}[{}]{}", right[0], right[1..].iter().collect::<String>())
 }
@@ -0,0 +1,268 @@
+//! **Random Boolean networks** (Kauffman’s NK model).
+//!
+//! `N` nodes, each reading `K` distinct random other nodes through a random
+//! truth table whose entries are `1` with probability `bias`.  The network
+//! is not spatial: the automaton’s grid shows it as a space‑time diagram –
+//! row 0 holds the current state of nodes `0‥N`, older states scroll down.
+//!
+//! Analysis (synchronous dynamics):
+//!
+//! * [`BooleanNetwork::attractors`] – exhaustive walk of all `2ᴺ` states
+//!   (small `N` only) giving every attractor and its basin size;
+//! * [`BooleanNetwork::derrida`]    – Derrida plot, the one‑step image of
+//!   the normalised Hamming distance between two random states.  A slope
+//!   above 1 at the origin means the chaotic regime, below 1 the ordered
+//!   one; the annealed prediction is `2K·bias·(1 − bias)`.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use engine_core::{core::cell::CellState, engine::grid::DenseGrid, events::AutomatonId};
+use rand::{
+    seq::{IndexedRandom, SliceRandom},
+    Rng,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod plugin;
+
+pub const RBN_ID: &str = "rbn";
+
+/// Largest `N` [`BooleanNetwork::attractors`] will enumerate; `2^16`
+/// states still walk within a frame when the UI asks.
+pub const MAX_ENUMERABLE: usize = 16;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateScheme {
+    /// Every node reads the previous state.
+    #[default]
+    Synchronous,
+    /// Nodes update one after another in a fresh random order each tick.
+    Asynchronous,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RbnParams {
+    pub n:      usize,
+    pub k:      usize,
+    /// Probability that a truth‑table entry is `1`.
+    pub bias:   f64,
+    pub update: UpdateScheme,
+}
+
+impl Default for RbnParams {
+    fn default() -> Self {
+        Self { n: 16, k: 2, bias: 0.5, update: UpdateScheme::Synchronous }
+    }
+}
+
+impl RbnParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+}
+
+/// One attractor cycle of the synchronous dynamics.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attractor {
+    /// States on the cycle (bit `i` = node `i`), in visiting order.
+    pub cycle: Vec<u64>,
+    /// Number of states (cycle included) that end on this attractor.
+    pub basin: usize,
+}
+
+impl Attractor {
+    pub fn period(&self) -> usize { self.cycle.len() }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BooleanNetwork {
+    /// `inputs[i]` – the nodes read by node `i`, most significant first.
+    inputs: Vec<Vec<usize>>,
+    /// `tables[i][pattern]` – output of node `i` for an input pattern.
+    tables: Vec<Vec<bool>>,
+}
+
+impl BooleanNetwork {
+    /// Random NK network; `k` is clamped to `0‥n − 1`.
+    pub fn random(n: usize, k: usize, bias: f64, rng: &mut impl Rng) -> Self {
+        let k = k.min(n.saturating_sub(1));
+        let inputs = (0..n)
+            .map(|i| {
+                let others: Vec<usize> = (0..n).filter(|&j| j != i).collect();
+                others.choose_multiple(rng, k).copied().collect()
+            })
+            .collect();
+        let tables = (0..n)
+            .map(|_| (0..1usize << k).map(|_| rng.random_bool(bias.clamp(0.0, 1.0))).collect())
+            .collect();
+        Self { inputs, tables }
+    }
+
+    /// Network from explicit wiring and truth tables (`tables[i]` must have
+    /// `2^inputs[i].len()` entries).
+    pub fn from_parts(inputs: Vec<Vec<usize>>, tables: Vec<Vec<bool>>) -> Self {
+        Self { inputs, tables }
+    }
+
+    pub fn len(&self) -> usize { self.inputs.len() }
+    pub fn is_empty(&self) -> bool { self.inputs.is_empty() }
+    pub fn inputs(&self, node: usize) -> &[usize] { &self.inputs[node] }
+
+    fn output(&self, node: usize, state: &[bool]) -> bool {
+        let pattern = self.inputs[node].iter().fold(0usize, |acc, &j| (acc << 1) | state[j] as usize);
+        self.tables[node][pattern]
+    }
+
+    pub fn step_sync(&self, state: &[bool]) -> Vec<bool> {
+        (0..self.len()).map(|i| self.output(i, state)).collect()
+    }
+
+    /// Random‑order sequential update in place.
+    pub fn step_async(&self, state: &mut [bool], rng: &mut impl Rng) {
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        order.shuffle(rng);
+        for i in order {
+            state[i] = self.output(i, state);
+        }
+    }
+
+    pub fn step(&self, state: &mut Vec<bool>, scheme: UpdateScheme, rng: &mut impl Rng) {
+        match scheme {
+            UpdateScheme::Synchronous  => *state = self.step_sync(state),
+            UpdateScheme::Asynchronous => self.step_async(state, rng),
+        }
+    }
+
+    /* ── state encoding ───────────────────────────────────────────────── */
+
+    pub fn encode(state: &[bool]) -> u64 {
+        state.iter().enumerate().fold(0, |acc, (i, &b)| acc | ((b as u64) << i))
+    }
+
+    pub fn decode(&self, code: u64) -> Vec<bool> {
+        (0..self.len()).map(|i| code >> i & 1 == 1).collect()
+    }
+
+    /* ── analysis ─────────────────────────────────────────────────────── */
+
+    /// All attractors of the synchronous map with their basin sizes,
+    /// largest basin first; `None` if `N > MAX_ENUMERABLE`.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::dynamical::boolean_network::BooleanNetwork;
+    ///
+    /// // two nodes copying each other: fixed points 00, 11 and the 2‑cycle 01 ↔ 10
+    /// let net = BooleanNetwork::from_parts(vec![vec![1], vec![0]], vec![vec![false, true]; 2]);
+    /// let att = net.attractors().unwrap();
+    /// assert_eq!(att.len(), 3);
+    /// assert_eq!(att.iter().map(|a| a.basin).sum::<usize>(), 4);
+    /// assert_eq!(att.iter().map(|a| a.period()).max(), Some(2));
+    /// ```
+    pub fn attractors(&self) -> Option<Vec<Attractor>> {
+        let n = self.len();
+        if n > MAX_ENUMERABLE { return None; }
+        let states = 1usize << n;
+        let next: Vec<u32> = (0..states as u64)
+            .map(|s| Self::encode(&self.step_sync(&self.decode(s))) as u32)
+            .collect();
+
+        // label: UNSEEN, ON_PATH, or the attractor index
+        const UNSEEN:  u32 = u32::MAX;
+        const ON_PATH: u32 = u32::MAX - 1;
+        let mut label = vec![UNSEEN; states];
+        let mut found: Vec<Attractor> = Vec::new();
+        let mut path  = Vec::new();
+
+        for start in 0..states {
+            if label[start] != UNSEEN { continue; }
+            let mut s = start;
+            while label[s] == UNSEEN {
+                label[s] = ON_PATH;
+                path.push(s);
+                s = next[s] as usize;
+            }
+            let id = if label[s] == ON_PATH {
+                // closed a new cycle at `s`
+                let at    = path.iter().position(|&p| p == s).unwrap_or(0);
+                let cycle = path[at..].iter().map(|&p| p as u64).collect();
+                found.push(Attractor { cycle, basin: 0 });
+                (found.len() - 1) as u32
+            } else {
+                label[s]
+            };
+            found[id as usize].basin += path.len();
+            for p in path.drain(..) {
+                label[p] = id;
+            }
+        }
+
+        found.sort_by_key(|a| std::cmp::Reverse(a.basin));
+        Some(found)
+    }
+
+    /// Derrida plot: for `d = 1‥N` the mean normalised distance after one
+    /// synchronous step between `samples` random pairs at distance `d`.
+    ///
+    /// ```
+    /// use computational_intelligence::automata::dynamical::boolean_network::BooleanNetwork;
+    ///
+    /// let mut rng = rand::rng();
+    /// // K = 0: every node is constant, all differences vanish at once
+    /// let frozen = BooleanNetwork::random(32, 0, 0.5, &mut rng);
+    /// assert!(frozen.derrida(8, &mut rng).iter().all(|&(_, y)| y == 0.0));
+    /// ```
+    pub fn derrida(&self, samples: usize, rng: &mut impl Rng) -> Vec<(f64, f64)> {
+        let n = self.len();
+        (1..=n)
+            .map(|d| {
+                let mut total = 0usize;
+                for _ in 0..samples.max(1) {
+                    let a: Vec<bool> = (0..n).map(|_| rng.random_bool(0.5)).collect();
+                    let mut b = a.clone();
+                    for i in rand::seq::index::sample(rng, n, d) {
+                        b[i] = !b[i];
+                    }
+                    let (a, b) = (self.step_sync(&a), self.step_sync(&b));
+                    total += a.iter().zip(&b).filter(|(x, y)| x != y).count();
+                }
+                (d as f64 / n as f64, total as f64 / (samples.max(1) * n) as f64)
+            })
+            .collect()
+    }
+}
+
+/// A live network and its current state.
+#[derive(Clone, Debug)]
+pub struct NetworkRun {
+    pub network: BooleanNetwork,
+    pub state:   Vec<bool>,
+}
+
+/// Networks of every live `rbn` automaton.
+#[derive(Resource, Default)]
+pub struct BooleanNetworks(pub HashMap<AutomatonId, NetworkRun>);
+
+/// Scrolls the space‑time diagram down by one row and writes `state` on top.
+pub fn write_row(grid: &mut DenseGrid, state: &[bool]) {
+    let w = grid.size.x as usize;
+    for y in (1..grid.size.y as usize).rev() {
+        let (above, below) = grid.cells.split_at_mut(y * w);
+        below[..w].clone_from_slice(&above[(y - 1) * w..y * w]);
+    }
+    for (x, cell) in grid.cells.iter_mut().take(w).enumerate() {
+        cell.state = match state.get(x) {
+            Some(true)  => CellState::Alive(1),
+            Some(false) => CellState::Alive(2),
+            None        => CellState::Dead,
+        };
+    }
+}
+
+/// On nodes bright, off nodes dim.
+pub fn palette() -> Vec<Color> {
+    vec![Color::srgb(1.0, 0.85, 0.30), Color::srgb(0.15, 0.18, 0.30)]
+}
@@ -0,0 +1,104 @@
+//! Registers the NK network automaton, builds a network per spawned
+//! automaton, steps it and samples its activity.
+
+use bevy::prelude::*;
+use engine_core::{
+    engine::grid::GridBackend,
+    events::{AutomatonAdded, AutomatonRemoved},
+    schedule::MainSet,
+    state::AppState,
+};
+use rand::Rng;
+
+use crate::{
+    agents::HabitatRule,
+    analytics::time_series::TimeSeries,
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{palette, write_row, BooleanNetwork, BooleanNetworks, NetworkRun, RbnParams, RBN_ID};
+
+pub struct BooleanNetworkPlugin;
+impl Plugin for BooleanNetworkPlugin {
+    fn build(&self, app: &mut App) {
+        // Wiring is random and non‑local: the network lives in
+        // `BooleanNetworks`, the grid only shows its history.
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        reg.register(RBN_ID, HabitatRule::boxed());
+        reg.register_params(RBN_ID, &RbnParams::default());
+        reg.register_palette(RBN_ID, palette());
+        app.insert_resource(reg);
+
+        app.init_resource::<BooleanNetworks>()
+           .add_systems(Update, (build_networks, drop_networks))
+           .add_systems(
+               Update,
+               step_networks
+                   .in_set(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           )
+           .add_systems(
+               Update,
+               record_network_series
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+fn build_networks(
+    mut added:    EventReader<AutomatonAdded>,
+    mut registry: ResMut<AutomataRegistry>,
+    mut networks: ResMut<BooleanNetworks>,
+) {
+    let mut rng = rand::rng();
+    for ev in added.read() {
+        let Some(info) = registry.get_mut(ev.id) else { continue };
+        if info.name != RBN_ID { continue; }
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        let p       = RbnParams::from_value(&info.params);
+        let network = BooleanNetwork::random(p.n, p.k, p.bias, &mut rng);
+        let state   = (0..network.len()).map(|_| rng.random_bool(0.5)).collect::<Vec<_>>();
+        write_row(grid, &state);
+        networks.0.insert(ev.id, NetworkRun { network, state });
+    }
+}
+
+fn drop_networks(mut removed: EventReader<AutomatonRemoved>, mut networks: ResMut<BooleanNetworks>) {
+    for ev in removed.read() {
+        networks.0.remove(&ev.id);
+    }
+}
+
+fn step_networks(mut registry: ResMut<AutomataRegistry>, mut networks: ResMut<BooleanNetworks>) {
+    let mut rng = rand::rng();
+    for (id, run) in networks.0.iter_mut() {
+        let Some(info) = registry.get_mut(*id) else { continue };
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        let p = RbnParams::from_value(&info.params);
+        run.network.step(&mut run.state, p.update, &mut rng);
+        write_row(grid, &run.state);
+    }
+}
+
+/// Per‑tick `activity` (fraction of nodes on) and `flips` (fraction that
+/// changed since the previous tick, read off the space‑time diagram).
+fn record_network_series(
+    registry:   Res<AutomataRegistry>,
+    networks:   Res<BooleanNetworks>,
+    mut series: ResMut<TimeSeries>,
+) {
+    for (id, run) in &networks.0 {
+        let Some(info) = registry.get(*id) else { continue };
+        let GridBackend::Dense(grid) = &info.grid else { continue };
+        let n = run.state.len().max(1) as f64;
+        let w = grid.size.x as usize;
+        let on = run.state.iter().filter(|b| **b).count();
+        series.record(*id, "activity", on as f64 / n);
+        if grid.size.y > 1 {
+            let flips = (0..run.state.len().min(w))
+                .filter(|&x| grid.cells[x].state != grid.cells[w + x].state)
+                .count();
+            series.record(*id, "flips", flips as f64 / n);
+        }
+    }
+}
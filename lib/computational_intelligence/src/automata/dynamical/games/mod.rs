@@ -0,0 +1,332 @@
+//! **Spatial evolutionary games** – Nowak & May’s lattice prisoner’s
+//! dilemma (Nature 359, 1992) generalised to several strategies.
+//!
+//! Every tick each site plays one round against each neighbour (and,
+//! optionally, itself), collects the payoffs of the [`Payoff`] matrix and
+//! then updates its strategy:
+//!
+//! * [`UpdateRule::Best`]  – copy the best‑scoring site of the neighbourhood
+//!   (deterministic, the original Nowak–May dynamics);
+//! * [`UpdateRule::Fermi`] – pick a random neighbour `j` and copy it with
+//!   probability `1 / (1 + exp((Pᵢ − Pⱼ) / K))`.
+//!
+//! Memory strategies ([`Strategy::TitForTat`], [`Strategy::Pavlov`]) see
+//! what each neighbour did to them in the previous round; those per‑pair
+//! histories live in the `"played"` / `"received"` cell layers as bit masks
+//! (bit `k` = defected against neighbour `k`), the last payoff in
+//! `"payoff"`.  Cells show strategy `s` as `Alive(s + 1)` and, for one tick
+//! after a switch, as `Alive(s + 1 + STRATEGIES)` – with the default palette
+//! that is the classic blue / red / yellow / green Nowak–May picture.
+
+use bevy::prelude::*;
+use engine_core::{
+    core::cell::CellState,
+    engine::grid::{CellLayers, DenseGrid},
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod plugin;
+
+/// Random initial mix.
+pub const GAMES_PD_ID: &str = "games:pd";
+/// One defector in a sea of cooperators – symmetric “kaleidoscopes”.
+pub const GAMES_KALEIDOSCOPE_ID: &str = "games:pd:kaleidoscope";
+
+pub const PLAYED:   &str = "played";
+pub const RECEIVED: &str = "received";
+pub const PAYOFF:   &str = "payoff";
+
+/// Moore offsets ordered so that `MOORE[7 − k] == −MOORE[k]`.
+const MOORE: [IVec2; 8] = [
+    IVec2::new(-1, -1), IVec2::new(0, -1), IVec2::new(1, -1),
+    IVec2::new(-1,  0),                    IVec2::new(1,  0),
+    IVec2::new(-1,  1), IVec2::new(0,  1), IVec2::new(1,  1),
+];
+/// Indices of the von‑Neumann subset of [`MOORE`].
+const VON_NEUMANN: [usize; 4] = [1, 3, 4, 6];
+
+/* ───────────────────────────── strategies ──────────────────────────── */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Strategy {
+    /// Always cooperate.
+    #[serde(alias = "allc")]
+    AllC,
+    /// Always defect.
+    #[serde(alias = "alld")]
+    AllD,
+    /// Cooperate first, then repeat the opponent’s last move.
+    #[serde(alias = "tft")]
+    TitForTat,
+    /// Win‑stay lose‑shift: cooperate iff both players made the same move.
+    Pavlov,
+}
+
+/// Number of [`Strategy`] variants (cell states per “changed” flag).
+pub const STRATEGIES: u8 = 4;
+
+impl Strategy {
+    pub const ALL: [Strategy; STRATEGIES as usize] =
+        [Strategy::AllC, Strategy::AllD, Strategy::TitForTat, Strategy::Pavlov];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Strategy::AllC      => "allc",
+            Strategy::AllD      => "alld",
+            Strategy::TitForTat => "tft",
+            Strategy::Pavlov    => "pavlov",
+        }
+    }
+
+    fn index(self) -> u8 {
+        Self::ALL.iter().position(|s| *s == self).unwrap_or(0) as u8
+    }
+
+    /// `true` = defect, given my and the opponent’s previous move.
+    pub fn defects(self, i_defected: bool, they_defected: bool) -> bool {
+        match self {
+            Strategy::AllC      => false,
+            Strategy::AllD      => true,
+            Strategy::TitForTat => they_defected,
+            Strategy::Pavlov    => i_defected != they_defected,
+        }
+    }
+
+    pub fn to_state(self, changed: bool) -> CellState {
+        CellState::Alive(1 + self.index() + if changed { STRATEGIES } else { 0 })
+    }
+
+    pub fn from_state(s: CellState) -> Option<Strategy> {
+        match s {
+            CellState::Dead     => None,
+            CellState::Alive(v) => Some(Self::ALL[((v.max(1) - 1) % STRATEGIES) as usize]),
+        }
+    }
+}
+
+/// Colours for [`Strategy::to_state`]: steady strategies first, then the
+/// ones that just switched.
+pub fn palette() -> Vec<Color> {
+    vec![
+        Color::srgb(0.15, 0.35, 0.95), // AllC
+        Color::srgb(0.90, 0.15, 0.15), // AllD
+        Color::srgb(0.20, 0.75, 0.75), // TFT
+        Color::srgb(0.65, 0.30, 0.85), // Pavlov
+        Color::srgb(0.20, 0.85, 0.25), // → AllC
+        Color::srgb(0.95, 0.90, 0.15), // → AllD
+        Color::srgb(0.60, 0.95, 0.95), // → TFT
+        Color::srgb(0.90, 0.70, 1.00), // → Pavlov
+    ]
+}
+
+/* ───────────────────────────── parameters ──────────────────────────── */
+
+/// Row player’s payoff: `R` mutual cooperation, `S` sucker, `T`
+/// temptation, `P` mutual defection.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Payoff {
+    pub reward:      f32,
+    pub sucker:      f32,
+    pub temptation:  f32,
+    pub punishment:  f32,
+}
+
+impl Default for Payoff {
+    /// Nowak–May’s one‑parameter weak dilemma with `b = 1.85`.
+    fn default() -> Self {
+        Self { reward: 1.0, sucker: 0.0, temptation: 1.85, punishment: 0.0 }
+    }
+}
+
+impl Payoff {
+    pub fn score(&self, i_defect: bool, they_defect: bool) -> f32 {
+        match (i_defect, they_defect) {
+            (false, false) => self.reward,
+            (false, true)  => self.sucker,
+            (true,  false) => self.temptation,
+            (true,  true)  => self.punishment,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateRule {
+    #[default]
+    Best,
+    Fermi,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Neighbourhood {
+    #[default]
+    Moore,
+    VonNeumann,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GameSeed {
+    /// Independent draws from `strategies` with weights `mix`.
+    #[default]
+    Random,
+    /// `strategies[0]` everywhere, a single `strategies[1]` in the centre.
+    Kaleidoscope,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameParams {
+    pub payoff:           Payoff,
+    pub strategies:       Vec<Strategy>,
+    /// Initial weights of `strategies` (missing entries count as 0).
+    pub mix:              Vec<f64>,
+    pub seed:             GameSeed,
+    pub update:           UpdateRule,
+    /// Fermi temperature `K`.
+    pub noise:            f32,
+    pub neighbourhood:    Neighbourhood,
+    pub self_interaction: bool,
+}
+
+impl Default for GameParams {
+    fn default() -> Self {
+        Self {
+            payoff:           Payoff::default(),
+            strategies:       vec![Strategy::AllC, Strategy::AllD],
+            mix:              vec![0.9, 0.1],
+            seed:             GameSeed::Random,
+            update:           UpdateRule::Best,
+            noise:            0.1,
+            neighbourhood:    Neighbourhood::Moore,
+            self_interaction: true,
+        }
+    }
+}
+
+impl GameParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+
+    /// Defaults of the kaleidoscope preset.
+    pub fn kaleidoscope() -> Self {
+        Self { seed: GameSeed::Kaleidoscope, ..Self::default() }
+    }
+
+    fn neighbours(&self) -> &'static [usize] {
+        const ALL: [usize; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+        match self.neighbourhood {
+            Neighbourhood::Moore      => &ALL,
+            Neighbourhood::VonNeumann => &VON_NEUMANN,
+        }
+    }
+}
+
+/* ───────────────────────────── dynamics ────────────────────────────── */
+
+fn wrap(p: IVec2, size: UVec2) -> usize {
+    let s = size.as_ivec2();
+    (p.y.rem_euclid(s.y) * s.x + p.x.rem_euclid(s.x)) as usize
+}
+
+/// Writes the initial strategies into `grid` and adds the history layers.
+pub fn seed_game(grid: &mut DenseGrid, layers: &mut CellLayers, p: &GameParams, rng: &mut impl Rng) {
+    for name in [PLAYED, RECEIVED, PAYOFF] {
+        layers.add(name, 0.0);
+    }
+    let first  = p.strategies.first().copied().unwrap_or(Strategy::AllC);
+    let second = p.strategies.get(1).copied().unwrap_or(Strategy::AllD);
+    match p.seed {
+        GameSeed::Random => {
+            let total: f64 = p.mix.iter().take(p.strategies.len()).sum();
+            for cell in &mut grid.cells {
+                let mut r = rng.random::<f64>() * total;
+                let pick = p.strategies.iter().zip(&p.mix)
+                    .find(|(_, w)| { r -= **w; r < 0.0 })
+                    .map_or(first, |(s, _)| *s);
+                cell.state = pick.to_state(false);
+            }
+        }
+        GameSeed::Kaleidoscope => {
+            for cell in &mut grid.cells {
+                cell.state = first.to_state(false);
+            }
+            let centre = wrap(grid.size.as_ivec2() / 2, grid.size);
+            grid.cells[centre].state = second.to_state(false);
+        }
+    }
+}
+
+/// One round: play every neighbour, score, imitate.
+pub fn step_game(grid: &mut DenseGrid, layers: &mut CellLayers, p: &GameParams, rng: &mut impl Rng) {
+    let size  = grid.size;
+    let n     = grid.cells.len();
+    let nbrs  = p.neighbours();
+    let strat: Vec<Strategy> = grid.cells.iter()
+        .map(|c| Strategy::from_state(c.state).unwrap_or(Strategy::AllC))
+        .collect();
+    let history = |name: &str| -> Vec<u8> {
+        layers.plane(name).map_or_else(|| vec![0; n], |v| v.iter().map(|b| *b as u8).collect())
+    };
+    let (played, received) = (history(PLAYED), history(RECEIVED));
+    let pos = |i: usize| IVec2::new((i as u32 % size.x) as i32, (i as u32 / size.x) as i32);
+
+    // ── play ───────────────────────────────────────────────────────────
+    // moves[i] bit k: site i defects against neighbour k this round.
+    let moves: Vec<u8> = (0..n)
+        .map(|i| nbrs.iter().fold(0u8, |m, &k| {
+            let d = strat[i].defects(played[i] >> k & 1 == 1, received[i] >> k & 1 == 1);
+            m | (d as u8) << k
+        }))
+        .collect();
+    let mut payoff   = vec![0.0f32; n];
+    let mut incoming = vec![0u8; n];
+    for i in 0..n {
+        for &k in nbrs {
+            let j  = wrap(pos(i) + MOORE[k], size);
+            let me = moves[i] >> k & 1 == 1;
+            let it = moves[j] >> (7 - k) & 1 == 1;
+            payoff[i] += p.payoff.score(me, it);
+            incoming[i] |= (it as u8) << k;
+        }
+        if p.self_interaction {
+            let d = strat[i].defects(false, false);
+            payoff[i] += p.payoff.score(d, d);
+        }
+    }
+
+    // ── imitate ────────────────────────────────────────────────────────
+    let next: Vec<Strategy> = (0..n)
+        .map(|i| match p.update {
+            UpdateRule::Best => nbrs.iter()
+                .map(|&k| wrap(pos(i) + MOORE[k], size))
+                .fold(i, |best, j| if payoff[j] > payoff[best] { j } else { best }),
+            UpdateRule::Fermi => {
+                let j = wrap(pos(i) + MOORE[nbrs[rng.random_range(0..nbrs.len())]], size);
+                let k = p.noise.max(1e-6);
+                let adopt = 1.0 / (1.0 + ((payoff[i] - payoff[j]) / k).exp());
+                if rng.random::<f32>() < adopt { j } else { i }
+            }
+        })
+        .map(|src| strat[src])
+        .collect();
+
+    // ── write back ─────────────────────────────────────────────────────
+    for (i, cell) in grid.cells.iter_mut().enumerate() {
+        cell.state = next[i].to_state(next[i] != strat[i]);
+    }
+    let store = |layers: &mut CellLayers, name: &str, v: &mut dyn Iterator<Item = f32>| {
+        if let Some(plane) = layers.plane_mut(name) {
+            plane.iter_mut().zip(v).for_each(|(dst, src)| *dst = src);
+        }
+    };
+    store(layers, PLAYED, &mut moves.iter().map(|m| *m as f32));
+    store(layers, RECEIVED, &mut incoming.iter().map(|m| *m as f32));
+    store(layers, PAYOFF, &mut payoff.iter().copied());
+}
@@ -0,0 +1,94 @@
+//! Registers the spatial prisoner’s dilemma presets, seeds and steps them,
+//! samples strategy frequencies.
+
+use bevy::prelude::*;
+use engine_core::{
+    engine::grid::GridBackend, events::AutomatonAdded, schedule::MainSet, state::AppState,
+};
+
+use crate::{
+    agents::HabitatRule,
+    analytics::time_series::TimeSeries,
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{
+    palette, seed_game, step_game, GameParams, Strategy, GAMES_KALEIDOSCOPE_ID, GAMES_PD_ID,
+    PAYOFF,
+};
+
+fn is_game(name: &str) -> bool {
+    name == GAMES_PD_ID || name == GAMES_KALEIDOSCOPE_ID
+}
+
+pub struct SpatialGamesPlugin;
+impl Plugin for SpatialGamesPlugin {
+    fn build(&self, app: &mut App) {
+        // Imitation needs the neighbours’ *payoffs*, i.e. radius‑2 information:
+        // the lattice is stepped as a whole below.
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        for (id, defaults) in [
+            (GAMES_PD_ID, GameParams::default()),
+            (GAMES_KALEIDOSCOPE_ID, GameParams::kaleidoscope()),
+        ] {
+            reg.register(id, HabitatRule::boxed());
+            reg.register_params(id, &defaults);
+            reg.register_palette(id, palette());
+        }
+        app.insert_resource(reg);
+
+        app.add_systems(Update, seed_games)
+           .add_systems(
+               Update,
+               step_games
+                   .in_set(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           )
+           .add_systems(
+               Update,
+               record_game_series
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+fn seed_games(mut added: EventReader<AutomatonAdded>, mut registry: ResMut<AutomataRegistry>) {
+    let mut rng = rand::rng();
+    for ev in added.read() {
+        let Some(info) = registry.get_mut(ev.id) else { continue };
+        if !is_game(&info.name) { continue; }
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        seed_game(grid, &mut info.layers, &GameParams::from_value(&info.params), &mut rng);
+    }
+}
+
+fn step_games(mut registry: ResMut<AutomataRegistry>) {
+    let mut rng = rand::rng();
+    for info in registry.iter_mut().filter(|i| is_game(&i.name)) {
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        step_game(grid, &mut info.layers, &GameParams::from_value(&info.params), &mut rng);
+    }
+}
+
+/// Per‑tick `freq_<strategy>` for every strategy of the game and the
+/// `mean_payoff` of the last round.
+fn record_game_series(registry: Res<AutomataRegistry>, mut series: ResMut<TimeSeries>) {
+    for info in registry.list().iter().filter(|i| is_game(&i.name)) {
+        let GridBackend::Dense(grid) = &info.grid else { continue };
+        let n = grid.cells.len().max(1) as f64;
+        let mut counts = [0usize; Strategy::ALL.len()];
+        for s in grid.cells.iter().filter_map(|c| Strategy::from_state(c.state)) {
+            counts[Strategy::ALL.iter().position(|x| *x == s).unwrap_or(0)] += 1;
+        }
+
+        let p = GameParams::from_value(&info.params);
+        for (s, c) in Strategy::ALL.iter().zip(counts) {
+            if p.strategies.contains(s) || c > 0 {
+                series.record(info.id, &format!("freq_{}", s.name()), c as f64 / n);
+            }
+        }
+        if let Some(payoff) = info.layers.plane(PAYOFF) {
+            series.record(info.id, "mean_payoff", payoff.iter().map(|v| *v as f64).sum::<f64>() / n);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Fruchterman–Reingold force‑directed layout in the unit square.
+//!
+//! Every pair of nodes repels with `k²/d`, every edge attracts with `d²/k`
+//! (`k = √(1/N)`); moves are capped by a temperature that cools linearly.
+//! `O(N²)` per iteration – fine for the few hundred nodes a graph automaton
+//! is meant to show.
+
+use bevy::math::Vec2;
+use engine_core::engine::grid::GraphGrid;
+use rand::Rng;
+
+const MARGIN: f32 = 0.03;
+
+/// Runs `iterations` rounds starting from jittered current positions.
+pub fn force_directed(graph: &mut GraphGrid, iterations: usize, rng: &mut impl Rng) {
+    let n = graph.len();
+    if n < 2 || iterations == 0 { return; }
+
+    let k = (1.0 / n as f32).sqrt();
+    // a little noise breaks the symmetry of the initial circle
+    for p in graph.positions.iter_mut() {
+        *p += Vec2::new(rng.random_range(-0.01..0.01), rng.random_range(-0.01..0.01));
+    }
+
+    let mut shift = vec![Vec2::ZERO; n];
+    for it in 0..iterations {
+        let temperature = 0.1 * (1.0 - it as f32 / iterations as f32) + 0.002;
+        shift.fill(Vec2::ZERO);
+
+        let pos = &graph.positions;
+        for i in 0..n {
+            for j in i + 1..n {
+                let d    = pos[i] - pos[j];
+                let dist = d.length().max(1e-4);
+                let push = d / dist * (k * k / dist);
+                shift[i] += push;
+                shift[j] -= push;
+            }
+        }
+        for (a, b) in graph.edges() {
+            let (a, b) = (a as usize, b as usize);
+            let d    = pos[a] - pos[b];
+            let dist = d.length().max(1e-4);
+            let pull = d / dist * (dist * dist / k);
+            shift[a] -= pull;
+            shift[b] += pull;
+        }
+
+        for (p, s) in graph.positions.iter_mut().zip(&shift) {
+            let len = s.length();
+            if len > 0.0 {
+                *p += *s / len * len.min(temperature);
+            }
+            *p = p.clamp(Vec2::splat(MARGIN), Vec2::splat(1.0 - MARGIN));
+        }
+    }
+}
@@ -0,0 +1,361 @@
+//! Cellular automata on **arbitrary graphs** – the same rules on a square
+//! lattice, a Watts–Strogatz small world, a Barabási–Albert scale‑free
+//! network, an Erdős–Rényi random graph or a loaded edge list.
+//!
+//! Graph automata live on a [`GraphGrid`]: the rule’s neighbourhood is the
+//! node’s adjacency list, so rules must not assume 8 neighbours.  The two
+//! rules here only count:
+//!
+//! * [`LifeLikeRule`] – outer‑totalistic `B…/S…` rules (Conway = `B3/S23`);
+//!   a count above 8 never births or survives;
+//! * [`VoterRule`]    – every node copies a random live neighbour.
+
+use std::{collections::BTreeSet, sync::Arc};
+
+use bevy::prelude::*;
+use engine_core::{
+    core::{AutomatonRule, CellCtx, CellOutcome, CellState, Dim, Dim2},
+    engine::grid::GraphGrid,
+};
+use rand::{seq::IndexedRandom, Rng};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod layout;
+pub mod plugin;
+
+pub const GRAPH_LIFE_ID:  &str = "graph:life";
+pub const GRAPH_VOTER_ID: &str = "graph:voter";
+
+/* ─────────────────────────────── rules ─────────────────────────────── */
+
+/// Outer‑totalistic rule read from `params.rule` (`"B3/S23"` by default).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LifeLikeRule;
+
+impl LifeLikeRule {
+    pub fn boxed() -> Arc<dyn AutomatonRule<D = Dim2> + Send + Sync> { Arc::new(Self) }
+
+    /// `(birth, survive)` bit masks of a `B…/S…` string (bit `n` = count `n`).
+    ///
+    /// ```
+    /// use computational_intelligence::automata::dynamical::graph::LifeLikeRule;
+    ///
+    /// assert_eq!(LifeLikeRule::parse("B3/S23"), Some((1 << 3, 1 << 2 | 1 << 3)));
+    /// assert_eq!(LifeLikeRule::parse("b36/s23"), Some((1 << 3 | 1 << 6, 1 << 2 | 1 << 3)));
+    /// assert_eq!(LifeLikeRule::parse("23/3"), None);
+    /// ```
+    pub fn parse(rule: &str) -> Option<(u16, u16)> {
+        let (b, s) = rule.trim().split_once('/')?;
+        let mask = |part: &str, tag: char| -> Option<u16> {
+            let digits = part.strip_prefix(tag).or_else(|| part.strip_prefix(tag.to_ascii_lowercase()))?;
+            digits.chars().try_fold(0u16, |m, c| Some(m | 1 << c.to_digit(10).filter(|d| *d <= 8)?))
+        };
+        Some((mask(b, 'B')?, mask(s, 'S')?))
+    }
+
+    fn masks(params: &Value) -> (u16, u16) {
+        params.get("rule")
+            .and_then(Value::as_str)
+            .and_then(Self::parse)
+            .unwrap_or((1 << 3, 1 << 2 | 1 << 3))
+    }
+}
+
+impl AutomatonRule for LifeLikeRule {
+    type D = Dim2;
+
+    fn next_state(&self, ctx: CellCtx<Self::D>, params: &Value) -> CellOutcome {
+        let (birth, survive) = Self::masks(params);
+        let alive = ctx.neighbourhood.iter().filter(|s| **s != CellState::Dead).count();
+        let hit   = |mask: u16| alive <= 8 && mask >> alive & 1 == 1;
+        let next  = match ctx.self_state {
+            CellState::Dead     if hit(birth)   => CellState::Alive(1),
+            CellState::Alive(_) if hit(survive) => CellState::Alive(1),
+            _ => CellState::Dead,
+        };
+        if next == ctx.self_state { return CellOutcome::Unchanged; }
+        CellOutcome::Next { state: next, memory: ctx.memory.clone() }
+    }
+}
+
+/// Voter model: adopt the state of a uniformly random live neighbour.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct VoterRule;
+
+impl VoterRule {
+    pub fn boxed() -> Arc<dyn AutomatonRule<D = Dim2> + Send + Sync> { Arc::new(Self) }
+}
+
+impl AutomatonRule for VoterRule {
+    type D = Dim2;
+
+    fn next_state(&self, ctx: CellCtx<Self::D>, _params: &Value) -> CellOutcome {
+        let live: Vec<CellState> = ctx.neighbourhood.iter().copied().filter(|s| *s != CellState::Dead).collect();
+        match live.choose(&mut rand::rng()) {
+            Some(&s) if s != ctx.self_state => CellOutcome::Next { state: s, memory: ctx.memory.clone() },
+            _ => CellOutcome::Unchanged,
+        }
+    }
+}
+
+/* ───────────────────────────── parameters ──────────────────────────── */
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Topology {
+    /// Square torus with Moore‑8 neighbours (the reference topology).
+    Lattice,
+    /// Ring lattice of even degree, each edge rewired with prob. `rewire`.
+    #[default]
+    WattsStrogatz,
+    /// Preferential attachment, `degree / 2` edges per new node.
+    BarabasiAlbert,
+    /// Every pair linked with probability `degree / (n − 1)`.
+    ErdosRenyi,
+    /// Edge list read from `edge_list`.
+    EdgeList,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GraphParams {
+    pub topology:  Topology,
+    pub nodes:     usize,
+    /// Target mean degree.
+    pub degree:    usize,
+    /// Watts–Strogatz rewiring probability.
+    pub rewire:    f64,
+    /// Path of a whitespace‑separated `u v` edge list.
+    pub edge_list: String,
+    /// Fruchterman–Reingold iterations for the layout.
+    pub layout:    usize,
+    /// Life: initial live fraction.
+    pub density:   f64,
+    /// Voter: number of opinions.
+    pub opinions:  u8,
+    /// Life‑like rule string.
+    pub rule:      String,
+}
+
+impl Default for GraphParams {
+    fn default() -> Self {
+        Self {
+            topology:  Topology::WattsStrogatz,
+            nodes:     400,
+            degree:    8,
+            rewire:    0.1,
+            edge_list: String::new(),
+            layout:    150,
+            density:   0.35,
+            opinions:  2,
+            rule:      "B3/S23".into(),
+        }
+    }
+}
+
+impl GraphParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+}
+
+/* ───────────────────────────── generators ──────────────────────────── */
+
+/// Adjacency lists from a set of undirected edges over `n` nodes.
+fn adjacency(n: usize, edges: &BTreeSet<(u32, u32)>) -> Vec<Vec<u32>> {
+    let mut adj = vec![Vec::new(); n];
+    for &(a, b) in edges {
+        adj[a as usize].push(b);
+        adj[b as usize].push(a);
+    }
+    adj
+}
+
+fn edge(a: usize, b: usize) -> (u32, u32) {
+    (a.min(b) as u32, a.max(b) as u32)
+}
+
+/// `side × side` torus, Moore‑8 neighbours; node `i` sits at `(i % side, i / side)`.
+pub fn lattice(side: usize) -> Vec<Vec<u32>> {
+    let s = side.max(3) as i32;
+    (0..s * s)
+        .map(|i| {
+            let (x, y) = (i % s, i / s);
+            Dim2::NEIGHBOUR_OFFSETS
+                .iter()
+                .map(|o| ((y + o.y).rem_euclid(s) * s + (x + o.x).rem_euclid(s)) as u32)
+                .collect()
+        })
+        .collect()
+}
+
+/// Watts–Strogatz small world: ring of mean degree `k` (rounded down to
+/// even), every edge’s far end rewired with probability `beta`.
+///
+/// ```
+/// use computational_intelligence::automata::dynamical::graph::watts_strogatz;
+///
+/// let ring = watts_strogatz(20, 4, 0.0, &mut rand::rng());
+/// assert!(ring.iter().all(|n| n.len() == 4));
+/// ```
+pub fn watts_strogatz(n: usize, k: usize, beta: f64, rng: &mut impl Rng) -> Vec<Vec<u32>> {
+    let half = (k / 2).min(n.saturating_sub(1) / 2);
+    let mut edges = BTreeSet::new();
+    for i in 0..n {
+        for d in 1..=half {
+            edges.insert(edge(i, (i + d) % n));
+        }
+    }
+    let ring: Vec<(u32, u32)> = edges.iter().copied().collect();
+    for (a, b) in ring {
+        if !rng.random_bool(beta.clamp(0.0, 1.0)) { continue; }
+        // keep `a`, move the other end to a fresh node
+        for _ in 0..16 {
+            let c = rng.random_range(0..n);
+            if c != a as usize && !edges.contains(&edge(a as usize, c)) {
+                edges.remove(&(a, b));
+                edges.insert(edge(a as usize, c));
+                break;
+            }
+        }
+    }
+    adjacency(n, &edges)
+}
+
+/// Barabási–Albert: start from a clique of `m + 1` nodes, every new node
+/// attaches to `m` distinct existing nodes with probability ∝ degree.
+pub fn barabasi_albert(n: usize, m: usize, rng: &mut impl Rng) -> Vec<Vec<u32>> {
+    let m = m.clamp(1, n.saturating_sub(1).max(1));
+    let mut edges = BTreeSet::new();
+    // every edge end once – sampling from it is degree‑proportional
+    let mut ends: Vec<usize> = Vec::new();
+    for a in 0..=m.min(n - 1) {
+        for b in 0..a {
+            edges.insert(edge(a, b));
+            ends.extend([a, b]);
+        }
+    }
+    for v in m + 1..n {
+        let mut targets = BTreeSet::new();
+        while targets.len() < m {
+            targets.insert(*ends.choose(rng).unwrap_or(&0));
+        }
+        for t in targets {
+            edges.insert(edge(v, t));
+            ends.extend([v, t]);
+        }
+    }
+    adjacency(n, &edges)
+}
+
+/// Erdős–Rényi `G(n, p)` with `p = mean_degree / (n − 1)`.
+pub fn erdos_renyi(n: usize, mean_degree: f64, rng: &mut impl Rng) -> Vec<Vec<u32>> {
+    let p = (mean_degree / n.saturating_sub(1).max(1) as f64).clamp(0.0, 1.0);
+    let mut edges = BTreeSet::new();
+    for a in 0..n {
+        for b in a + 1..n {
+            if rng.random_bool(p) {
+                edges.insert(edge(a, b));
+            }
+        }
+    }
+    adjacency(n, &edges)
+}
+
+/// Largest node count an edge list may describe.
+pub const MAX_EDGE_LIST_NODES: u32 = 10_000;
+
+/// Parses an undirected edge list: one `u v` pair per line (whitespace or
+/// comma separated), `#` / `%` comments, node ids `0‥n` below
+/// [`MAX_EDGE_LIST_NODES`].  Self loops and duplicates are dropped.
+///
+/// ```
+/// use computational_intelligence::automata::dynamical::graph::parse_edge_list;
+///
+/// let adj = parse_edge_list("# triangle\n0 1\n1,2\n2 0\n0 1\n").unwrap();
+/// assert_eq!(adj, vec![vec![1, 2], vec![0, 2], vec![0, 1]]);
+/// assert!(parse_edge_list("0 x").is_err());
+/// assert!(parse_edge_list("0 18446744073709551615").is_err());
+/// assert!(parse_edge_list("0 10000").is_err());
+/// ```
+pub fn parse_edge_list(text: &str) -> Result<Vec<Vec<u32>>, String> {
+    let mut edges = BTreeSet::new();
+    let mut n = 0;
+    for (line_no, line) in text.lines().enumerate() {
+        let line = line.split(['#', '%']).next().unwrap_or("").trim();
+        if line.is_empty() { continue; }
+        let mut ids = line.split(|c: char| c.is_whitespace() || c == ',').filter(|s| !s.is_empty());
+        let mut node = || -> Result<u32, String> {
+            let tok = ids.next().ok_or_else(|| format!("line {}: expected two node ids", line_no + 1))?;
+            tok.parse::<u32>()
+                .ok()
+                .filter(|&id| id < MAX_EDGE_LIST_NODES)
+                .ok_or_else(|| format!("line {}: bad node id `{tok}` (at most {})", line_no + 1, MAX_EDGE_LIST_NODES - 1))
+        };
+        let (a, b) = (node()?, node()?);
+        n = n.max(a + 1).max(b + 1);
+        if a != b {
+            edges.insert((a.min(b), a.max(b)));
+        }
+    }
+    Ok(adjacency(n as usize, &edges))
+}
+
+/// Builds the graph described by `p` on a `canvas`‑sized raster and lays it
+/// out.  A missing or malformed edge list falls back to Erdős–Rényi.
+pub fn build_graph(p: &GraphParams, canvas: UVec2, rng: &mut impl Rng) -> GraphGrid {
+    let n = p.nodes.max(2);
+    let adjacency = match p.topology {
+        Topology::Lattice        => lattice((n as f64).sqrt().round() as usize),
+        Topology::WattsStrogatz  => watts_strogatz(n, p.degree, p.rewire, rng),
+        Topology::BarabasiAlbert => barabasi_albert(n, (p.degree / 2).max(1), rng),
+        Topology::ErdosRenyi     => erdos_renyi(n, p.degree as f64, rng),
+        Topology::EdgeList => match std::fs::read_to_string(&p.edge_list)
+            .map_err(|e| e.to_string())
+            .and_then(|text| parse_edge_list(&text))
+        {
+            Ok(adj) if !adj.is_empty() => adj,
+            Ok(_) | Err(_) => {
+                warn!("Could not load edge list {:?}; using an Erdős–Rényi graph.", p.edge_list);
+                erdos_renyi(n, p.degree as f64, rng)
+            }
+        },
+    };
+
+    let mut graph = GraphGrid::new(adjacency, canvas);
+    if p.topology == Topology::Lattice {
+        let side = (graph.len() as f64).sqrt().round().max(1.0) as usize;
+        for (i, pos) in graph.positions.iter_mut().enumerate() {
+            *pos = (Vec2::new((i % side) as f32, (i / side) as f32) + 0.5) / side as f32;
+        }
+    } else {
+        layout::force_directed(&mut graph, p.layout, rng);
+    }
+    graph
+}
+
+/// Fraction of edges whose ends are in different states (voter interface
+/// density, Life activity front).
+pub fn interface_density(graph: &GraphGrid) -> f64 {
+    let (mut cut, mut total) = (0usize, 0usize);
+    for (a, b) in graph.edges() {
+        total += 1;
+        if graph.cells[a as usize].state != graph.cells[b as usize].state {
+            cut += 1;
+        }
+    }
+    if total == 0 { 0.0 } else { cut as f64 / total as f64 }
+}
+
+/// Distinct colours for voter opinions.
+pub fn opinion_palette() -> Vec<Color> {
+    vec![
+        Color::srgb(0.90, 0.30, 0.25),
+        Color::srgb(0.25, 0.55, 0.95),
+        Color::srgb(0.95, 0.80, 0.25),
+        Color::srgb(0.35, 0.80, 0.40),
+        Color::srgb(0.70, 0.40, 0.90),
+        Color::srgb(0.95, 0.55, 0.20),
+    ]
+}
@@ -0,0 +1,102 @@
+//! Registers the graph automata – plus Conway’s Life on the square grid,
+//! driven by the same rule – turns freshly spawned graph automata into
+//! graphs and samples their activity.
+
+use bevy::prelude::*;
+use engine_core::{
+    core::cell::CellState,
+    engine::grid::GridBackend,
+    events::AutomatonAdded,
+    schedule::MainSet,
+    state::AppState,
+};
+use rand::Rng;
+use serde_json::json;
+
+use crate::{
+    analytics::time_series::TimeSeries,
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{
+    build_graph, interface_density, opinion_palette, GraphParams, LifeLikeRule, VoterRule,
+    GRAPH_LIFE_ID, GRAPH_VOTER_ID,
+};
+
+/// Conway’s Life on the ordinary grid, for side‑by‑side comparison.
+pub const LIFE_CONWAY_ID: &str = "life:conway";
+
+fn is_graph(name: &str) -> bool {
+    name == GRAPH_LIFE_ID || name == GRAPH_VOTER_ID
+}
+
+pub struct GraphAutomataPlugin;
+impl Plugin for GraphAutomataPlugin {
+    fn build(&self, app: &mut App) {
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        reg.register_with_seed(LIFE_CONWAY_ID, LifeLikeRule::boxed(), seed_soup);
+        reg.register_params(LIFE_CONWAY_ID, &json!({ "rule": "B3/S23" }));
+
+        reg.register(GRAPH_LIFE_ID, LifeLikeRule::boxed());
+        reg.register_params(GRAPH_LIFE_ID, &GraphParams::default());
+        reg.register(GRAPH_VOTER_ID, VoterRule::boxed());
+        reg.register_params(GRAPH_VOTER_ID, &GraphParams::default());
+        reg.register_palette(GRAPH_VOTER_ID, opinion_palette());
+        app.insert_resource(reg);
+
+        app.add_systems(Update, build_graphs)
+           .add_systems(
+               Update,
+               record_graph_series
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+/// 35 % random live cells.
+pub(crate) fn seed_soup(grid: &mut GridBackend) {
+    let mut rng = rand::rng();
+    if let GridBackend::Dense(g) = grid {
+        for c in g.cells.iter_mut() {
+            c.state = if rng.random_bool(0.35) { CellState::Alive(1) } else { CellState::Dead };
+        }
+    }
+}
+
+/// Replaces the spawned lattice by the configured graph, drawn onto a
+/// canvas of the lattice’s size, and seeds it.
+fn build_graphs(mut added: EventReader<AutomatonAdded>, mut registry: ResMut<AutomataRegistry>) {
+    let mut rng = rand::rng();
+    for ev in added.read() {
+        let Some(info) = registry.get_mut(ev.id) else { continue };
+        if !is_graph(&info.name) { continue; }
+        let canvas = match &info.grid {
+            GridBackend::Dense(g) => g.size,
+            GridBackend::Sparse(_) | GridBackend::Graph(_) => UVec2::splat(512),
+        };
+
+        let p = GraphParams::from_value(&info.params);
+        let mut graph = build_graph(&p, canvas, &mut rng);
+        for cell in graph.cells.iter_mut() {
+            cell.state = if info.name == GRAPH_VOTER_ID {
+                CellState::Alive(1 + rng.random_range(0..p.opinions.max(1)))
+            } else if rng.random_bool(p.density.clamp(0.0, 1.0)) {
+                CellState::Alive(1)
+            } else {
+                CellState::Dead
+            };
+        }
+        info.grid = GridBackend::Graph(graph);
+    }
+}
+
+/// Per‑tick `alive` fraction and `interface` density (share of edges
+/// joining different states).
+fn record_graph_series(registry: Res<AutomataRegistry>, mut series: ResMut<TimeSeries>) {
+    for info in registry.list().iter().filter(|i| is_graph(&i.name)) {
+        let GridBackend::Graph(g) = &info.grid else { continue };
+        let alive = g.cells.iter().filter(|c| c.state != CellState::Dead).count();
+        series.record(info.id, "alive", alive as f64 / g.len().max(1) as f64);
+        series.record(info.id, "interface", interface_density(g));
+    }
+}
@@ -0,0 +1,220 @@
+//! **Kuramoto lattice** – one phase oscillator per site, coupled to its
+//! neighbours:
+//!
+//! ```text
+//! dθᵢ = [ ωᵢ + K/|Nᵢ| Σ_{j∈Nᵢ} sin(θⱼ − θᵢ) ] dt + √(2D dt) ξᵢ
+//! ```
+//!
+//! integrated with Euler–Maruyama on a torus.  `Nᵢ` is either the four
+//! nearest neighbours or the `(2r+1)² − 1` sites of the square of radius
+//! `r`; the latter is summed with periodic box sums so its cost does not
+//! grow with `r`.
+//!
+//! Phases `θ ∈ [0, 2π)` live in the `"phase"` layer, natural frequencies in
+//! `"omega"`.  Cells show the phase on a colour wheel of [`PHASE_BINS`]
+//! hues as `Alive(1 + bin)`.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use engine_core::{
+    core::cell::CellState,
+    engine::grid::{CellLayers, DenseGrid},
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod plugin;
+
+/// Heterogeneous oscillators – a synchronisation transition in `K`.
+pub const KURAMOTO_ID: &str = "kuramoto";
+/// Identical oscillators from random phases – spiral waves and defects.
+pub const KURAMOTO_SPIRALS_ID: &str = "kuramoto:spirals";
+
+pub const PHASE: &str = "phase";
+pub const OMEGA: &str = "omega";
+
+/// Hues of the phase colour wheel.
+pub const PHASE_BINS: u8 = 64;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Neighbourhood {
+    /// Four nearest neighbours.
+    #[default]
+    Nearest,
+    /// Square of Chebyshev radius `radius`.
+    Radius,
+}
+
+/// Distribution of the natural frequencies, centred on `omega`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Frequencies {
+    /// Every oscillator at `omega`.
+    Constant,
+    /// `omega ± spread`.
+    Uniform,
+    /// Standard deviation `spread`.
+    #[default]
+    Normal,
+    /// Cauchy with half‑width `spread` (Kuramoto’s solvable case).
+    Lorentzian,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KuramotoParams {
+    /// Coupling strength `K`.
+    pub coupling:      f32,
+    pub neighbourhood: Neighbourhood,
+    pub radius:        u32,
+    pub frequencies:   Frequencies,
+    pub omega:         f32,
+    pub spread:        f32,
+    /// Phase diffusion `D`.
+    pub noise:         f32,
+    pub dt:            f32,
+    /// Integration steps per tick.
+    pub steps:         u32,
+}
+
+impl Default for KuramotoParams {
+    fn default() -> Self {
+        Self {
+            coupling:      1.5,
+            neighbourhood: Neighbourhood::Nearest,
+            radius:        1,
+            frequencies:   Frequencies::Normal,
+            omega:         1.0,
+            spread:        0.2,
+            noise:         0.0,
+            dt:            0.1,
+            steps:         1,
+        }
+    }
+}
+
+impl KuramotoParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+
+    pub fn spirals() -> Self {
+        Self { coupling: 1.0, frequencies: Frequencies::Constant, spread: 0.0, ..Self::default() }
+    }
+
+    fn sample_omega(&self, rng: &mut impl Rng) -> f32 {
+        let u: f32 = rng.random();
+        match self.frequencies {
+            Frequencies::Constant   => self.omega,
+            Frequencies::Uniform    => self.omega + self.spread * (2.0 * u - 1.0),
+            Frequencies::Normal     => self.omega + self.spread * gaussian(rng),
+            Frequencies::Lorentzian => self.omega + self.spread * (std::f32::consts::PI * (u - 0.5)).tan(),
+        }
+    }
+}
+
+/// Standard normal sample (Box–Muller).
+fn gaussian(rng: &mut impl Rng) -> f32 {
+    let u1: f32 = rng.random::<f32>().max(f32::MIN_POSITIVE);
+    let u2: f32 = rng.random();
+    (-2.0 * u1.ln()).sqrt() * (TAU * u2).cos()
+}
+
+/// Colour‑wheel state of phase `theta`.
+pub fn phase_state(theta: f32) -> CellState {
+    let bin = ((theta.rem_euclid(TAU) / TAU) * PHASE_BINS as f32) as u8;
+    CellState::Alive(1 + bin.min(PHASE_BINS - 1))
+}
+
+/// [`PHASE_BINS`] hues going once round the wheel.
+pub fn phase_palette() -> Vec<Color> {
+    (0..PHASE_BINS)
+        .map(|k| Color::hsl(k as f32 * 360.0 / PHASE_BINS as f32, 0.85, 0.55))
+        .collect()
+}
+
+/// Random phases, natural frequencies drawn from `p`.
+pub fn seed_oscillators(grid: &mut DenseGrid, layers: &mut CellLayers, p: &KuramotoParams, rng: &mut impl Rng) {
+    layers.add(PHASE, 0.0);
+    layers.add(OMEGA, 0.0);
+    if let Some(phase) = layers.plane_mut(PHASE) {
+        phase.iter_mut().for_each(|t| *t = rng.random::<f32>() * TAU);
+    }
+    if let Some(omega) = layers.plane_mut(OMEGA) {
+        omega.iter_mut().for_each(|w| *w = p.sample_omega(rng));
+    }
+    paint(grid, layers);
+}
+
+/// Periodic sum over the `(2r+1)²` square around every site.
+fn box_sum(v: &[f32], w: usize, h: usize, r: usize) -> Vec<f32> {
+    let mut rows = vec![0.0; v.len()];
+    for y in 0..h {
+        let row = &v[y * w..(y + 1) * w];
+        let mut acc: f32 = (0..=2 * r).map(|k| row[(k + w * (r / w + 1) - r) % w]).sum();
+        for x in 0..w {
+            rows[y * w + x] = acc;
+            acc += row[(x + r + 1) % w] - row[(x + w * (r / w + 1) - r) % w];
+        }
+    }
+    let mut out = vec![0.0; v.len()];
+    for x in 0..w {
+        let col = |y: usize| rows[(y % h) * w + x];
+        let mut acc: f32 = (0..=2 * r).map(|k| col(k + h * (r / h + 1) - r)).sum();
+        for y in 0..h {
+            out[y * w + x] = acc;
+            acc += col(y + r + 1) - col(y + h * (r / h + 1) - r);
+        }
+    }
+    out
+}
+
+/// `steps` Euler–Maruyama steps of the whole lattice.
+pub fn step_oscillators(grid: &mut DenseGrid, layers: &mut CellLayers, p: &KuramotoParams, rng: &mut impl Rng) {
+    let (w, h) = (grid.size.x as usize, grid.size.y as usize);
+    let Some(omega) = layers.plane(OMEGA).map(<[f32]>::to_vec) else { return };
+    let Some(theta) = layers.plane_mut(PHASE) else { return };
+    let kick = (2.0 * p.noise.max(0.0) * p.dt).sqrt();
+
+    for _ in 0..p.steps.max(1) {
+        // Σ sin(θⱼ − θᵢ) = cos θᵢ Σ sin θⱼ − sin θᵢ Σ cos θⱼ
+        let sin: Vec<f32> = theta.iter().map(|t| t.sin()).collect();
+        let cos: Vec<f32> = theta.iter().map(|t| t.cos()).collect();
+        let (ss, sc, count) = match p.neighbourhood {
+            Neighbourhood::Nearest => {
+                let nn = |v: &[f32], i: usize| {
+                    let (x, y) = (i % w, i / w);
+                    v[y * w + (x + 1) % w] + v[y * w + (x + w - 1) % w]
+                        + v[((y + 1) % h) * w + x] + v[((y + h - 1) % h) * w + x]
+                };
+                let ss = (0..theta.len()).map(|i| nn(&sin, i)).collect::<Vec<_>>();
+                let sc = (0..theta.len()).map(|i| nn(&cos, i)).collect::<Vec<_>>();
+                (ss, sc, 4.0)
+            }
+            Neighbourhood::Radius => {
+                let r = p.radius.max(1) as usize;
+                // the box includes the site itself, whose sin(θᵢ − θᵢ) is 0
+                let side = (2 * r + 1) as f32;
+                (box_sum(&sin, w, h, r), box_sum(&cos, w, h, r), side * side - 1.0)
+            }
+        };
+
+        for (i, t) in theta.iter_mut().enumerate() {
+            let pull  = cos[i] * ss[i] - sin[i] * sc[i];
+            let drift = omega[i] + p.coupling * pull / count;
+            let noise = if kick > 0.0 { kick * gaussian(rng) } else { 0.0 };
+            *t = (*t + drift * p.dt + noise).rem_euclid(TAU);
+        }
+    }
+    paint(grid, layers);
+}
+
+fn paint(grid: &mut DenseGrid, layers: &CellLayers) {
+    let Some(theta) = layers.plane(PHASE) else { return };
+    for (cell, t) in grid.cells.iter_mut().zip(theta) {
+        cell.state = phase_state(*t);
+    }
+}
@@ -0,0 +1,87 @@
+//! Registers the Kuramoto presets, seeds and integrates them, and samples
+//! the order parameter and defect count.
+
+use bevy::prelude::*;
+use engine_core::{
+    engine::grid::GridBackend, events::AutomatonAdded, schedule::MainSet, state::AppState,
+};
+
+use crate::{
+    agents::HabitatRule,
+    analytics::{
+        synchrony::{order_parameter, phase_defects},
+        time_series::TimeSeries,
+    },
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{
+    phase_palette, seed_oscillators, step_oscillators, KuramotoParams, KURAMOTO_ID,
+    KURAMOTO_SPIRALS_ID, PHASE,
+};
+
+fn is_kuramoto(name: &str) -> bool {
+    name == KURAMOTO_ID || name == KURAMOTO_SPIRALS_ID
+}
+
+pub struct KuramotoPlugin;
+impl Plugin for KuramotoPlugin {
+    fn build(&self, app: &mut App) {
+        // Phases are continuous and radius‑r coupling reaches past the
+        // 8‑cell context: the lattice is integrated as a whole below.
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        for (id, defaults) in [
+            (KURAMOTO_ID, KuramotoParams::default()),
+            (KURAMOTO_SPIRALS_ID, KuramotoParams::spirals()),
+        ] {
+            reg.register(id, HabitatRule::boxed());
+            reg.register_params(id, &defaults);
+            reg.register_palette(id, phase_palette());
+        }
+        app.insert_resource(reg);
+
+        app.add_systems(Update, seed_lattices)
+           .add_systems(
+               Update,
+               step_lattices
+                   .in_set(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           )
+           .add_systems(
+               Update,
+               record_kuramoto_series
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+fn seed_lattices(mut added: EventReader<AutomatonAdded>, mut registry: ResMut<AutomataRegistry>) {
+    let mut rng = rand::rng();
+    for ev in added.read() {
+        let Some(info) = registry.get_mut(ev.id) else { continue };
+        if !is_kuramoto(&info.name) { continue; }
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        seed_oscillators(grid, &mut info.layers, &KuramotoParams::from_value(&info.params), &mut rng);
+    }
+}
+
+fn step_lattices(mut registry: ResMut<AutomataRegistry>) {
+    let mut rng = rand::rng();
+    for info in registry.iter_mut().filter(|i| is_kuramoto(&i.name)) {
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        step_oscillators(grid, &mut info.layers, &KuramotoParams::from_value(&info.params), &mut rng);
+    }
+}
+
+/// Per‑tick global `order_parameter` r(t) and the number of phase `defects`
+/// (vortex cores of either sign).
+fn record_kuramoto_series(registry: Res<AutomataRegistry>, mut series: ResMut<TimeSeries>) {
+    for info in registry.list().iter().filter(|i| is_kuramoto(&i.name)) {
+        let GridBackend::Dense(grid) = &info.grid else { continue };
+        let Some(theta) = info.layers.plane(PHASE) else { continue };
+        let (r, _)     = order_parameter(theta);
+        let (pos, neg) = phase_defects(theta, grid.size);
+        series.record(info.id, "order_parameter", r as f64);
+        series.record(info.id, "defects", (pos + neg) as f64);
+    }
+}
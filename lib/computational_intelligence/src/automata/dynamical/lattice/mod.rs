@@ -0,0 +1,153 @@
+//! Automata on **hexagonal and triangular lattices**.
+//!
+//! Outer‑totalistic presets run the Life‑like rule of
+//! [`graph::LifeLikeRule`](super::graph::LifeLikeRule) on the six‑ or
+//! three‑cell stencil selected by [`Lattice`](engine_core::core::Lattice):
+//!
+//! * `hex:life`      – B2/S34 from a random soup;
+//! * `hex:snowflake` – Packard’s snowflake, B1/S123456 from one seed;
+//! * `tri:life`      – B1/S from one seed on triangles.
+//!
+//! `hex:reiter` is Reiter’s (2005) continuous snowflake model, integrated as
+//! a whole lattice.  Every cell holds a water level `s` (`"water"` layer);
+//! cells with `s ≥ 1` are ice.  A cell is *receptive* if it or a neighbour
+//! is ice.  Each step receptive cells gain `γ`, while the water of the
+//! others diffuses towards their neighbours' mean with rate `α⁄2`.  Cells
+//! past the border stay at the background level `β`.
+
+use bevy::prelude::*;
+use engine_core::{
+    core::{cell::CellState, Dim, DimHex},
+    engine::grid::{CellLayers, DenseGrid, GridBackend},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod plugin;
+
+pub const HEX_LIFE_ID: &str      = "hex:life";
+pub const HEX_SNOWFLAKE_ID: &str = "hex:snowflake";
+pub const HEX_REITER_ID: &str    = "hex:reiter";
+pub const TRI_LIFE_ID: &str      = "tri:life";
+
+pub const WATER: &str = "water";
+
+/// Shades of vapour below the ice colour.
+const VAPOUR_BINS: u8 = 16;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReiterParams {
+    /// Diffusion rate `α`.
+    pub alpha: f32,
+    /// Background water level `β`.
+    pub beta:  f32,
+    /// Water added to receptive cells per step, `γ`.
+    pub gamma: f32,
+    /// Integration steps per tick.
+    pub steps: u32,
+}
+
+impl Default for ReiterParams {
+    fn default() -> Self {
+        Self { alpha: 1.0, beta: 0.4, gamma: 0.001, steps: 4 }
+    }
+}
+
+impl ReiterParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+}
+
+/// One live cell in the middle of the grid.
+pub fn seed_centre(grid: &mut GridBackend) {
+    if let GridBackend::Dense(g) = grid {
+        g.cells.iter_mut().for_each(|c| c.state = CellState::Dead);
+        let centre = (g.size / 2).as_ivec2();
+        let i = g.idx(centre);
+        g.cells[i].state = CellState::Alive(1);
+    }
+}
+
+/// Background level `β` everywhere, one frozen cell in the middle.
+pub fn seed_reiter(grid: &mut DenseGrid, layers: &mut CellLayers, p: &ReiterParams) {
+    layers.add(WATER, p.beta);
+    let centre = (grid.size / 2).as_ivec2();
+    layers.set(WATER, centre, 1.0);
+    paint(grid, layers);
+}
+
+/// `steps` updates of Reiter’s model on the hex stencil.
+///
+/// ```
+/// use bevy::math::{IVec2, UVec2};
+/// use engine_core::engine::grid::{CellLayers, DenseGrid};
+/// use computational_intelligence::automata::dynamical::lattice::{
+///     seed_reiter, step_reiter, ReiterParams, WATER,
+/// };
+///
+/// let size = UVec2::new(41, 41);
+/// let (mut grid, mut layers) = (DenseGrid::blank(size), CellLayers::new(size));
+/// let p = ReiterParams { steps: 2000, ..Default::default() };
+/// seed_reiter(&mut grid, &mut layers, &p);
+/// step_reiter(&mut grid, &mut layers, &p);
+///
+/// // the crystal has grown beyond its seed, symmetrically along the rows
+/// let ice = |x, y| layers.get(WATER, IVec2::new(x, y)) >= 1.0;
+/// assert!(ice(21, 20) && ice(19, 20));
+/// assert_eq!(ice(25, 20), ice(15, 20));
+/// ```
+pub fn step_reiter(grid: &mut DenseGrid, layers: &mut CellLayers, p: &ReiterParams) {
+    let size = grid.size.as_ivec2();
+    let Some(water) = layers.plane_mut(WATER) else { return };
+    let idx    = |q: IVec2| (q.y * size.x + q.x) as usize;
+    let inside = |q: IVec2| (0..size.x).contains(&q.x) && (0..size.y).contains(&q.y);
+
+    for _ in 0..p.steps.max(1) {
+        let s = water.to_vec();
+        let frozen = |q: IVec2| if inside(q) { s[idx(q)] >= 1.0 } else { p.beta >= 1.0 };
+        // receptive cells keep their water (`v`), the rest diffuses (`u`)
+        let receptive: Vec<bool> = (0..s.len())
+            .map(|i| {
+                let q = IVec2::new(i as i32 % size.x, i as i32 / size.x);
+                frozen(q) || DimHex::offsets_at(q).iter().any(|o| frozen(q + *o))
+            })
+            .collect();
+        let u = |q: IVec2| {
+            if !inside(q)             { p.beta }
+            else if receptive[idx(q)] { 0.0 }
+            else                      { s[idx(q)] }
+        };
+
+        for (i, w) in water.iter_mut().enumerate() {
+            let q    = IVec2::new(i as i32 % size.x, i as i32 / size.x);
+            let mean = DimHex::offsets_at(q).iter().map(|o| u(q + *o)).sum::<f32>() / 6.0;
+            let own  = u(q);
+            let v    = if receptive[i] { s[i] + p.gamma } else { 0.0 };
+            *w = own + p.alpha * 0.5 * (mean - own) + v;
+        }
+    }
+    paint(grid, layers);
+}
+
+fn paint(grid: &mut DenseGrid, layers: &CellLayers) {
+    let Some(water) = layers.plane(WATER) else { return };
+    for (cell, &s) in grid.cells.iter_mut().zip(water) {
+        cell.state = if s >= 1.0 {
+            CellState::Alive(1)
+        } else {
+            CellState::Alive(2 + (s.clamp(0.0, 0.999) * VAPOUR_BINS as f32) as u8)
+        };
+    }
+}
+
+/// Ice first, then vapour from dry (dark) to humid.
+pub fn reiter_palette() -> Vec<Color> {
+    std::iter::once(Color::srgb(0.92, 0.97, 1.0))
+        .chain((0..VAPOUR_BINS).map(|k| {
+            let t = k as f32 / (VAPOUR_BINS - 1) as f32;
+            Color::srgb(0.04 + 0.10 * t, 0.06 + 0.18 * t, 0.16 + 0.40 * t)
+        }))
+        .collect()
+}
@@ -0,0 +1,82 @@
+//! Registers the hex and triangle presets with their lattices, and seeds,
+//! grows and measures Reiter snowflakes.
+
+use bevy::prelude::*;
+use engine_core::{
+    core::Lattice, engine::grid::GridBackend, events::AutomatonAdded, schedule::MainSet,
+    state::AppState,
+};
+use serde_json::json;
+
+use crate::{
+    agents::HabitatRule,
+    analytics::time_series::TimeSeries,
+    automata::dynamical::graph::{plugin::seed_soup, LifeLikeRule},
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{
+    reiter_palette, seed_centre, seed_reiter, step_reiter, ReiterParams, HEX_LIFE_ID,
+    HEX_REITER_ID, HEX_SNOWFLAKE_ID, TRI_LIFE_ID, WATER,
+};
+
+pub struct LatticeAutomataPlugin;
+impl Plugin for LatticeAutomataPlugin {
+    fn build(&self, app: &mut App) {
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        for (id, rule, seed, lattice) in [
+            (HEX_LIFE_ID,      "B2/S34",      seed_soup as fn(&mut GridBackend), Lattice::Hex),
+            (HEX_SNOWFLAKE_ID, "B1/S123456",  seed_centre,                       Lattice::Hex),
+            (TRI_LIFE_ID,      "B1/S",        seed_centre,                       Lattice::Triangular),
+        ] {
+            reg.register_with_seed(id, LifeLikeRule::boxed(), seed);
+            reg.register_params(id, &json!({ "rule": rule }));
+            reg.register_lattice(id, lattice);
+        }
+
+        // Water levels are continuous: the lattice is integrated as a
+        // whole below.
+        reg.register(HEX_REITER_ID, HabitatRule::boxed());
+        reg.register_params(HEX_REITER_ID, &ReiterParams::default());
+        reg.register_palette(HEX_REITER_ID, reiter_palette());
+        reg.register_lattice(HEX_REITER_ID, Lattice::Hex);
+        app.insert_resource(reg);
+
+        app.add_systems(Update, seed_snowflakes)
+           .add_systems(
+               Update,
+               grow_snowflakes
+                   .in_set(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           )
+           .add_systems(
+               Update,
+               record_ice
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+fn seed_snowflakes(mut added: EventReader<AutomatonAdded>, mut registry: ResMut<AutomataRegistry>) {
+    for ev in added.read() {
+        let Some(info) = registry.get_mut(ev.id) else { continue };
+        if info.name != HEX_REITER_ID { continue; }
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        seed_reiter(grid, &mut info.layers, &ReiterParams::from_value(&info.params));
+    }
+}
+
+fn grow_snowflakes(mut registry: ResMut<AutomataRegistry>) {
+    for info in registry.iter_mut().filter(|i| i.name == HEX_REITER_ID) {
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        step_reiter(grid, &mut info.layers, &ReiterParams::from_value(&info.params));
+    }
+}
+
+/// Per‑tick number of `ice` cells of every Reiter snowflake.
+fn record_ice(registry: Res<AutomataRegistry>, mut series: ResMut<TimeSeries>) {
+    for info in registry.list().iter().filter(|i| i.name == HEX_REITER_ID) {
+        let Some(water) = info.layers.plane(WATER) else { continue };
+        series.record(info.id, "ice", water.iter().filter(|&&s| s >= 1.0).count() as f64);
+    }
+}
@@ -84,7 +84,7 @@ impl AutomatonRule for LeniaRule {
 
 /* ─────────────────── seed functions for Lenia ─────────────────── */
 
-use engine_core::engine::grid::GridBackend::{Dense, Sparse};
+use engine_core::engine::grid::GridBackend::{Dense, Graph, Sparse};
 
 /// Default seed pattern for Lenia: a solid circular blob of moderate density at the center.
 pub fn seed_lenia(grid: &mut engine_core::engine::grid::GridBackend) {
@@ -106,6 +106,9 @@ pub fn seed_lenia(grid: &mut engine_core::engine::grid::GridBackend) {
             // in sparse case, just set a single cell at origin with a medium-high level
             s.set_state(IVec2::ZERO, CellState::Alive(180));
         }
+        Graph(g) => {
+            if let Some(c) = g.cells.first_mut() { c.state = CellState::Alive(180); }
+        }
     }
 }
 
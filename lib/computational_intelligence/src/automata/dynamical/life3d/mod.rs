@@ -0,0 +1,248 @@
+//! **Three‑dimensional Life‑like automata** on a [`DenseGrid3`] volume.
+//!
+//! Outer‑totalistic rules over the Moore‑26 ([`Dim3`]) or six‑face
+//! ([`Dim3VonNeumann`]) stencil, written either in Bays’ four‑number form
+//! `ElEuFlFu` (“4555”: survive with 4–5, born with 5–5 live neighbours) or
+//! as `B…/S…` lists with ranges (“S13‑26/B13‑14,17‑19” – Clouds).
+//!
+//! The volume lives beside the automaton in [`Volumes`]; its 2‑D grid is a
+//! viewport showing either one `z` slice or a depth‑cued max‑intensity
+//! projection, scaled up to fill the grid.
+
+use std::{collections::HashMap, marker::PhantomData};
+
+use bevy::prelude::*;
+use engine_core::{
+    core::{cell::CellState, AutomatonRule, CellCtx, CellOutcome, Dim, Dim3, Dim3VonNeumann},
+    engine::{
+        grid::{DenseGrid, DenseGrid3},
+        stepper::dense3::step_dense3,
+    },
+    events::AutomatonId,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod plugin;
+
+/// Bays’ 3‑D Life 4555 grown from a small random block.
+pub const LIFE3D_4555_ID: &str   = "life3d:4555";
+/// “Clouds” – a half‑full volume condensing into blobs.
+pub const LIFE3D_CLOUDS_ID: &str = "life3d:clouds";
+
+/// Shades of the depth cue (and of the palette).
+pub const DEPTH_BINS: u8 = 16;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stencil {
+    /// The 26 cells of the surrounding cube.
+    #[default]
+    Moore,
+    /// The six face neighbours.
+    VonNeumann,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum View {
+    /// The plane `z`.
+    #[default]
+    Slice,
+    /// Brightest voxel along each `z` ray, nearer planes brighter.
+    Mip,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Life3Params {
+    pub rule:          String,
+    pub neighbourhood: Stencil,
+    /// Edge length of the cubic volume.
+    pub side:          u32,
+    /// Edge of the central block filled at random (`0` = the whole volume).
+    pub seed:          u32,
+    /// Live fraction of the seeded block.
+    pub density:       f64,
+    pub view:          View,
+    /// Initial slice (clamped to the volume).
+    pub z:             u32,
+}
+
+impl Default for Life3Params {
+    fn default() -> Self {
+        Self {
+            rule:          "4555".into(),
+            neighbourhood: Stencil::Moore,
+            side:          40,
+            seed:          20,
+            density:       0.3,
+            view:          View::Slice,
+            z:             20,
+        }
+    }
+}
+
+impl Life3Params {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+
+    pub fn clouds() -> Self {
+        Self { rule: "S13-26/B13-14,17-19".into(), seed: 0, density: 0.6, view: View::Mip, ..Self::default() }
+    }
+}
+
+/* ── rule ─────────────────────────────────────────────────────────────── */
+
+/// Outer‑totalistic 3‑D rule on stencil `D`; bit `n` of a mask = count `n`.
+pub struct Life3Rule<D> {
+    pub birth:   u32,
+    pub survive: u32,
+    _stencil:    PhantomData<D>,
+}
+
+impl<D> Life3Rule<D> {
+    pub fn new(birth: u32, survive: u32) -> Self {
+        Self { birth, survive, _stencil: PhantomData }
+    }
+}
+
+/// `(birth, survive)` masks of a Bays `ElEuFlFu` code or a `B…/S…` rule
+/// (either order, comma‑separated counts and `a‑b` ranges up to 26).
+///
+/// ```
+/// use computational_intelligence::automata::dynamical::life3d::parse_rule;
+///
+/// assert_eq!(parse_rule("4555"), Some((1 << 5, 1 << 4 | 1 << 5)));
+/// assert_eq!(parse_rule("B5/S4-5"), parse_rule("4555"));
+/// let (b, s) = parse_rule("S13-26/B13-14,17-19").unwrap();
+/// assert_eq!(b, 1 << 13 | 1 << 14 | 1 << 17 | 1 << 18 | 1 << 19);
+/// assert_eq!(s.count_ones(), 14);
+/// assert_eq!(parse_rule("B27/S1"), None);
+/// ```
+pub fn parse_rule(rule: &str) -> Option<(u32, u32)> {
+    let rule = rule.trim();
+    let span = |lo: u32, hi: u32| (lo..=hi).fold(0u32, |m, n| m | 1 << n);
+
+    if rule.len() == 4 && rule.chars().all(|c| c.is_ascii_digit()) {
+        let d: Vec<u32> = rule.chars().filter_map(|c| c.to_digit(10)).collect();
+        return Some((span(d[2], d[3]), span(d[0], d[1])));
+    }
+
+    let list = |part: &str| -> Option<u32> {
+        part.split(',').filter(|t| !t.is_empty()).try_fold(0u32, |m, tok| {
+            let (lo, hi) = match tok.split_once('-') {
+                Some((a, b)) => (a.parse().ok()?, b.parse().ok()?),
+                None         => { let n = tok.parse().ok()?; (n, n) }
+            };
+            (lo <= hi && hi <= 26).then(|| m | span(lo, hi))
+        })
+    };
+    let (mut birth, mut survive) = (None, None);
+    for part in rule.split('/') {
+        let (tag, rest) = part.split_at_checked(1)?;
+        match tag {
+            "B" | "b" => birth   = Some(list(rest)?),
+            "S" | "s" => survive = Some(list(rest)?),
+            _ => return None,
+        }
+    }
+    Some((birth?, survive?))
+}
+
+impl<D: Dim<Coord = IVec3>> AutomatonRule for Life3Rule<D> {
+    type D = D;
+
+    fn next_state(&self, ctx: CellCtx<Self::D>, _params: &Value) -> CellOutcome {
+        let alive = ctx.neighbourhood.iter().filter(|s| **s != CellState::Dead).count();
+        let hit   = |mask: u32| mask >> alive & 1 == 1;
+        let next  = match ctx.self_state {
+            CellState::Dead     if hit(self.birth)   => CellState::Alive(1),
+            CellState::Alive(_) if hit(self.survive) => CellState::Alive(1),
+            _ => CellState::Dead,
+        };
+        if next == ctx.self_state {
+            CellOutcome::Unchanged
+        } else {
+            CellOutcome::Next { state: next, memory: ctx.memory.clone() }
+        }
+    }
+}
+
+/* ── volume ───────────────────────────────────────────────────────────── */
+
+/// A live volume and how it is being viewed.
+#[derive(Clone)]
+pub struct Volume {
+    pub grid: DenseGrid3,
+    pub view: View,
+    pub z:    u32,
+}
+
+/// Volumes of every live 3‑D automaton.
+#[derive(Resource, Default)]
+pub struct Volumes(pub HashMap<AutomatonId, Volume>);
+
+/// Cube of `p.side`, its central `p.seed` block filled at `p.density`.
+pub fn random_volume(p: &Life3Params, rng: &mut impl Rng) -> DenseGrid3 {
+    let side = p.side.max(1);
+    let mut vol = DenseGrid3::blank(UVec3::splat(side));
+    let block = if p.seed == 0 { side } else { p.seed.min(side) };
+    let lo = ((side - block) / 2) as i32;
+    for z in lo..lo + block as i32 {
+        for y in lo..lo + block as i32 {
+            for x in lo..lo + block as i32 {
+                if rng.random_bool(p.density.clamp(0.0, 1.0)) {
+                    let i = vol.idx(IVec3::new(x, y, z));
+                    vol.cells[i].state = CellState::Alive(1);
+                }
+            }
+        }
+    }
+    vol
+}
+
+/// One step under `p.rule` (Life 4555 if it does not parse).
+pub fn step_volume(vol: &mut DenseGrid3, p: &Life3Params) {
+    let (birth, survive) = parse_rule(&p.rule).unwrap_or((1 << 5, 1 << 4 | 1 << 5));
+    match p.neighbourhood {
+        Stencil::Moore      => step_dense3(vol, &Life3Rule::<Dim3>::new(birth, survive), &Value::Null),
+        Stencil::VonNeumann => step_dense3(vol, &Life3Rule::<Dim3VonNeumann>::new(birth, survive), &Value::Null),
+    }
+}
+
+/// Draws the chosen view of `vol` into `grid`, each voxel an `s × s` block
+/// with `s` the largest scale that fits.
+pub fn render_view(vol: &DenseGrid3, view: View, z: u32, grid: &mut DenseGrid) {
+    let plane = vol.plane_size();
+    let scale = (grid.size.x / plane.x.max(1)).min(grid.size.y / plane.y.max(1)).max(1);
+    let depth = vol.size.z.max(1);
+    let levels: Vec<u8> = match view {
+        View::Slice => vol.slice(z)
+            .iter()
+            .map(|c| if c.state == CellState::Dead { 0 } else { DEPTH_BINS })
+            .collect(),
+        View::Mip => vol.project_max(|z, s| match s {
+            CellState::Dead => 0,
+            _ => DEPTH_BINS - (z * DEPTH_BINS as u32 / depth) as u8,
+        }),
+    };
+
+    for (i, cell) in grid.cells.iter_mut().enumerate() {
+        let (x, y) = (i as u32 % grid.size.x / scale, i as u32 / grid.size.x / scale);
+        let level  = if x < plane.x && y < plane.y { levels[(y * plane.x + x) as usize] } else { 0 };
+        cell.state = if level == 0 { CellState::Dead } else { CellState::Alive(level) };
+    }
+}
+
+/// Far (dim, blue) to near (bright, warm) – slices use the brightest.
+pub fn depth_palette() -> Vec<Color> {
+    (0..DEPTH_BINS)
+        .map(|k| {
+            let t = (k + 1) as f32 / DEPTH_BINS as f32;
+            Color::srgb(0.15 + 0.85 * t, 0.25 + 0.65 * t, 0.55 + 0.25 * t)
+        })
+        .collect()
+}
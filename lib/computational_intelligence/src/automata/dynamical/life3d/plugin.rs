@@ -0,0 +1,101 @@
+//! Registers the 3‑D Life presets, keeps a volume per spawned automaton,
+//! steps it and redraws the viewed slice or projection.
+
+use bevy::prelude::*;
+use engine_core::{
+    engine::grid::GridBackend,
+    events::{AutomatonAdded, AutomatonRemoved},
+    schedule::MainSet,
+    state::AppState,
+};
+
+use crate::{
+    agents::HabitatRule,
+    analytics::time_series::TimeSeries,
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{
+    depth_palette, random_volume, render_view, step_volume, Life3Params, Volume, Volumes,
+    LIFE3D_4555_ID, LIFE3D_CLOUDS_ID,
+};
+
+fn is_life3d(name: &str) -> bool {
+    name == LIFE3D_4555_ID || name == LIFE3D_CLOUDS_ID
+}
+
+pub struct Life3dPlugin;
+impl Plugin for Life3dPlugin {
+    fn build(&self, app: &mut App) {
+        // The cells live in a `Volumes` entry; the 2‑D grid is only the
+        // viewport, redrawn every frame so scrubbing shows at once.
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        for (id, defaults) in [
+            (LIFE3D_4555_ID, Life3Params::default()),
+            (LIFE3D_CLOUDS_ID, Life3Params::clouds()),
+        ] {
+            reg.register(id, HabitatRule::boxed());
+            reg.register_params(id, &defaults);
+            reg.register_palette(id, depth_palette());
+        }
+        app.insert_resource(reg);
+
+        app.init_resource::<Volumes>()
+           .add_systems(Update, (build_volumes, drop_volumes))
+           .add_systems(
+               Update,
+               step_volumes
+                   .in_set(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           )
+           .add_systems(
+               Update,
+               (draw_volumes, record_population)
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+fn build_volumes(
+    mut added:    EventReader<AutomatonAdded>,
+    registry:     Res<AutomataRegistry>,
+    mut volumes:  ResMut<Volumes>,
+) {
+    let mut rng = rand::rng();
+    for ev in added.read() {
+        let Some(info) = registry.get(ev.id) else { continue };
+        if !is_life3d(&info.name) { continue; }
+        let p    = Life3Params::from_value(&info.params);
+        let grid = random_volume(&p, &mut rng);
+        let z    = p.z.min(grid.size.z.saturating_sub(1));
+        volumes.0.insert(ev.id, Volume { grid, view: p.view, z });
+    }
+}
+
+fn drop_volumes(mut removed: EventReader<AutomatonRemoved>, mut volumes: ResMut<Volumes>) {
+    for ev in removed.read() {
+        volumes.0.remove(&ev.id);
+    }
+}
+
+fn step_volumes(registry: Res<AutomataRegistry>, mut volumes: ResMut<Volumes>) {
+    for (id, vol) in volumes.0.iter_mut() {
+        let Some(info) = registry.get(*id) else { continue };
+        step_volume(&mut vol.grid, &Life3Params::from_value(&info.params));
+    }
+}
+
+fn draw_volumes(mut registry: ResMut<AutomataRegistry>, volumes: Res<Volumes>) {
+    for (id, vol) in volumes.0.iter() {
+        let Some(info) = registry.get_mut(*id) else { continue };
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        render_view(&vol.grid, vol.view, vol.z, grid);
+    }
+}
+
+/// Per‑tick number of live voxels.
+fn record_population(volumes: Res<Volumes>, mut series: ResMut<TimeSeries>) {
+    for (id, vol) in volumes.0.iter() {
+        series.record(*id, "population", vol.grid.population() as f64);
+    }
+}
@@ -1,7 +1,21 @@
-//! Continuous‑state & hybrid dynamical systems (Lenia, Life, reservoirs, swarms).
+//! Continuous‑state & hybrid dynamical systems (Lenia, Life, reservoirs, swarms,
+//! agent‑based economies, predator–prey, segregation, traffic, spatial games,
+//! Ising / Potts spin models, Kuramoto oscillators, random Boolean networks,
+//! automata on graphs, hexagonal and triangular lattices, 3‑D Life).
 
+pub mod boolean_network;
+pub mod games;
+pub mod graph;
+pub mod kuramoto;
+pub mod lattice;
 pub mod lenia;
+pub mod life3d;
 pub mod particle;
+pub mod schelling;
+pub mod spin;
+pub mod sugarscape;
+pub mod traffic;
+pub mod wator;
 
 pub mod swarm;
 
@@ -82,7 +82,7 @@ impl AutomatonRule for HPPRule {
 /* ───────────────────────────── seeding function ───────────────────────────── */
 
 use bevy::math::IVec2;
-use engine_core::engine::grid::GridBackend::{Dense, Sparse};
+use engine_core::engine::grid::GridBackend::{Dense, Graph, Sparse};
 
 /// Seed pattern for HPP: a cross of streams at the center to demonstrate collisions.
 ///
@@ -97,5 +97,7 @@ pub fn seed_hpp(grid: &mut engine_core::engine::grid::GridBackend) {
         Sparse(s) => {
             s.set_state(IVec2::ZERO, CellState::Alive(HPPRule::N | HPPRule::E | HPPRule::S | HPPRule::W));
         }
+        // lattice‑gas directions need a lattice
+        Graph(_) => {}
     }
 }
@@ -1,8 +1,18 @@
-//! Aggregates all *dynamical* automata: Lenia, HPP lattice‑gas, etc.
+//! Aggregates all *dynamical* automata: Lenia, HPP lattice‑gas, swarms, etc.
 
 use bevy::prelude::*;
 
-use crate::automata::dynamical::{lenia::plugin::LeniaPlugin, particle::plugin::ParticleAutomataPlugin};
+use crate::automata::dynamical::{
+    boolean_network::plugin::BooleanNetworkPlugin,
+    games::plugin::SpatialGamesPlugin, graph::plugin::GraphAutomataPlugin,
+    kuramoto::plugin::KuramotoPlugin, lattice::plugin::LatticeAutomataPlugin,
+    lenia::plugin::LeniaPlugin, life3d::plugin::Life3dPlugin,
+    particle::plugin::ParticleAutomataPlugin,
+    schelling::plugin::SchellingPlugin, spin::plugin::SpinModelsPlugin,
+    sugarscape::plugin::SugarscapePlugin,
+    swarm::plugin::SwarmAutomataPlugin, traffic::plugin::TrafficPlugin,
+    wator::plugin::WatorPlugin,
+};
 
 /// Dynamical automata master plugin.
 pub struct DynamicalAutomataPlugin;
@@ -12,6 +22,18 @@ impl Plugin for DynamicalAutomataPlugin {
         app.add_plugins((
             LeniaPlugin,
             ParticleAutomataPlugin,
+            SwarmAutomataPlugin,
+            SugarscapePlugin,
+            WatorPlugin,
+            SchellingPlugin,
+            TrafficPlugin,
+            SpatialGamesPlugin,
+            SpinModelsPlugin,
+            KuramotoPlugin,
+            BooleanNetworkPlugin,
+            GraphAutomataPlugin,
+            LatticeAutomataPlugin,
+            Life3dPlugin,
         ));
     }
 }
@@ -0,0 +1,190 @@
+//! Thomas Schelling’s **segregation model** (Dynamic Models of
+//! Segregation, 1971).
+//!
+//! `groups` kinds of agents share a toroidal grid with a fraction of empty
+//! cells.  Each tick, in random order, every agent looks at its occupied
+//! neighbours within `radius` (Chebyshev); it is *content* when the share of
+//! its own group reaches its tolerance threshold, otherwise it relocates:
+//!
+//! * [`Movement::Random`]  – to a uniformly random empty cell;
+//! * [`Movement::Nearest`] – to the closest empty cell (ring by ring, up to
+//!   `search_radius`) where it would be content, staying put if none exists.
+//!
+//! Cells show group `g` as `Alive(g + 1)`, so the
+//! [`segregation_index`](crate::analytics::segregation::segregation_index)
+//! can be read straight off the grid.
+
+use bevy::prelude::*;
+use engine_core::core::cell::CellState;
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::agents::{Agent, AgentCtx, AgentOutcome, AgentSpawner};
+
+pub mod plugin;
+
+/// Rule id under which the model is registered.
+pub const SCHELLING_ID: &str = "schelling";
+
+/// Random probes before a `Random` mover gives up for this tick.
+const RANDOM_PROBES: u32 = 256;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Movement {
+    #[default]
+    Random,
+    Nearest,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SchellingParams {
+    /// Number of agent groups (1‥=254).
+    pub groups:        u8,
+    /// Per‑group minimum share of like neighbours an agent is content with;
+    /// the last entry applies to all further groups.
+    pub tolerance:     Vec<f32>,
+    /// Fraction of cells left empty at seeding time.
+    pub vacancy:       f64,
+    pub movement:      Movement,
+    /// Neighbourhood radius (1 = Moore).
+    pub radius:        i32,
+    /// How far a `Nearest` mover searches for a satisfying vacancy.
+    pub search_radius: i32,
+}
+
+impl Default for SchellingParams {
+    fn default() -> Self {
+        Self {
+            groups:        2,
+            tolerance:     vec![0.3],
+            vacancy:       0.1,
+            movement:      Movement::Random,
+            radius:        1,
+            search_radius: 16,
+        }
+    }
+}
+
+impl SchellingParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+
+    pub fn tolerance_of(&self, group: u8) -> f32 {
+        let i = (group as usize).min(self.tolerance.len().saturating_sub(1));
+        self.tolerance.get(i).copied().unwrap_or(0.0)
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SchellingAgent {
+    pub group: u8,
+    /// Content after its last turn.
+    pub happy: bool,
+    /// Relocated during its last turn.
+    pub moved: bool,
+}
+
+impl SchellingAgent {
+    pub fn new(group: u8) -> Self {
+        Self { group, happy: true, moved: false }
+    }
+
+    fn state(self) -> CellState {
+        CellState::Alive(self.group + 1)
+    }
+
+    /// Share of like agents among the occupied cells around `at`, ignoring
+    /// the cell at `home` (the agent’s own, when judging a vacancy).
+    /// An agent without neighbours counts as content.
+    fn like_share(self, ctx: &AgentCtx, at: IVec2, home: IVec2, radius: i32) -> f32 {
+        let home = ctx.cells.wrap(home);
+        let (mut like, mut total) = (0u32, 0u32);
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let q = at + IVec2::new(dx, dy);
+                if (dx == 0 && dy == 0) || ctx.cells.wrap(q) == home { continue; }
+                match ctx.cells.state(q) {
+                    CellState::Dead => {}
+                    s => {
+                        total += 1;
+                        if s == self.state() { like += 1; }
+                    }
+                }
+            }
+        }
+        if total == 0 { 1.0 } else { like as f32 / total as f32 }
+    }
+
+    fn random_vacancy(ctx: &mut AgentCtx) -> Option<IVec2> {
+        let size = ctx.cells.size()?.as_ivec2();
+        for _ in 0..RANDOM_PROBES {
+            let q = IVec2::new(ctx.rng.random_range(0..size.x), ctx.rng.random_range(0..size.y));
+            if ctx.is_free(q) { return Some(q); }
+        }
+        None
+    }
+
+    /// Closest vacancy (Chebyshev rings, shuffled within a ring) at which
+    /// the agent would be content.
+    fn nearest_vacancy(self, ctx: &mut AgentCtx, pos: IVec2, p: &SchellingParams) -> Option<IVec2> {
+        let want = p.tolerance_of(self.group);
+        for r in 1..=p.search_radius.max(1) {
+            let mut ring: Vec<IVec2> = (-r..=r)
+                .flat_map(|d| [IVec2::new(d, -r), IVec2::new(d, r)])
+                .chain((1 - r..r).flat_map(|d| [IVec2::new(-r, d), IVec2::new(r, d)]))
+                .collect();
+            ring.shuffle(ctx.rng);
+            let found = ring.into_iter().map(|d| pos + d).find(|q| {
+                ctx.is_free(*q) && self.like_share(ctx, *q, pos, p.radius) >= want
+            });
+            if found.is_some() { return found; }
+        }
+        None
+    }
+}
+
+impl Agent for SchellingAgent {
+    type Params = SchellingParams;
+
+    fn step(&mut self, ctx: &mut AgentCtx, p: &SchellingParams) -> AgentOutcome {
+        let pos = ctx.pos();
+        self.happy = self.like_share(ctx, pos, pos, p.radius) >= p.tolerance_of(self.group);
+        self.moved = false;
+
+        if !self.happy {
+            let target = match p.movement {
+                Movement::Random  => Self::random_vacancy(ctx),
+                Movement::Nearest => self.nearest_vacancy(ctx, pos, p),
+            };
+            if let Some(q) = target {
+                ctx.cells.set_state(pos, CellState::Dead);
+                ctx.move_to(q);
+                self.moved = true;
+            }
+        }
+        ctx.cells.set_state(ctx.pos(), self.state());
+        AgentOutcome::Live
+    }
+}
+
+/// Fills the slice with uniformly mixed groups, leaving `vacancy` empty.
+pub fn seed_schelling(s: &mut AgentSpawner) {
+    let p = SchellingParams::from_value(s.params);
+    let Some(size) = s.cells.size() else { return };
+    let groups = p.groups.clamp(1, 254);
+    let mut rng = rand::rng();
+
+    for y in 0..size.y as i32 {
+        for x in 0..size.x as i32 {
+            if rng.random_bool(p.vacancy.clamp(0.0, 1.0)) { continue; }
+            let q     = IVec2::new(x, y);
+            let agent = SchellingAgent::new(rng.random_range(0..groups));
+            s.spawn(agent, q, Vec2::ZERO);
+            s.cells.set_state(q, agent.state());
+        }
+    }
+}
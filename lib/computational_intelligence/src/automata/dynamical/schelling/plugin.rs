@@ -0,0 +1,55 @@
+//! Registers the Schelling model and samples its segregation statistics.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use engine_core::{events::AutomatonId, schedule::MainSet, state::AppState};
+
+use crate::{
+    agents::{AgentStepperPlugin, Habitat, HabitatRule},
+    analytics::{segregation::segregation_index, time_series::TimeSeries},
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{seed_schelling, SchellingAgent, SchellingParams, SCHELLING_ID};
+
+pub struct SchellingPlugin;
+impl Plugin for SchellingPlugin {
+    fn build(&self, app: &mut App) {
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        reg.register(SCHELLING_ID, HabitatRule::boxed());
+        reg.register_agents(SCHELLING_ID, seed_schelling);
+        reg.register_params(SCHELLING_ID, &SchellingParams::default());
+        app.insert_resource(reg);
+
+        app.add_plugins(AgentStepperPlugin::<SchellingAgent>::default())
+           .add_systems(
+               Update,
+               record_schelling_series
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+/// Per‑tick `segregation` index, share of `unhappy` agents and `moves`.
+fn record_schelling_series(
+    registry:   Res<AutomataRegistry>,
+    agents:     Query<(&SchellingAgent, &Habitat)>,
+    mut series: ResMut<TimeSeries>,
+) {
+    // [agents, unhappy, moved]
+    let mut counts: HashMap<AutomatonId, [u32; 3]> = HashMap::new();
+    for (agent, habitat) in &agents {
+        let c = counts.entry(habitat.0).or_default();
+        c[0] += 1;
+        c[1] += !agent.happy as u32;
+        c[2] += agent.moved as u32;
+    }
+
+    for info in registry.list().iter().filter(|i| i.name == SCHELLING_ID) {
+        let [n, unhappy, moved] = counts.get(&info.id).copied().unwrap_or_default();
+        series.record(info.id, "segregation", segregation_index(&info.grid));
+        series.record(info.id, "unhappy", if n > 0 { unhappy as f64 / n as f64 } else { 0.0 });
+        series.record(info.id, "moves", moved as f64);
+    }
+}
@@ -0,0 +1,312 @@
+//! 2‑D **Ising** and **q‑state Potts** models on a periodic square lattice.
+//!
+//! ```text
+//! Ising (q = 2, σ = ±1):  H = −J Σ⟨ij⟩ σᵢσⱼ − h Σᵢ σᵢ
+//! Potts (q ≥ 3):          H = −J Σ⟨ij⟩ δ(sᵢ, sⱼ) − h Σᵢ δ(sᵢ, 0)
+//! ```
+//!
+//! Three samplers, all measured in *sweeps* (≈ one update per site):
+//!
+//! * [`SpinUpdate::Metropolis`] – checkerboard single‑spin Metropolis;
+//! * [`SpinUpdate::HeatBath`]   – checkerboard heat bath (Glauber for q = 2);
+//! * [`SpinUpdate::Wolff`]      – Wolff clusters until ~N spins flipped; a
+//!   non‑zero field is handled by accepting each cluster flip with
+//!   `min(1, e^{−ΔE_h/T})`.
+//!
+//! Spin `s ∈ 0‥q` is shown as `Alive(s + 1)`.
+
+use bevy::prelude::*;
+use engine_core::{core::cell::CellState, engine::grid::DenseGrid};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod plugin;
+
+pub const ISING_ID: &str = "spin:ising";
+pub const POTTS_ID: &str = "spin:potts";
+
+/// Onsager’s critical temperature of the square‑lattice Ising model.
+pub const ISING_TC: f64 = 2.269_185_314;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpinUpdate {
+    #[default]
+    Metropolis,
+    HeatBath,
+    Wolff,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpinInit {
+    /// Infinite temperature.
+    #[default]
+    Random,
+    /// Zero temperature, every spin in state 0.
+    Ordered,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpinParams {
+    /// Number of spin states (2 = Ising).
+    pub q:           u8,
+    pub temperature: f64,
+    /// External field `h` (favours σ = +1 / state 0).
+    pub field:       f64,
+    /// Coupling `J` (> 0 ferromagnetic).
+    pub coupling:    f64,
+    pub update:      SpinUpdate,
+    pub sweeps:      u32,
+    pub init:        SpinInit,
+}
+
+impl Default for SpinParams {
+    fn default() -> Self {
+        Self {
+            q:           2,
+            temperature: ISING_TC,
+            field:       0.0,
+            coupling:    1.0,
+            update:      SpinUpdate::Metropolis,
+            sweeps:      1,
+            init:        SpinInit::Random,
+        }
+    }
+}
+
+impl SpinParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+
+    /// Three‑state Potts at its transition `T_c = 1/ln(1 + √3)`.
+    pub fn potts() -> Self {
+        Self { q: 3, temperature: 1.0 / (1.0 + 3f64.sqrt()).ln(), ..Self::default() }
+    }
+
+    fn q(&self) -> u8 { self.q.clamp(2, 255) }
+}
+
+/// Spins of one periodic `size.x × size.y` lattice.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpinLattice {
+    size:  UVec2,
+    spins: Vec<u8>,
+}
+
+impl SpinLattice {
+    pub fn ordered(size: UVec2) -> Self {
+        Self { size, spins: vec![0; (size.x * size.y) as usize] }
+    }
+
+    pub fn random(size: UVec2, q: u8, rng: &mut impl Rng) -> Self {
+        let n = (size.x * size.y) as usize;
+        Self { size, spins: (0..n).map(|_| rng.random_range(0..q.max(2))).collect() }
+    }
+
+    pub fn sites(&self) -> usize { self.spins.len() }
+    pub fn spins(&self) -> &[u8] { &self.spins }
+
+    /* ── geometry ─────────────────────────────────────────────────────── */
+
+    fn neighbours(&self, i: usize) -> [usize; 4] {
+        let (w, h) = (self.size.x as usize, self.size.y as usize);
+        let (x, y) = (i % w, i / w);
+        [
+            y * w + (x + 1) % w,
+            y * w + (x + w - 1) % w,
+            ((y + 1) % h) * w + x,
+            ((y + h - 1) % h) * w + x,
+        ]
+    }
+
+    /* ── energetics ───────────────────────────────────────────────────── */
+
+    fn bond(p: &SpinParams, a: u8, b: u8) -> f64 {
+        if p.q() == 2 {
+            let (sa, sb) = (1.0 - 2.0 * a as f64, 1.0 - 2.0 * b as f64);
+            -p.coupling * sa * sb
+        } else {
+            -p.coupling * (a == b) as u8 as f64
+        }
+    }
+
+    fn site_field(p: &SpinParams, s: u8) -> f64 {
+        if p.q() == 2 { -p.field * (1.0 - 2.0 * s as f64) } else { -p.field * (s == 0) as u8 as f64 }
+    }
+
+    /// Energy of site `i` if it held state `s` (bonds + field).
+    fn local_energy(&self, p: &SpinParams, i: usize, s: u8) -> f64 {
+        self.neighbours(i).iter().map(|&j| Self::bond(p, s, self.spins[j])).sum::<f64>()
+            + Self::site_field(p, s)
+    }
+
+    /// Energy per site.
+    ///
+    /// ```
+    /// use bevy::math::UVec2;
+    /// use computational_intelligence::automata::dynamical::spin::{SpinLattice, SpinParams};
+    ///
+    /// let ground = SpinLattice::ordered(UVec2::new(16, 16));
+    /// assert_eq!(ground.energy(&SpinParams::default()), -2.0);
+    /// assert_eq!(ground.magnetisation(&SpinParams::default()), 1.0);
+    /// ```
+    pub fn energy(&self, p: &SpinParams) -> f64 {
+        let bonds: f64 = (0..self.sites())
+            .map(|i| {
+                let [right, _, down, _] = self.neighbours(i);
+                Self::bond(p, self.spins[i], self.spins[right]) + Self::bond(p, self.spins[i], self.spins[down])
+            })
+            .sum();
+        let field: f64 = self.spins.iter().map(|&s| Self::site_field(p, s)).sum();
+        (bonds + field) / self.sites() as f64
+    }
+
+    /// Order parameter per site: `Σσ / N` for Ising, `(q·max ρₖ − 1)/(q − 1)`
+    /// for Potts.
+    pub fn magnetisation(&self, p: &SpinParams) -> f64 {
+        let n = self.sites() as f64;
+        let q = p.q();
+        if q == 2 {
+            return self.spins.iter().map(|&s| 1.0 - 2.0 * s as f64).sum::<f64>() / n;
+        }
+        let mut counts = vec![0usize; q as usize];
+        for &s in &self.spins {
+            counts[(s % q) as usize] += 1;
+        }
+        let max = counts.into_iter().max().unwrap_or(0) as f64 / n;
+        (q as f64 * max - 1.0) / (q as f64 - 1.0)
+    }
+
+    /* ── samplers ─────────────────────────────────────────────────────── */
+
+    fn other_state(q: u8, s: u8, rng: &mut impl Rng) -> u8 {
+        if q == 2 { 1 - s } else { (s + rng.random_range(1..q)) % q }
+    }
+
+    /// Updates the even sublattice, then the odd one; sites of one colour
+    /// only see the other, so each half‑sweep is order independent.
+    ///
+    /// That needs even sides: an odd periodic side wraps same‑coloured
+    /// sites onto each other, and a half‑sweep then depends on the visiting
+    /// order.  Sites are still updated in place one at a time, so every move
+    /// keeps detailed balance and the sampling stays correct – only the
+    /// sublattice independence is lost.
+    fn checkerboard<R: Rng>(&mut self, rng: &mut R, mut site: impl FnMut(&Self, usize, &mut R) -> u8) {
+        let w = self.size.x as usize;
+        for parity in 0..2 {
+            for i in 0..self.sites() {
+                if (i % w + i / w) % 2 != parity { continue; }
+                self.spins[i] = site(self, i, rng);
+            }
+        }
+    }
+
+    pub fn metropolis_sweep(&mut self, p: &SpinParams, rng: &mut impl Rng) {
+        let (q, t) = (p.q(), p.temperature.max(1e-9));
+        self.checkerboard(rng, |lat, i, rng| {
+            let old = lat.spins[i];
+            let new = Self::other_state(q, old, rng);
+            let de  = lat.local_energy(p, i, new) - lat.local_energy(p, i, old);
+            if de <= 0.0 || rng.random::<f64>() < (-de / t).exp() { new } else { old }
+        });
+    }
+
+    pub fn heat_bath_sweep(&mut self, p: &SpinParams, rng: &mut impl Rng) {
+        let (q, t) = (p.q(), p.temperature.max(1e-9));
+        let mut weights = vec![0.0; q as usize];
+        self.checkerboard(rng, |lat, i, rng| {
+            let energies: Vec<f64> = (0..q).map(|s| lat.local_energy(p, i, s)).collect();
+            let e_min = energies.iter().copied().fold(f64::INFINITY, f64::min);
+            for (w, e) in weights.iter_mut().zip(&energies) {
+                *w = (-(e - e_min) / t).exp();
+            }
+            let mut r = rng.random::<f64>() * weights.iter().sum::<f64>();
+            for (s, w) in weights.iter().enumerate() {
+                r -= w;
+                if r < 0.0 { return s as u8; }
+            }
+            q - 1
+        });
+    }
+
+    /// Grows and flips Wolff clusters until about `N` spins were flipped.
+    pub fn wolff_sweep(&mut self, p: &SpinParams, rng: &mut impl Rng) {
+        let (q, t) = (p.q(), p.temperature.max(1e-9));
+        // Ising bonds cost 2J to break, Potts bonds J.
+        let bond  = if q == 2 { 2.0 * p.coupling } else { p.coupling };
+        let p_add = 1.0 - (-bond / t).exp();
+        let n     = self.sites();
+
+        let mut in_cluster = vec![false; n];
+        let mut flipped    = 0;
+        while flipped < n {
+            let seed = rng.random_range(0..n);
+            let old  = self.spins[seed];
+            let new  = Self::other_state(q, old, rng);
+
+            let mut cluster = vec![seed];
+            let mut stack   = vec![seed];
+            in_cluster[seed] = true;
+            while let Some(i) = stack.pop() {
+                for j in self.neighbours(i) {
+                    if !in_cluster[j] && self.spins[j] == old && rng.random::<f64>() < p_add {
+                        in_cluster[j] = true;
+                        cluster.push(j);
+                        stack.push(j);
+                    }
+                }
+            }
+
+            let de_field = cluster.len() as f64 * (Self::site_field(p, new) - Self::site_field(p, old));
+            if de_field <= 0.0 || rng.random::<f64>() < (-de_field / t).exp() {
+                for &i in &cluster {
+                    self.spins[i] = new;
+                }
+            }
+            for &i in &cluster {
+                in_cluster[i] = false;
+            }
+            flipped += cluster.len();
+        }
+    }
+
+    pub fn sweep(&mut self, p: &SpinParams, rng: &mut impl Rng) {
+        match p.update {
+            SpinUpdate::Metropolis => self.metropolis_sweep(p, rng),
+            SpinUpdate::HeatBath   => self.heat_bath_sweep(p, rng),
+            SpinUpdate::Wolff      => self.wolff_sweep(p, rng),
+        }
+    }
+
+    /* ── grid mapping ─────────────────────────────────────────────────── */
+
+    pub fn from_grid(grid: &DenseGrid) -> Self {
+        let spins = grid.cells.iter()
+            .map(|c| match c.state {
+                CellState::Alive(v) => v.saturating_sub(1),
+                CellState::Dead     => 0,
+            })
+            .collect();
+        Self { size: grid.size, spins }
+    }
+
+    pub fn write_grid(&self, grid: &mut DenseGrid) {
+        for (cell, &s) in grid.cells.iter_mut().zip(&self.spins) {
+            cell.state = CellState::Alive(s.saturating_add(1));
+        }
+    }
+}
+
+/// Ising: spin up light, spin down dark.
+pub fn ising_palette() -> Vec<Color> {
+    vec![Color::srgb(0.95, 0.95, 0.90), Color::srgb(0.10, 0.20, 0.45)]
+}
+
+/// Evenly spaced hues for up to 12 Potts states (higher states reuse the last).
+pub fn potts_palette() -> Vec<Color> {
+    (0..12).map(|k| Color::hsl(k as f32 * 360.0 / 12.0, 0.75, 0.55)).collect()
+}
@@ -0,0 +1,107 @@
+//! Registers the Ising / Potts presets, seeds and sweeps them, and samples
+//! the thermodynamic observables.
+
+use bevy::prelude::*;
+use engine_core::{
+    engine::grid::GridBackend, events::AutomatonAdded, schedule::MainSet, state::AppState,
+};
+
+use crate::{
+    agents::HabitatRule,
+    analytics::{
+        thermodynamics::{specific_heat, susceptibility},
+        time_series::TimeSeries,
+    },
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{
+    ising_palette, potts_palette, SpinInit, SpinLattice, SpinParams, ISING_ID, POTTS_ID,
+};
+
+/// Most recent samples the fluctuation estimators average over.
+const WINDOW: usize = 256;
+
+fn is_spin(name: &str) -> bool {
+    name == ISING_ID || name == POTTS_ID
+}
+
+pub struct SpinModelsPlugin;
+impl Plugin for SpinModelsPlugin {
+    fn build(&self, app: &mut App) {
+        // Checkerboard sweeps and Wolff clusters need global random updates:
+        // the lattice is stepped as a whole below.
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        for (id, defaults, palette) in [
+            (ISING_ID, SpinParams::default(), ising_palette()),
+            (POTTS_ID, SpinParams::potts(), potts_palette()),
+        ] {
+            reg.register(id, HabitatRule::boxed());
+            reg.register_params(id, &defaults);
+            reg.register_palette(id, palette);
+        }
+        app.insert_resource(reg);
+
+        app.add_systems(Update, seed_spins)
+           .add_systems(
+               Update,
+               sweep_spins
+                   .in_set(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           )
+           .add_systems(
+               Update,
+               record_spin_series
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+fn seed_spins(mut added: EventReader<AutomatonAdded>, mut registry: ResMut<AutomataRegistry>) {
+    let mut rng = rand::rng();
+    for ev in added.read() {
+        let Some(info) = registry.get_mut(ev.id) else { continue };
+        if !is_spin(&info.name) { continue; }
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        let p = SpinParams::from_value(&info.params);
+        let lattice = match p.init {
+            SpinInit::Random  => SpinLattice::random(grid.size, p.q, &mut rng),
+            SpinInit::Ordered => SpinLattice::ordered(grid.size),
+        };
+        lattice.write_grid(grid);
+    }
+}
+
+fn sweep_spins(mut registry: ResMut<AutomataRegistry>) {
+    let mut rng = rand::rng();
+    for info in registry.iter_mut().filter(|i| is_spin(&i.name)) {
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        let p = SpinParams::from_value(&info.params);
+        let mut lattice = SpinLattice::from_grid(grid);
+        for _ in 0..p.sweeps.max(1) {
+            lattice.sweep(&p, &mut rng);
+        }
+        lattice.write_grid(grid);
+    }
+}
+
+/// Per‑tick `energy` and `magnetisation` per site, plus `susceptibility` and
+/// `specific_heat` over the last [`WINDOW`] samples.
+fn record_spin_series(registry: Res<AutomataRegistry>, mut series: ResMut<TimeSeries>) {
+    for info in registry.list().iter().filter(|i| is_spin(&i.name)) {
+        let GridBackend::Dense(grid) = &info.grid else { continue };
+        let p       = SpinParams::from_value(&info.params);
+        let lattice = SpinLattice::from_grid(grid);
+        series.record(info.id, "energy", lattice.energy(&p));
+        series.record(info.id, "magnetisation", lattice.magnetisation(&p));
+
+        let window = |name: &str| -> Vec<f64> {
+            let Some(s) = series.get(info.id, name) else { return Vec::new() };
+            s.iter().skip(s.len().saturating_sub(WINDOW)).map(|(_, v)| v).collect()
+        };
+        let (e, m) = (window("energy"), window("magnetisation"));
+        let n = lattice.sites();
+        series.record(info.id, "susceptibility", susceptibility(&m, n, p.temperature));
+        series.record(info.id, "specific_heat", specific_heat(&e, n, p.temperature));
+    }
+}
@@ -0,0 +1,404 @@
+//! Epstein & Axtell’s **Sugarscape** (Growing Artificial Societies, 1996).
+//!
+//! A landscape of two renewable resources – *sugar* and (optionally)
+//! *spice* – is populated by agents with genetic vision, metabolism and
+//! lifespan.  Implemented rules:
+//!
+//! | rule | behaviour                                                       |
+//! |------|-----------------------------------------------------------------|
+//! | G_α  | every site regrows `regrowth` units up to its capacity          |
+//! | M    | look along the four lattice axes up to *vision*, move to the    |
+//! |      | free site with the best welfare (nearest on ties), harvest all  |
+//! | S    | fertile neighbours of opposite sex produce a child on a free    |
+//! |      | adjacent site; each parent gives half its initial endowment     |
+//! | I    | on death, wealth is split among the living children             |
+//! | T    | neighbours trade sugar ↔ spice at the geometric mean of their   |
+//! |      | MRS while both gain welfare                                     |
+//! | R    | optional replacement of every dead agent by a random newcomer   |
+//!
+//! Resources live in the slice’s `CellLayers` (`"sugar"`, `"sugar_cap"`,
+//! `"spice"`, `"spice_cap"`); cells only show where agents stand.
+
+use bevy::prelude::*;
+use engine_core::core::cell::CellState;
+use rand::{rngs::ThreadRng, Rng};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::agents::{Agent, AgentCtx, AgentOutcome, AgentSpawner, CellView};
+
+pub mod plugin;
+
+/// Rule id under which the model is registered.
+pub const SUGARSCAPE_ID: &str = "sugarscape";
+
+pub const SUGAR:     &str = "sugar";
+pub const SUGAR_CAP: &str = "sugar_cap";
+pub const SPICE:     &str = "spice";
+pub const SPICE_CAP: &str = "spice_cap";
+
+const VON_NEUMANN: [IVec2; 4] = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y];
+
+/* ───────────────────────────── parameters ──────────────────────────── */
+
+/// Model switches and genetic ranges (inclusive `[min, max]`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SugarscapeParams {
+    pub agents:       u32,
+    /// Side of one landscape tile; the slice repeats it so distances stay
+    /// on the classic 50 × 50 scale that vision ∈ 1‥6 was designed for.
+    pub tile:         u32,
+    pub spice:        bool,
+    pub regrowth:     f32,
+    pub max_capacity: f32,
+    pub vision:       [i32; 2],
+    pub metabolism:   [f32; 2],
+    pub endowment:    [f32; 2],
+    pub max_age:      [u32; 2],
+    pub reproduction: bool,
+    pub inheritance:  bool,
+    pub trade:        bool,
+    pub replacement:  bool,
+}
+
+impl Default for SugarscapeParams {
+    fn default() -> Self {
+        Self {
+            agents:       4_000,
+            tile:         64,
+            spice:        true,
+            regrowth:     1.0,
+            max_capacity: 4.0,
+            vision:       [1, 6],
+            metabolism:   [1.0, 4.0],
+            endowment:    [5.0, 25.0],
+            max_age:      [60, 100],
+            reproduction: true,
+            inheritance:  true,
+            trade:        true,
+            replacement:  false,
+        }
+    }
+}
+
+impl SugarscapeParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+}
+
+/* ─────────────────────────────── agent ─────────────────────────────── */
+
+#[derive(Component, Clone, Debug)]
+pub struct SugarAgent {
+    pub sugar:            f32,
+    pub spice:            f32,
+    pub metabolism_sugar: f32,
+    pub metabolism_spice: f32,
+    pub vision:           i32,
+    pub age:              u32,
+    pub max_age:          u32,
+    pub female:           bool,
+    /// Initial endowment – the reproduction threshold and the amount a
+    /// parent hands down (halved) to each child.
+    pub endowment_sugar:  f32,
+    pub endowment_spice:  f32,
+    pub children:         Vec<Entity>,
+    /// Trades completed during the last tick.
+    pub trades:           u32,
+}
+
+impl SugarAgent {
+    /// Newcomer with genes drawn uniformly from the ranges in `p`.
+    pub fn random(rng: &mut ThreadRng, p: &SugarscapeParams) -> Self {
+        let sugar = rng.random_range(p.endowment[0]..=p.endowment[1]);
+        let spice = if p.spice { rng.random_range(p.endowment[0]..=p.endowment[1]) } else { 0.0 };
+        Self {
+            sugar,
+            spice,
+            metabolism_sugar: rng.random_range(p.metabolism[0]..=p.metabolism[1]).round(),
+            metabolism_spice: if p.spice { rng.random_range(p.metabolism[0]..=p.metabolism[1]).round() } else { 0.0 },
+            vision:           rng.random_range(p.vision[0]..=p.vision[1]),
+            age:              0,
+            max_age:          rng.random_range(p.max_age[0]..=p.max_age[1]),
+            female:           rng.random_bool(0.5),
+            endowment_sugar:  sugar,
+            endowment_spice:  spice,
+            children:         Vec::new(),
+            trades:           0,
+        }
+    }
+
+    pub fn wealth(&self) -> f32 { self.sugar + self.spice }
+
+    /// Cobb–Douglas welfare of holding `sugar` / `spice`.
+    pub fn welfare(&self, sugar: f32, spice: f32, with_spice: bool) -> f32 {
+        if !with_spice { return sugar; }
+        let mt = self.metabolism_sugar + self.metabolism_spice;
+        if mt <= 0.0 { return sugar + spice; }
+        sugar.max(0.0).powf(self.metabolism_sugar / mt) * spice.max(0.0).powf(self.metabolism_spice / mt)
+    }
+
+    /// Marginal rate of substitution (spice per unit of sugar).
+    pub fn mrs(&self) -> f32 {
+        let time_sugar = self.sugar / self.metabolism_sugar.max(f32::EPSILON);
+        let time_spice = self.spice / self.metabolism_spice.max(f32::EPSILON);
+        time_spice / time_sugar.max(f32::EPSILON)
+    }
+
+    fn fertile(&self) -> bool {
+        let last = if self.female { 50 } else { 60 };
+        (12..=last).contains(&self.age)
+            && self.sugar >= self.endowment_sugar
+            && self.spice >= self.endowment_spice
+    }
+
+    fn starved(&self, with_spice: bool) -> bool {
+        self.sugar <= 0.0 || (with_spice && self.spice <= 0.0)
+    }
+
+    /* ── rule M ──────────────────────────────────────────────────────── */
+
+    fn best_site(&self, ctx: &mut AgentCtx, p: &SugarscapeParams) -> IVec2 {
+        let pos    = ctx.pos();
+        let score  = |ctx: &AgentCtx, q: IVec2| {
+            self.welfare(self.sugar + ctx.cells.layer(SUGAR, q), self.spice + ctx.cells.layer(SPICE, q), p.spice)
+        };
+        let mut best = (score(ctx, pos), pos);
+
+        let mut dirs = VON_NEUMANN;
+        dirs.swap(0, ctx.rng.random_range(0..4));
+        dirs.swap(1, ctx.rng.random_range(1..4));
+        for d in 1..=self.vision {
+            for dir in dirs {
+                let q = ctx.cells.wrap(pos + dir * d);
+                if !ctx.is_free(q) { continue; }
+                let w = score(ctx, q);
+                // strictly better only – ties keep the nearer site
+                if w > best.0 {
+                    best = (w, q);
+                }
+            }
+        }
+        best.1
+    }
+
+    /* ── rule T ──────────────────────────────────────────────────────── */
+
+    fn trade_with(&mut self, other: &mut SugarAgent) -> u32 {
+        let mut trades = 0;
+        for _ in 0..50 {
+            let (mrs_a, mrs_b) = (self.mrs(), other.mrs());
+            if (mrs_a - mrs_b).abs() < 1e-3 { break; }
+
+            // Higher MRS ⇒ values sugar more ⇒ buys sugar, pays spice.
+            let price = (mrs_a * mrs_b).sqrt();
+            let (sugar_qty, spice_qty) = if price >= 1.0 { (1.0, price) } else { (1.0 / price, 1.0) };
+            let dir = if mrs_a > mrs_b { 1.0 } else { -1.0 };
+
+            let mut a = self.clone();
+            let mut b = other.clone();
+            a.sugar += dir * sugar_qty; a.spice -= dir * spice_qty;
+            b.sugar -= dir * sugar_qty; b.spice += dir * spice_qty;
+
+            let gains = a.welfare(a.sugar, a.spice, true) > self.welfare(self.sugar, self.spice, true)
+                && b.welfare(b.sugar, b.spice, true) > other.welfare(other.sugar, other.spice, true);
+            let no_cross = (a.mrs() > b.mrs()) == (mrs_a > mrs_b);
+            if !gains || !no_cross || a.starved(true) || b.starved(true) { break; }
+
+            (self.sugar, self.spice)   = (a.sugar, a.spice);
+            (other.sugar, other.spice) = (b.sugar, b.spice);
+            trades += 1;
+        }
+        trades
+    }
+
+    /* ── rule S ──────────────────────────────────────────────────────── */
+
+    fn reproduce(&mut self, ctx: &mut AgentCtx, pos: IVec2) {
+        for dir in VON_NEUMANN {
+            if !self.fertile() { return; }
+            let Some(mate_e) = ctx.occupant(pos + dir) else { continue };
+            let Some(mate) = ctx.agent::<SugarAgent>(mate_e).cloned() else { continue };
+            if mate.female == self.female || !mate.fertile() { continue; }
+
+            let free = VON_NEUMANN.iter()
+                .flat_map(|d| [pos + *d, pos + dir + *d])
+                .map(|q| ctx.cells.wrap(q))
+                .find(|q| ctx.is_free(*q));
+            let Some(site) = free else { continue };
+
+            let pick = |rng: &mut ThreadRng, a: f32, b: f32| if rng.random_bool(0.5) { a } else { b };
+            let (ms, mp) = (self.endowment_sugar / 2.0, self.endowment_spice / 2.0);
+            let (fs, fp) = (mate.endowment_sugar / 2.0, mate.endowment_spice / 2.0);
+            let child = SugarAgent {
+                sugar:            ms + fs,
+                spice:            mp + fp,
+                metabolism_sugar: pick(ctx.rng, self.metabolism_sugar, mate.metabolism_sugar),
+                metabolism_spice: pick(ctx.rng, self.metabolism_spice, mate.metabolism_spice),
+                vision:           if ctx.rng.random_bool(0.5) { self.vision } else { mate.vision },
+                age:              0,
+                max_age:          if ctx.rng.random_bool(0.5) { self.max_age } else { mate.max_age },
+                female:           ctx.rng.random_bool(0.5),
+                endowment_sugar:  ms + fs,
+                endowment_spice:  mp + fp,
+                children:         Vec::new(),
+                trades:           0,
+            };
+            let child_e = ctx.spawn(child, site, Vec2::ZERO);
+
+            self.sugar -= ms;
+            self.spice -= mp;
+            self.children.push(child_e);
+            if let Some(mate) = ctx.agent_mut::<SugarAgent>(mate_e) {
+                mate.sugar -= fs;
+                mate.spice -= fp;
+                mate.children.push(child_e);
+            }
+        }
+    }
+
+    /* ── rules I + R ─────────────────────────────────────────────────── */
+
+    fn die(&self, ctx: &mut AgentCtx, p: &SugarscapeParams) -> AgentOutcome {
+        if p.inheritance {
+            let heirs: Vec<Entity> = self.children.iter()
+                .copied()
+                .filter(|e| ctx.agent::<SugarAgent>(*e).is_some())
+                .collect();
+            let n = heirs.len() as f32;
+            for e in heirs {
+                if let Some(child) = ctx.agent_mut::<SugarAgent>(e) {
+                    child.sugar += self.sugar.max(0.0) / n;
+                    child.spice += self.spice.max(0.0) / n;
+                }
+            }
+        }
+        if p.replacement && let Some(size) = ctx.cells.size() {
+            for _ in 0..64 {
+                let q = IVec2::new(ctx.rng.random_range(0..size.x as i32), ctx.rng.random_range(0..size.y as i32));
+                if ctx.is_free(q) {
+                    let newcomer = SugarAgent::random(ctx.rng, p);
+                    ctx.spawn(newcomer, q, Vec2::ZERO);
+                    break;
+                }
+            }
+        }
+        AgentOutcome::Die
+    }
+}
+
+impl Agent for SugarAgent {
+    type Params = SugarscapeParams;
+
+    /// Rule G_α, then wipe the cells – living agents repaint themselves.
+    fn habitat_tick(cells: &mut CellView, p: &SugarscapeParams) {
+        for (res, cap) in [(SUGAR, SUGAR_CAP), (SPICE, SPICE_CAP)] {
+            let Some(caps) = cells.layers.plane(cap).map(<[f32]>::to_vec) else { continue };
+            if let Some(level) = cells.layers.plane_mut(res) {
+                for (v, c) in level.iter_mut().zip(caps) {
+                    *v = (*v + p.regrowth).min(c);
+                }
+            }
+        }
+        if let Some(size) = cells.size() {
+            for y in 0..size.y as i32 {
+                for x in 0..size.x as i32 {
+                    cells.set_state(IVec2::new(x, y), CellState::Dead);
+                }
+            }
+        }
+    }
+
+    fn step(&mut self, ctx: &mut AgentCtx, p: &SugarscapeParams) -> AgentOutcome {
+        self.age   += 1;
+        self.trades = 0;
+
+        // ── M: move + harvest ──────────────────────────────────────────
+        let site = self.best_site(ctx, p);
+        ctx.try_move_to(site);
+        let here = ctx.pos();
+        self.sugar += ctx.cells.layer(SUGAR, here);
+        ctx.cells.set_layer(SUGAR, here, 0.0);
+        if p.spice {
+            self.spice += ctx.cells.layer(SPICE, here);
+            ctx.cells.set_layer(SPICE, here, 0.0);
+        }
+
+        // ── metabolism + ageing ────────────────────────────────────────
+        self.sugar -= self.metabolism_sugar;
+        if p.spice { self.spice -= self.metabolism_spice; }
+        if self.starved(p.spice) || self.age > self.max_age {
+            return self.die(ctx, p);
+        }
+
+        // ── T: trade with lattice neighbours ───────────────────────────
+        if p.trade && p.spice {
+            for dir in VON_NEUMANN {
+                let Some(e) = ctx.occupant(here + dir) else { continue };
+                if let Some(other) = ctx.agent_mut::<SugarAgent>(e) {
+                    self.trades += self.trade_with(other);
+                }
+            }
+        }
+
+        // ── S: reproduction ────────────────────────────────────────────
+        if p.reproduction {
+            self.reproduce(ctx, here);
+        }
+
+        ctx.cells.set_state(here, CellState::Alive(1));
+        AgentOutcome::Live
+    }
+}
+
+/* ─────────────────────────────── seeding ───────────────────────────── */
+
+/// Two sugar peaks (NW / SE), two spice peaks (NE / SW) per landscape
+/// tile, capacities in integer terraces `0 ..= max_capacity`; agents are
+/// scattered uniformly.
+pub fn seed_sugarscape(s: &mut AgentSpawner) {
+    let p    = SugarscapeParams::from_value(s.params);
+    let Some(size) = s.cells.size() else { return };
+    let tile   = p.tile.clamp(8, size.min_element()) as i32;
+    let radius = tile as f32 * 0.35;
+
+    let peak_field = |q: IVec2, peaks: [Vec2; 2]| {
+        let local = (q % tile).as_vec2();
+        peaks.iter()
+            .map(|c| (p.max_capacity * (1.0 - local.distance(*c * tile as f32) / radius)).ceil())
+            .fold(0.0_f32, f32::max)
+    };
+    let sugar_peaks = [Vec2::new(0.3, 0.7), Vec2::new(0.7, 0.3)];
+    let spice_peaks = [Vec2::new(0.3, 0.3), Vec2::new(0.7, 0.7)];
+
+    for (name, cap_name) in [(SUGAR, SUGAR_CAP), (SPICE, SPICE_CAP)] {
+        s.cells.layers.add(name, 0.0);
+        s.cells.layers.add(cap_name, 0.0);
+    }
+    for y in 0..size.y as i32 {
+        for x in 0..size.x as i32 {
+            let q     = IVec2::new(x, y);
+            let sugar = peak_field(q, sugar_peaks);
+            s.cells.set_layer(SUGAR_CAP, q, sugar);
+            s.cells.set_layer(SUGAR, q, sugar);
+            if p.spice {
+                let spice = peak_field(q, spice_peaks);
+                s.cells.set_layer(SPICE_CAP, q, spice);
+                s.cells.set_layer(SPICE, q, spice);
+            }
+        }
+    }
+
+    let mut rng   = rand::rng();
+    let mut taken = std::collections::HashSet::new();
+    let target    = p.agents.min(size.x * size.y);
+    while (taken.len() as u32) < target {
+        let q = IVec2::new(rng.random_range(0..size.x as i32), rng.random_range(0..size.y as i32));
+        if taken.insert(q) {
+            s.spawn(SugarAgent::random(&mut rng, &p), q, Vec2::ZERO);
+            s.cells.set_state(q, CellState::Alive(1));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Registers Sugarscape and samples its population statistics.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use engine_core::{events::AutomatonId, schedule::MainSet, state::AppState};
+
+use crate::{
+    agents::{AgentStepperPlugin, Habitat, HabitatRule},
+    analytics::{inequality::gini, time_series::TimeSeries},
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{seed_sugarscape, SugarAgent, SugarscapeParams, SUGARSCAPE_ID};
+
+pub struct SugarscapePlugin;
+impl Plugin for SugarscapePlugin {
+    fn build(&self, app: &mut App) {
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        reg.register(SUGARSCAPE_ID, HabitatRule::boxed());
+        reg.register_agents(SUGARSCAPE_ID, seed_sugarscape);
+        reg.register_params(SUGARSCAPE_ID, &SugarscapeParams::default());
+        app.insert_resource(reg);
+
+        app.add_plugins(AgentStepperPlugin::<SugarAgent>::default())
+           .add_systems(
+               Update,
+               record_sugarscape_series
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+/// Per‑tick series: `population`, `gini` (sugar + spice wealth),
+/// `mean_vision`, `mean_metabolism` and `trades`.
+fn record_sugarscape_series(
+    registry:   Res<AutomataRegistry>,
+    agents:     Query<(&SugarAgent, &Habitat)>,
+    mut series: ResMut<TimeSeries>,
+) {
+    let mut by_habitat: HashMap<AutomatonId, Vec<&SugarAgent>> = HashMap::new();
+    for (agent, habitat) in &agents {
+        by_habitat.entry(habitat.0).or_default().push(agent);
+    }
+
+    for info in registry.list().iter().filter(|i| i.name == SUGARSCAPE_ID) {
+        let pop    = by_habitat.get(&info.id).map_or(&[][..], Vec::as_slice);
+        let n      = pop.len() as f64;
+        let mean   = |f: fn(&SugarAgent) -> f64| if n > 0.0 { pop.iter().map(|a| f(a)).sum::<f64>() / n } else { 0.0 };
+        let wealth: Vec<f64> = pop.iter().map(|a| a.wealth() as f64).collect();
+
+        series.record(info.id, "population", n);
+        series.record(info.id, "gini", gini(&wealth));
+        series.record(info.id, "mean_vision", mean(|a| a.vision as f64));
+        series.record(info.id, "mean_metabolism", mean(|a| (a.metabolism_sugar + a.metabolism_spice) as f64));
+        series.record(info.id, "trades", pop.iter().map(|a| a.trades as f64).sum());
+    }
+}
@@ -1,8 +1,14 @@
 //! Simplified ant colony simulation with pheromones (foraging behavior).
+//!
+//! [`AntColony`] is the self‑contained version; [`ForagerAnt`] runs the same
+//! behaviour as grid agents on an automaton slice (`"swarm:ants"`).
 
-use bevy::math::IVec2;
-use engine_core::core::{dim::Dim2, Dim};
+use bevy::{math::IVec2, prelude::{Component, Vec2}};
+use engine_core::core::{cell::CellState, dim::Dim2, Dim};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::agents::{Agent, AgentCtx, AgentOutcome, AgentSpawner, CellView};
 
 pub struct Ant {
     pub pos: IVec2,
@@ -70,3 +76,152 @@ impl AntColony {
         }
     }
 }
+
+/* ─────────────────────────── grid‑agent version ─────────────────────── */
+
+/// Foraging parameters for [`ForagerAnt`] habitats.
+///
+/// Layers used: `"pheromone"` (trail strength) and `"food"` (units left).
+/// The nest sits at the slice centre.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AntParams {
+    pub ants:        u32,
+    /// Fraction of pheromone lost per tick.
+    pub evaporation: f32,
+    /// Pheromone dropped per step by a loaded ant.
+    pub deposit:     f32,
+    /// Probability of ignoring the trail and moving randomly.
+    pub wander:      f64,
+}
+
+impl Default for AntParams {
+    fn default() -> Self {
+        Self { ants: 80, evaporation: 0.01, deposit: 1.0, wander: 0.1 }
+    }
+}
+
+/// An [`Ant`] living on an automaton slice; position & heading are ECS
+/// components, only the cargo flag is internal state.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ForagerAnt {
+    pub carrying_food: bool,
+}
+
+const PHEROMONE: &str = "pheromone";
+const FOOD:      &str = "food";
+
+fn nest_of(cells: &CellView) -> IVec2 {
+    cells.size().map_or(IVec2::ZERO, |s| s.as_ivec2() / 2)
+}
+
+impl Agent for ForagerAnt {
+    type Params = AntParams;
+
+    /// Evaporate the trail and repaint: food / trail cells alive, rest dead.
+    fn habitat_tick(cells: &mut CellView, p: &AntParams) {
+        if let Some(trail) = cells.layers.plane_mut(PHEROMONE) {
+            for v in trail.iter_mut() {
+                *v *= 1.0 - p.evaporation;
+                if *v < 0.001 { *v = 0.0; }
+            }
+        }
+        let Some(size) = cells.size() else { return };
+        for y in 0..size.y as i32 {
+            for x in 0..size.x as i32 {
+                let q = IVec2::new(x, y);
+                let s = if cells.layer(FOOD, q) > 0.0 {
+                    CellState::Alive(3)
+                } else if cells.layer(PHEROMONE, q) > 0.05 {
+                    CellState::Alive(1)
+                } else {
+                    CellState::Dead
+                };
+                cells.set_state(q, s);
+            }
+        }
+        let nest = nest_of(cells);
+        cells.set_state(nest, CellState::Alive(4));
+    }
+
+    fn sense(&self, ctx: &mut AgentCtx, _p: &AntParams) {
+        let pos = ctx.pos();
+        ctx.sensors.clear();
+        for off in Dim2::NEIGHBOUR_OFFSETS.iter() {
+            ctx.sensors.push(ctx.cells.layer(PHEROMONE, pos + *off));
+        }
+    }
+
+    fn step(&mut self, ctx: &mut AgentCtx, p: &AntParams) -> AgentOutcome {
+        let nest = nest_of(&ctx.cells);
+        let pos  = ctx.pos();
+
+        let dir = if self.carrying_food {
+            // Head home, dropping pheromone on the way.
+            ctx.cells.delta(pos, nest).clamp(IVec2::NEG_ONE, IVec2::ONE)
+        } else {
+            // Follow the strongest trail (sensed above) or wander.
+            let best = ctx.sensors.iter().enumerate()
+                .filter(|(_, v)| **v > 0.0)
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(i, _)| Dim2::NEIGHBOUR_OFFSETS[i]);
+            match best {
+                Some(d) if !ctx.rng.random_bool(p.wander) => d,
+                _ => Dim2::NEIGHBOUR_OFFSETS[ctx.rng.random_range(0..Dim2::NEIGHBOUR_OFFSETS.len())],
+            }
+        };
+        ctx.move_to(pos + dir);
+        ctx.heading = dir.as_vec2();
+
+        let here = ctx.pos();
+        if self.carrying_food {
+            let trail = ctx.cells.layer(PHEROMONE, here);
+            ctx.cells.set_layer(PHEROMONE, here, trail + p.deposit);
+            if here == nest { self.carrying_food = false; }
+        } else {
+            let food = ctx.cells.layer(FOOD, here);
+            if food > 0.0 {
+                ctx.cells.set_layer(FOOD, here, food - 1.0);
+                self.carrying_food = true;
+            }
+        }
+        ctx.cells.set_state(here, CellState::Alive(2));
+        AgentOutcome::Live
+    }
+}
+
+/// Nest in the centre, four food patches, ants one per cell in square rings
+/// around the nest so the occupancy map starts with every ant in it.
+pub fn seed_ant_colony(s: &mut AgentSpawner) {
+    let p     = serde_json::from_value::<AntParams>(s.params.clone()).unwrap_or_default();
+    let nest  = nest_of(&s.cells);
+    let reach = s.cells.size().map_or(48, |sz| sz.x.min(sz.y) as i32 / 3);
+
+    s.cells.layers.add(PHEROMONE, 0.0);
+    s.cells.layers.add(FOOD, 0.0);
+    for corner in [IVec2::new(1, 1), IVec2::new(-1, 1), IVec2::new(1, -1), IVec2::new(-1, -1)] {
+        let centre = nest + corner * reach;
+        for dy in -5..=5 {
+            for dx in -5..=5 {
+                if dx * dx + dy * dy <= 25 {
+                    s.cells.set_layer(FOOD, centre + IVec2::new(dx, dy), 20.0);
+                }
+            }
+        }
+    }
+    let target    = p.ants.min(s.cells.size().map_or(u32::MAX, |sz| sz.x * sz.y));
+    let mut taken = std::collections::HashSet::new();
+    let mut ring  = 0i32;
+    while (taken.len() as u32) < target {
+        for dy in -ring..=ring {
+            for dx in -ring..=ring {
+                if dx.abs().max(dy.abs()) != ring || taken.len() as u32 == target { continue; }
+                let q = s.cells.wrap(nest + IVec2::new(dx, dy));
+                if taken.insert(q) {
+                    s.spawn(ForagerAnt::default(), q, Vec2::ZERO);
+                }
+            }
+        }
+        ring += 1;
+    }
+}
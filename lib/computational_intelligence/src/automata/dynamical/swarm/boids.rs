@@ -1,7 +1,17 @@
 //! Boids flocking algorithm implementation.
+//!
+//! [`step_boids`] advances a free‑standing flock; the [`Agent`] impl lets the
+//! same rules run on an automaton slice (`"swarm:boids"`), with the slice as
+//! a torus and every boid painting the cell it occupies.
 
-use bevy::prelude::Vec2;
+use bevy::prelude::{Component, IVec2, Vec2};
+use engine_core::core::cell::CellState;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 
+use crate::agents::{Agent, AgentCtx, AgentOutcome, AgentSpawner, CellView};
+
+#[derive(Component, Clone, Copy, Debug)]
 pub struct Boid {
     pub position: Vec2,
     pub velocity: Vec2,
@@ -16,50 +26,149 @@ impl Boid {
     }
 }
 
+/// Flocking weights.  Defaults are tuned for boids living on a cell grid.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoidParams {
+    pub boids:               u32,
+    pub cohesion:            f32,
+    pub alignment:           f32,
+    pub separation:          f32,
+    pub separation_distance: f32,
+    /// Neighbourhood radius.
+    pub vision:              f32,
+    pub max_speed:           f32,
+}
+
+impl Default for BoidParams {
+    fn default() -> Self {
+        Self {
+            boids:               150,
+            cohesion:            0.005,
+            alignment:           0.05,
+            separation:          1.0,
+            separation_distance: 3.0,
+            vision:              12.0,
+            max_speed:           1.5,
+        }
+    }
+}
+
+/// New velocity of a boid given its neighbours as `(offset, velocity)`
+/// pairs (offset = neighbour position − own position).
+fn flock_velocity(
+    velocity:   Vec2,
+    neighbours: impl Iterator<Item = (Vec2, Vec2)>,
+    p:          &BoidParams,
+) -> Vec2 {
+    let mut velocity   = velocity;
+    let mut centre     = Vec2::ZERO;
+    let mut avg_vel    = Vec2::ZERO;
+    let mut count      = 0;
+    let mut separation = Vec2::ZERO;
+    for (diff, vel) in neighbours {
+        let dist_sq = diff.length_squared();
+        if dist_sq < p.vision * p.vision {
+            centre  += diff;
+            avg_vel += vel;
+            count   += 1;
+        }
+        if dist_sq < p.separation_distance * p.separation_distance && dist_sq > 0.0 {
+            // steer away from too-close boid
+            separation -= diff.normalize_or_zero() / diff.length();
+        }
+    }
+    if count > 0 {
+        centre  /= count as f32;
+        avg_vel /= count as f32;
+        // Cohesion: steer towards center of neighbors
+        velocity += centre * p.cohesion;
+        // Alignment: adjust velocity towards average velocity of neighbors
+        velocity += (avg_vel - velocity) * p.alignment;
+    }
+    // Separation: avoid crowding
+    velocity += separation * p.separation;
+    // Limit speed to a maximum
+    if velocity.length() > p.max_speed {
+        velocity = velocity.normalize() * p.max_speed;
+    }
+    velocity
+}
+
 /// Advances the boids simulation by one step using standard flocking rules.
 pub fn step_boids(boids: &mut [Boid], cohesion_weight: f32, alignment_weight: f32, separation_weight: f32, separation_distance: f32) {
     let n = boids.len();
     if n == 0 { return; }
-    // Make a copy of current velocities to use in calculations
-    let current_vel: Vec<Vec2> = boids.iter().map(|b| b.velocity).collect();
-    for i in 0..n {
-        let mut center = Vec2::ZERO;
-        let mut avg_vel = Vec2::ZERO;
-        let mut count = 0;
-        let mut separation = Vec2::ZERO;
-        for j in 0..n {
-            if i == j { continue; }
-            let diff = boids[j].position - boids[i].position;
-            let dist_sq = diff.length_squared();
-            let vision_range = 100.0; // neighborhood radius
-            if dist_sq < vision_range * vision_range {
-                center += boids[j].position;
-                avg_vel += current_vel[j];
-                count += 1;
-            }
-            if dist_sq < separation_distance * separation_distance && dist_sq > 0.0 {
-                // steer away from too-close boid j
-                separation -= diff.normalize_or_zero() / diff.length();
-            }
-        }
-        if count > 0 {
-            center /= count as f32;
-            avg_vel /= count as f32;
-            // Cohesion: steer towards center of neighbors
-            boids[i].velocity += (center - boids[i].position) * cohesion_weight;
-            // Alignment: adjust velocity towards average velocity of neighbors
-            boids[i].velocity += (avg_vel - boids[i].velocity) * alignment_weight;
-        }
-        // Separation: avoid crowding
-        boids[i].velocity += separation * separation_weight;
-        // Limit speed to a maximum
-        let max_speed = 5.0;
-        if boids[i].velocity.length() > max_speed {
-            boids[i].velocity = boids[i].velocity.normalize() * max_speed;
-        }
+    let params = BoidParams {
+        boids:      n as u32,
+        cohesion:   cohesion_weight,
+        alignment:  alignment_weight,
+        separation: separation_weight,
+        separation_distance,
+        vision:     100.0, // neighborhood radius
+        max_speed:  5.0,
+    };
+    // Make a copy of current state to use in calculations
+    let current: Vec<Boid> = boids.to_vec();
+    for (i, boid) in boids.iter_mut().enumerate() {
+        let neighbours = current.iter().enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, other)| (other.position - boid.position, other.velocity));
+        boid.velocity = flock_velocity(boid.velocity, neighbours, &params);
     }
     // Update positions
     for boid in boids.iter_mut() {
         boid.position += boid.velocity;
     }
 }
+
+/* ─────────────────────────── grid‑agent version ─────────────────────── */
+
+impl Agent for Boid {
+    type Params = BoidParams;
+
+    /// Boids leave no trail: wipe the slice, each boid repaints its cell.
+    fn habitat_tick(cells: &mut CellView, _p: &BoidParams) {
+        let Some(size) = cells.size() else { return };
+        for y in 0..size.y as i32 {
+            for x in 0..size.x as i32 {
+                cells.set_state(IVec2::new(x, y), CellState::Dead);
+            }
+        }
+    }
+
+    fn step(&mut self, ctx: &mut AgentCtx, p: &BoidParams) -> AgentOutcome {
+        let pos = ctx.pos();
+
+        // Peers are the tick‑start snapshot → synchronous update.
+        let neighbours: Vec<(Vec2, Vec2)> = ctx.peers().iter()
+            .filter(|peer| peer.entity != ctx.entity)
+            .map(|peer| (ctx.cells.delta(pos, peer.pos).as_vec2(), peer.heading))
+            .collect();
+        self.velocity = flock_velocity(self.velocity, neighbours.into_iter(), p);
+
+        // Sub‑cell position, wrapped onto the torus.
+        self.position += self.velocity;
+        if let Some(size) = ctx.cells.size() {
+            self.position = self.position.rem_euclid(size.as_vec2());
+        }
+        ctx.move_to(self.position.floor().as_ivec2());
+        ctx.heading = self.velocity;
+        ctx.cells.set_state(ctx.pos(), CellState::Alive(1));
+        AgentOutcome::Live
+    }
+}
+
+/// Scatters `boids` boids with random velocities over the slice.
+pub fn seed_boids(s: &mut AgentSpawner) {
+    let p    = serde_json::from_value::<BoidParams>(s.params.clone()).unwrap_or_default();
+    let size = s.cells.size().map_or(Vec2::splat(256.0), |sz| sz.as_vec2());
+    let mut rng = rand::rng();
+
+    for _ in 0..p.boids {
+        let position = Vec2::new(rng.random_range(0.0..size.x), rng.random_range(0.0..size.y));
+        let velocity = Vec2::from_angle(rng.random_range(0.0..std::f32::consts::TAU)) * p.max_speed;
+        let boid     = Boid { position, velocity };
+        s.spawn(boid, position.floor().as_ivec2(), velocity);
+    }
+}
@@ -1,2 +1,5 @@
 pub mod ant_colony;
 pub mod boids;
+pub mod turmite;
+
+pub mod plugin;
@@ -0,0 +1,38 @@
+//! Registers the grid‑agent swarms: turmites, foraging ants and boids.
+
+use bevy::prelude::*;
+
+use crate::{
+    agents::{AgentStepperPlugin, HabitatRule},
+    registry::RuleRegistry,
+};
+use super::{
+    ant_colony::{seed_ant_colony, AntParams, ForagerAnt},
+    boids::{seed_boids, Boid, BoidParams},
+    turmite::{seed_turmites, Turmite, TurmiteParams},
+};
+
+/// Makes `"swarm:turmite"`, `"swarm:ants"` and `"swarm:boids"` spawnable
+/// and steps their agents.
+pub struct SwarmAutomataPlugin;
+impl Plugin for SwarmAutomataPlugin {
+    fn build(&self, app: &mut App) {
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        reg.register("swarm:turmite", HabitatRule::boxed());
+        reg.register_agents("swarm:turmite", seed_turmites);
+        reg.register_params("swarm:turmite", &TurmiteParams::default());
+        reg.register("swarm:ants", HabitatRule::boxed());
+        reg.register_agents("swarm:ants", seed_ant_colony);
+        reg.register_params("swarm:ants", &AntParams::default());
+        reg.register("swarm:boids", HabitatRule::boxed());
+        reg.register_agents("swarm:boids", seed_boids);
+        reg.register_params("swarm:boids", &BoidParams::default());
+        app.insert_resource(reg);
+
+        app.add_plugins((
+            AgentStepperPlugin::<Turmite>::default(),
+            AgentStepperPlugin::<ForagerAnt>::default(),
+            AgentStepperPlugin::<Boid>::default(),
+        ));
+    }
+}
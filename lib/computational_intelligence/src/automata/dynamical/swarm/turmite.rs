@@ -0,0 +1,118 @@
+//! Generalised **turmites** (Langton’s ant and friends) as grid agents.
+//!
+//! A turmite reads the colour `c` under itself, turns according to the
+//! `c`‑th letter of its rule string, repaints the cell with `(c + 1) mod n`
+//! and steps forward.  Colour 0 is `CellState::Dead`, colour `c > 0` is
+//! `CellState::Alive(c)`.
+//!
+//! | letter | turn            |
+//! |--------|-----------------|
+//! | `R`    | 90° right       |
+//! | `L`    | 90° left        |
+//! | `N`    | no turn         |
+//! | `U`    | U‑turn (180°)   |
+//!
+//! `"RL"` is Langton’s ant; `"RLR"`, `"LLRR"`, `"LRRRRRLLR"` … give the
+//! well‑known symmetric / chaotic / square‑filling variants.
+
+use bevy::prelude::*;
+use engine_core::core::cell::CellState;
+use serde::{Deserialize, Serialize};
+
+use crate::agents::{Agent, AgentCtx, AgentOutcome, AgentSpawner, Heading};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Turn { Right, Left, None, UTurn }
+
+impl Turn {
+    /// Parses a rule string such as `"RL"`; unknown letters are skipped.
+    pub fn parse_rule(rule: &str) -> Vec<Turn> {
+        rule.chars()
+            .filter_map(|c| match c.to_ascii_uppercase() {
+                'R' => Some(Turn::Right),
+                'L' => Some(Turn::Left),
+                'N' => Some(Turn::None),
+                'U' => Some(Turn::UTurn),
+                _   => None,
+            })
+            .collect()
+    }
+
+    pub fn apply(self, h: Heading) -> Heading {
+        match self {
+            Turn::Right => h.turned_right(),
+            Turn::Left  => h.turned_left(),
+            Turn::None  => h,
+            Turn::UTurn => h.reversed(),
+        }
+    }
+}
+
+/// Tunables read from the automaton’s `params`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TurmiteParams {
+    /// Turn table, one letter per colour.
+    pub rule:           String,
+    /// Number of turmites placed around the slice centre.
+    pub count:          u32,
+    /// Moves per logic tick (Langton’s highway needs ~10⁴ moves).
+    pub steps_per_tick: u32,
+}
+
+impl Default for TurmiteParams {
+    fn default() -> Self {
+        Self { rule: "RL".into(), count: 1, steps_per_tick: 20 }
+    }
+}
+
+/// Internal state of one turmite: its turn table.
+#[derive(Component, Clone, Debug)]
+pub struct Turmite {
+    pub rule: Vec<Turn>,
+}
+
+impl Turmite {
+    pub fn new(rule: &str) -> Self {
+        let rule = Turn::parse_rule(rule);
+        Self { rule: if rule.is_empty() { vec![Turn::Right, Turn::Left] } else { rule } }
+    }
+
+    fn colour(s: CellState) -> usize {
+        match s { CellState::Dead => 0, CellState::Alive(c) => c as usize }
+    }
+}
+
+impl Agent for Turmite {
+    type Params = TurmiteParams;
+
+    fn step(&mut self, ctx: &mut AgentCtx, p: &TurmiteParams) -> AgentOutcome {
+        let n = self.rule.len();
+
+        for _ in 0..p.steps_per_tick.max(1) {
+            let pos = ctx.pos();
+            let c   = Self::colour(ctx.cells.state(pos)) % n;
+
+            ctx.heading = self.rule[c].apply(Heading(ctx.heading)).0;
+            let next = (c + 1) % n;
+            ctx.cells.set_state(pos, if next == 0 { CellState::Dead } else { CellState::Alive(next as u8) });
+            ctx.move_to(pos + Heading(ctx.heading).step());
+        }
+        AgentOutcome::Live
+    }
+}
+
+/// Places `count` turmites on a small ring around the slice centre.
+pub fn seed_turmites(s: &mut AgentSpawner) {
+    let p: TurmiteParams = serde_json::from_value(s.params.clone()).unwrap_or_default();
+    let centre = s.cells.size().map_or(IVec2::ZERO, |sz| sz.as_ivec2() / 2);
+    let headings = [Vec2::Y, Vec2::X, Vec2::NEG_Y, Vec2::NEG_X];
+
+    for i in 0..p.count.max(1) {
+        let offset = if i == 0 { IVec2::ZERO } else {
+            let a = i as f32 / p.count as f32 * std::f32::consts::TAU;
+            (Vec2::from_angle(a) * 16.0).round().as_ivec2()
+        };
+        s.spawn(Turmite::new(&p.rule), centre + offset, headings[i as usize % 4]);
+    }
+}
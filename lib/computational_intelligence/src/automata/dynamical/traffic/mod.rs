@@ -0,0 +1,231 @@
+//! **Nagel–Schreckenberg** traffic CA (J. Phys. I France 2, 1992) on a ring
+//! road, with the symmetric two‑rule lane change of Rickert, Nagel,
+//! Schreckenberg & Latour (Physica A 231, 1996) for multi‑lane roads.
+//!
+//! Every tick, all cars update in parallel:
+//!
+//! 0. *lane change* (≥ 2 lanes) – a car blocked ahead (`gap < v + 1`) moves
+//!    sideways if the neighbouring lane offers at least `v + 1` free sites,
+//!    the target site is empty and nobody within `vmax` sites behind it
+//!    could crash into it (probability `p_change`);
+//! 1. accelerate `v ← min(v + 1, vmax)`;
+//! 2. brake to the gap `v ← min(v, gap)`;
+//! 3. dawdle: with probability `p_brake`, `v ← max(v − 1, 0)`;
+//! 4. drive `x ← x + v`.
+//!
+//! On the grid each lane owns a horizontal band of the slice: its first
+//! row is the road (a car of speed `v` is `Alive(v + 1)`), the rows below
+//! scroll one step per tick and form the lane’s space‑time diagram, where
+//! jams show up as backward‑travelling stripes.
+
+use bevy::math::{IVec2, UVec2};
+use engine_core::{core::cell::CellState, engine::grid::DenseGrid};
+use rand::{seq::index::sample, Rng};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub mod plugin;
+
+/// Rule id under which the model is registered.
+pub const TRAFFIC_ID: &str = "traffic:nasch";
+
+/// Speed of a car in sites per tick.
+pub type Speed = u8;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrafficParams {
+    pub lanes:    u32,
+    /// Cars per site, seeded uniformly at speed 0.
+    pub density:  f64,
+    pub vmax:     Speed,
+    /// Random‑braking (dawdling) probability.
+    pub p_brake:  f64,
+    /// Probability that a car allowed to change lane actually does.
+    pub p_change: f64,
+}
+
+impl Default for TrafficParams {
+    fn default() -> Self {
+        Self { lanes: 2, density: 0.15, vmax: 5, p_brake: 0.3, p_change: 1.0 }
+    }
+}
+
+impl TrafficParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+}
+
+/// `lanes` parallel circular lanes of `length` sites.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RingRoad {
+    length: usize,
+    lanes:  Vec<Vec<Option<Speed>>>,
+}
+
+impl RingRoad {
+    pub fn new(length: usize, lanes: usize) -> Self {
+        Self { length: length.max(1), lanes: vec![vec![None; length.max(1)]; lanes.max(1)] }
+    }
+
+    /// `round(density · sites)` stopped cars on uniformly random sites.
+    pub fn random(length: usize, lanes: usize, density: f64, rng: &mut impl Rng) -> Self {
+        let mut road = Self::new(length, lanes);
+        let sites = road.length * road.lanes.len();
+        let cars  = ((density.clamp(0.0, 1.0) * sites as f64).round() as usize).min(sites);
+        for i in sample(rng, sites, cars) {
+            road.lanes[i / road.length][i % road.length] = Some(0);
+        }
+        road
+    }
+
+    pub fn length(&self) -> usize { self.length }
+    pub fn lane_count(&self) -> usize { self.lanes.len() }
+    pub fn lane(&self, i: usize) -> &[Option<Speed>] { &self.lanes[i] }
+
+    pub fn set(&mut self, lane: usize, x: usize, car: Option<Speed>) {
+        self.lanes[lane][x % self.length] = car;
+    }
+
+    pub fn cars(&self) -> usize {
+        self.lanes.iter().flatten().filter(|c| c.is_some()).count()
+    }
+
+    /// Cars per site over all lanes.
+    pub fn density(&self) -> f64 {
+        self.cars() as f64 / (self.length * self.lanes.len()) as f64
+    }
+
+    /// Sum of all speeds – the distance covered during the last tick.
+    pub fn total_speed(&self) -> u64 {
+        self.lanes.iter().flatten().flatten().map(|&v| v as u64).sum()
+    }
+
+    /// Cars passing a fixed point per tick and lane (= density × mean speed).
+    pub fn flow(&self) -> f64 {
+        self.total_speed() as f64 / (self.length * self.lanes.len()) as f64
+    }
+
+    pub fn mean_speed(&self) -> f64 {
+        match self.cars() {
+            0 => 0.0,
+            n => self.total_speed() as f64 / n as f64,
+        }
+    }
+
+    /// Empty sites in front of `x` on `lane`, scanning at most `limit`.
+    fn gap_ahead(&self, lane: usize, x: usize, limit: usize) -> usize {
+        let road = &self.lanes[lane];
+        (1..=limit.min(self.length - 1))
+            .find(|d| road[(x + d) % self.length].is_some())
+            .map_or(limit.min(self.length - 1), |d| d - 1)
+    }
+
+    /// Empty sites behind `x` on `lane`, scanning at most `limit`.
+    fn gap_behind(&self, lane: usize, x: usize, limit: usize) -> usize {
+        let road = &self.lanes[lane];
+        (1..=limit.min(self.length - 1))
+            .find(|d| road[(x + self.length - d) % self.length].is_some())
+            .map_or(limit.min(self.length - 1), |d| d - 1)
+    }
+
+    /// Rule 0 – symmetric lane changes decided on the current state.
+    fn change_lanes(&mut self, p: &TrafficParams, rng: &mut impl Rng) {
+        let vmax = p.vmax as usize;
+        let mut next = self.lanes.clone();
+        for lane in 0..self.lanes.len() {
+            for (x, car) in self.lanes[lane].iter().enumerate() {
+                let Some(v) = *car else { continue };
+                let want = v as usize + 1;
+                if self.gap_ahead(lane, x, want) >= want { continue; }
+
+                let mut sides: Vec<usize> = [lane.checked_sub(1), Some(lane + 1)]
+                    .into_iter()
+                    .flatten()
+                    .filter(|&t| t < self.lanes.len())
+                    .filter(|&t| {
+                        self.lanes[t][x].is_none()
+                            && self.gap_ahead(t, x, want) >= want
+                            && self.gap_behind(t, x, vmax) >= vmax
+                    })
+                    .collect();
+                if sides.len() == 2 && rng.random_bool(0.5) { sides.swap(0, 1); }
+                let Some(&target) = sides.first() else { continue };
+                // Two cars may pick the same free site from either side.
+                if next[target][x].is_some() || !rng.random_bool(p.p_change.clamp(0.0, 1.0)) {
+                    continue;
+                }
+                next[target][x] = Some(v);
+                next[lane][x]   = None;
+            }
+        }
+        self.lanes = next;
+    }
+
+    /// One parallel update of the whole road (rules 0–4).
+    pub fn step(&mut self, p: &TrafficParams, rng: &mut impl Rng) {
+        if self.lanes.len() > 1 {
+            self.change_lanes(p, rng);
+        }
+        let vmax = p.vmax.clamp(1, Speed::MAX - 1);
+        for lane in 0..self.lanes.len() {
+            let mut next = vec![None; self.length];
+            for x in 0..self.length {
+                let Some(v) = self.lanes[lane][x] else { continue };
+                let gap = self.gap_ahead(lane, x, vmax as usize) as Speed;
+                let mut v = (v + 1).min(vmax).min(gap);
+                if v > 0 && rng.random_bool(p.p_brake.clamp(0.0, 1.0)) {
+                    v -= 1;
+                }
+                next[(x + v as usize) % self.length] = Some(v);
+            }
+            self.lanes[lane] = next;
+        }
+    }
+
+    /* ── grid mapping ────────────────────────────────────────────────── */
+
+    /// Height of one lane band on a `size` slice.
+    fn band(size: UVec2, lanes: usize) -> u32 {
+        (size.y / lanes.max(1) as u32).max(1)
+    }
+
+    /// Reads the road from the first row of every lane band.
+    pub fn from_grid(grid: &DenseGrid, lanes: usize) -> Self {
+        let lanes = lanes.clamp(1, grid.size.y.max(1) as usize);
+        let band  = Self::band(grid.size, lanes);
+        let mut road = Self::new(grid.size.x as usize, lanes);
+        for (lane, cells) in road.lanes.iter_mut().enumerate() {
+            let y = (lane as u32 * band) as i32;
+            for (x, car) in cells.iter_mut().enumerate() {
+                *car = match grid.get(IVec2::new(x as i32, y)).map(|c| c.state) {
+                    Some(CellState::Alive(s)) => Some(s.saturating_sub(1)),
+                    _                         => None,
+                };
+            }
+        }
+        road
+    }
+
+    /// Scrolls every lane band down one row and writes the road into its
+    /// first row.
+    pub fn write_grid(&self, grid: &mut DenseGrid) {
+        let lanes = self.lanes.len().min(grid.size.y as usize);
+        let band  = Self::band(grid.size, lanes);
+        let w     = grid.size.x as usize;
+        for (lane, cells) in self.lanes.iter().take(lanes).enumerate() {
+            let top = (lane as u32 * band) as usize;
+            for y in (top + 1..top + band as usize).rev() {
+                let (above, below) = grid.cells.split_at_mut(y * w);
+                below[..w].clone_from_slice(&above[(y - 1) * w..y * w]);
+            }
+            for (x, car) in cells.iter().enumerate().take(w) {
+                grid.cells[top * w + x].state = match car {
+                    Some(v) => CellState::Alive(v.saturating_add(1)),
+                    None    => CellState::Dead,
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Registers the traffic CA, seeds and steps its roads, samples flow.
+
+use bevy::prelude::*;
+use engine_core::{
+    engine::grid::GridBackend, events::AutomatonAdded, schedule::MainSet, state::AppState,
+};
+
+use crate::{
+    agents::HabitatRule,
+    analytics::time_series::TimeSeries,
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{RingRoad, TrafficParams, TRAFFIC_ID};
+
+pub struct TrafficPlugin;
+impl Plugin for TrafficPlugin {
+    fn build(&self, app: &mut App) {
+        // Cars look up to `vmax` sites ahead, beyond the reach of a
+        // per‑cell rule: the road is stepped as a whole below.
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        reg.register(TRAFFIC_ID, HabitatRule::boxed());
+        reg.register_params(TRAFFIC_ID, &TrafficParams::default());
+        app.insert_resource(reg);
+
+        app.add_systems(Update, seed_roads)
+           .add_systems(
+               Update,
+               step_roads
+                   .in_set(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           )
+           .add_systems(
+               Update,
+               record_traffic_series
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+/// Places `density` stopped cars on freshly spawned roads.
+fn seed_roads(mut added: EventReader<AutomatonAdded>, mut registry: ResMut<AutomataRegistry>) {
+    let mut rng = rand::rng();
+    for ev in added.read() {
+        let Some(info) = registry.get_mut(ev.id) else { continue };
+        if info.name != TRAFFIC_ID { continue; }
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+
+        let p    = TrafficParams::from_value(&info.params);
+        let road = RingRoad::random(grid.size.x as usize, p.lanes as usize, p.density, &mut rng);
+        road.write_grid(grid);
+    }
+}
+
+fn step_roads(mut registry: ResMut<AutomataRegistry>) {
+    let mut rng = rand::rng();
+    for info in registry.iter_mut().filter(|i| i.name == TRAFFIC_ID) {
+        let GridBackend::Dense(grid) = &mut info.grid else { continue };
+        let p        = TrafficParams::from_value(&info.params);
+        let mut road = RingRoad::from_grid(grid, p.lanes as usize);
+        road.step(&p, &mut rng);
+        road.write_grid(grid);
+    }
+}
+
+/// Per‑tick `density`, `flow` (cars / tick / lane), `mean_speed` and the
+/// share of `stopped` cars.
+fn record_traffic_series(registry: Res<AutomataRegistry>, mut series: ResMut<TimeSeries>) {
+    for info in registry.list().iter().filter(|i| i.name == TRAFFIC_ID) {
+        let GridBackend::Dense(grid) = &info.grid else { continue };
+        let p    = TrafficParams::from_value(&info.params);
+        let road = RingRoad::from_grid(grid, p.lanes as usize);
+        let cars = road.cars();
+        let stopped = (0..road.lane_count())
+            .flat_map(|l| road.lane(l).iter())
+            .filter(|c| **c == Some(0))
+            .count();
+
+        series.record(info.id, "density", road.density());
+        series.record(info.id, "flow", road.flow());
+        series.record(info.id, "mean_speed", road.mean_speed());
+        series.record(info.id, "stopped", if cars > 0 { stopped as f64 / cars as f64 } else { 0.0 });
+    }
+}
@@ -0,0 +1,180 @@
+//! A. K. Dewdney’s **Wa‑Tor** (Scientific American, 1984) – fish and
+//! sharks on a toroidal ocean.
+//!
+//! Every chronon, in random order:
+//!
+//! * **Fish** swim to a random free von‑Neumann neighbour.  Once their breed
+//!   counter reaches `fish_breed` a move leaves a new fish behind.
+//! * **Sharks** eat a random neighbouring fish if there is one, otherwise
+//!   swim like fish.  They breed after `shark_breed` chronons and starve
+//!   when they have not eaten for `shark_starve` chronons.
+//!
+//! Both species are one [`WatorAgent`] type so they share the occupancy map
+//! and can see each other.  Cells show fish as `Alive(1)`, sharks as
+//! `Alive(2)`; the populations feed the `fish` / `sharks` time series whose
+//! phase plot traces the Lotka–Volterra cycles.
+
+use bevy::prelude::*;
+use engine_core::core::cell::CellState;
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::agents::{Agent, AgentCtx, AgentOutcome, AgentSpawner, CellView};
+
+pub mod plugin;
+
+/// Rule id under which the model is registered.
+pub const WATOR_ID: &str = "wator";
+
+const VON_NEUMANN: [IVec2; 4] = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatorParams {
+    /// Initial fraction of cells holding fish / sharks.
+    pub fish_density:  f64,
+    pub shark_density: f64,
+    pub fish_breed:    u32,
+    pub shark_breed:   u32,
+    pub shark_starve:  u32,
+}
+
+impl Default for WatorParams {
+    fn default() -> Self {
+        Self { fish_density: 0.2, shark_density: 0.03, fish_breed: 3, shark_breed: 10, shark_starve: 3 }
+    }
+}
+
+impl WatorParams {
+    pub fn from_value(params: &Value) -> Self {
+        serde_json::from_value(params.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Species { Fish, Shark }
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct WatorAgent {
+    pub species: Species,
+    /// Chronons since birth / last offspring.
+    pub breed:   u32,
+    /// Chronons since the last meal (sharks only).
+    pub hunger:  u32,
+}
+
+impl WatorAgent {
+    pub fn new(species: Species) -> Self {
+        Self { species, breed: 0, hunger: 0 }
+    }
+
+    fn state(self) -> CellState {
+        match self.species {
+            Species::Fish  => CellState::Alive(1),
+            Species::Shark => CellState::Alive(2),
+        }
+    }
+
+    /// Chronons between offspring.
+    fn breed_time(self, p: &WatorParams) -> u32 {
+        match self.species {
+            Species::Fish  => p.fish_breed,
+            Species::Shark => p.shark_breed,
+        }
+    }
+
+    /// Random free neighbour (if any) in shuffled von‑Neumann order.
+    fn free_neighbour(ctx: &mut AgentCtx, pos: IVec2) -> Option<IVec2> {
+        let mut dirs = VON_NEUMANN;
+        dirs.shuffle(ctx.rng);
+        dirs.into_iter().map(|d| pos + d).find(|q| ctx.is_free(*q))
+    }
+
+    fn prey_neighbour(ctx: &mut AgentCtx, pos: IVec2) -> Option<IVec2> {
+        let mut dirs = VON_NEUMANN;
+        dirs.shuffle(ctx.rng);
+        dirs.into_iter().map(|d| pos + d).find(|q| {
+            ctx.occupant(*q)
+                .and_then(|e| ctx.agent::<WatorAgent>(e))
+                .is_some_and(|a| a.species == Species::Fish)
+        })
+    }
+}
+
+impl Agent for WatorAgent {
+    type Params = WatorParams;
+
+    /// Wipe the ocean; every surviving creature repaints its cell.
+    fn habitat_tick(cells: &mut CellView, _p: &WatorParams) {
+        let Some(size) = cells.size() else { return };
+        for y in 0..size.y as i32 {
+            for x in 0..size.x as i32 {
+                cells.set_state(IVec2::new(x, y), CellState::Dead);
+            }
+        }
+    }
+
+    fn step(&mut self, ctx: &mut AgentCtx, p: &WatorParams) -> AgentOutcome {
+        let pos = ctx.pos();
+        self.breed += 1;
+
+        // ── hunt or swim ───────────────────────────────────────────────
+        let target = match self.species {
+            Species::Fish  => Self::free_neighbour(ctx, pos),
+            Species::Shark => {
+                self.hunger += 1;
+                match Self::prey_neighbour(ctx, pos) {
+                    Some(q) => {
+                        ctx.kill_at(q);
+                        self.hunger = 0;
+                        Some(q)
+                    }
+                    None => Self::free_neighbour(ctx, pos),
+                }
+            }
+        };
+        if self.species == Species::Shark && self.hunger >= p.shark_starve {
+            return AgentOutcome::Die;
+        }
+
+        // ── move, maybe leaving offspring behind ───────────────────────
+        if let Some(q) = target {
+            ctx.move_to(q);
+            if self.breed >= self.breed_time(p) {
+                self.breed = 0;
+                let child = WatorAgent::new(self.species);
+                ctx.spawn(child, pos, Vec2::ZERO);
+                ctx.cells.set_state(pos, child.state());
+            }
+        }
+        ctx.cells.set_state(ctx.pos(), self.state());
+        AgentOutcome::Live
+    }
+}
+
+/// Scatters fish and sharks uniformly according to the densities.
+pub fn seed_wator(s: &mut AgentSpawner) {
+    let p = WatorParams::from_value(s.params);
+    let Some(size) = s.cells.size() else { return };
+    let mut rng = rand::rng();
+
+    for y in 0..size.y as i32 {
+        for x in 0..size.x as i32 {
+            let q = IVec2::new(x, y);
+            let r = rng.random::<f64>();
+            let species = if r < p.shark_density {
+                Species::Shark
+            } else if r < p.shark_density + p.fish_density {
+                Species::Fish
+            } else {
+                continue;
+            };
+            let mut agent = WatorAgent::new(species);
+            // Desynchronise the first generation.
+            agent.breed = rng.random_range(0..agent.breed_time(&p).max(1));
+            s.spawn(agent, q, Vec2::ZERO);
+            s.cells.set_state(q, agent.state());
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Registers Wa‑Tor and samples its two populations.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use engine_core::{events::AutomatonId, schedule::MainSet, state::AppState};
+
+use crate::{
+    agents::{AgentStepperPlugin, Habitat, HabitatRule},
+    analytics::time_series::TimeSeries,
+    registry::{AutomataRegistry, RuleRegistry},
+};
+use super::{seed_wator, Species, WatorAgent, WatorParams, WATOR_ID};
+
+pub struct WatorPlugin;
+impl Plugin for WatorPlugin {
+    fn build(&self, app: &mut App) {
+        let mut reg = app.world_mut().remove_resource::<RuleRegistry>().unwrap_or_default();
+        reg.register(WATOR_ID, HabitatRule::boxed());
+        reg.register_agents(WATOR_ID, seed_wator);
+        reg.register_params(WATOR_ID, &WatorParams::default());
+        app.insert_resource(reg);
+
+        app.add_plugins(AgentStepperPlugin::<WatorAgent>::default())
+           .add_systems(
+               Update,
+               record_wator_series
+                   .after(MainSet::Logic)
+                   .run_if(in_state(AppState::InGame)),
+           );
+    }
+}
+
+/// Per‑tick `fish` and `sharks` counts.
+fn record_wator_series(
+    registry:   Res<AutomataRegistry>,
+    agents:     Query<(&WatorAgent, &Habitat)>,
+    mut series: ResMut<TimeSeries>,
+) {
+    let mut counts: HashMap<AutomatonId, [u32; 2]> = HashMap::new();
+    for (agent, habitat) in &agents {
+        let c = counts.entry(habitat.0).or_default();
+        match agent.species {
+            Species::Fish  => c[0] += 1,
+            Species::Shark => c[1] += 1,
+        }
+    }
+
+    for info in registry.list().iter().filter(|i| i.name == WATOR_ID) {
+        let [fish, sharks] = counts.get(&info.id).copied().unwrap_or_default();
+        series.record(info.id, "fish", fish as f64);
+        series.record(info.id, "sharks", sharks as f64);
+    }
+}
@@ -6,14 +6,16 @@ use std::sync::Arc;
 use bevy::prelude::*;
 use engine_core::{
     engine::{
-        grid::{DenseGrid, GridBackend},
+        grid::{CellLayers, DenseGrid, GridBackend},
         worldgrid::WorldGrid,
     },
     events::{AutomataCommand, AutomatonAdded, AutomatonId, AutomatonRemoved}, state::AppState,
 };
-use serde_json::Value;
 
-use crate::registry::{AutomataRegistry, AutomatonInfo, RuleRegistry};
+use crate::{
+    agents::{AgentSpawner, CellView},
+    registry::{AutomataRegistry, AutomatonInfo, RuleRegistry},
+};
 
 /* ───────────────────────── Constants ─────────────────────────────── */
 
@@ -49,6 +51,9 @@ impl Plugin for CommandExecutorPlugin {
 
 /* ───────── new system ───────── */
 /// Despawns every automaton & resets the global dense world‑grid.
+///
+/// The removals are emitted in `MainMenu`, so systems that drop per‑automaton
+/// state on [`AutomatonRemoved`] must not be gated on `InGame`.
 fn purge_on_main_menu(
     mut registry:       ResMut<AutomataRegistry>,
     mut world_grid:     ResMut<WorldGrid>,
@@ -110,17 +115,29 @@ fn handle_commands(
                     id:               AutomatonId(0), // overwritten by `register`
                     name:             id.clone(),
                     rule:             Arc::clone(rule),
-                    params:           Value::Null,
+                    params:           rules.params(id),
                     seed_fn:          None,
                     grid:             slice_backend,
+                    layers:           CellLayers::new(size),
                     dimension:        2,
+                    lattice:          rules.lattice(id),
                     cell_size:        DEFAULT_CELL,
                     background_color: BG,
-                    palette:          None,
+                    palette:          rules.palette(id),
                     world_offset:     slice.offset,     // NEW  ←──────────────
                 };
                 let new_id = registry.register(info);
                 let entity = commands.spawn_empty().id();
+
+                /* optional agent population living on the slice */
+                if let (Some(seed_agents), Some(info)) =
+                    (rules.agent_seed(id.as_str()), registry.get_mut(new_id))
+                {
+                    let AutomatonInfo { grid, layers, params, .. } = info;
+                    let cells = CellView::new(grid, layers);
+                    seed_agents(&mut AgentSpawner::new(new_id, entity, cells, params, &mut commands));
+                }
+
                 added_writer.write(AutomatonAdded { id: new_id, entity });
             }
 
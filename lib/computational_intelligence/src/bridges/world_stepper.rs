@@ -9,17 +9,18 @@ use rayon::prelude::*;
 use engine_core::{
     core::{
         cell::{CellCtx, CellOutcome, CellState},
-        dim::Dim2, Dim,
+        dim::{Dim2, Lattice},
+    },
+    engine::{
+        grid::{DenseGrid, GridBackend, SparseGrid},
+        stepper::graph::step_graph,
     },
-    engine::grid::{DenseGrid, GridBackend, SparseGrid},
 };
 
 use crate::registry::AutomataRegistry;
 
 /* ------------------------------------------------- */
 
-const EMPTY: Value = Value::Null;
-
 pub struct WorldStepperPlugin;
 impl Plugin for WorldStepperPlugin {
     fn build(&self, app: &mut App) {
@@ -40,8 +41,9 @@ fn step_every_automaton_parallel(mut reg: ResMut<AutomataRegistry>) {
        .par_bridge()                      // converts iterator → ParallelIterator
        .for_each(|auto| {
            match &mut auto.grid {
-               GridBackend::Dense(g)  => step_dense_dyn_parallel(g, &*auto.rule, &EMPTY),
-               GridBackend::Sparse(s) => step_sparse_dyn_parallel(s, &*auto.rule, &EMPTY),
+               GridBackend::Dense(g)  => step_dense_dyn_parallel(g, &*auto.rule, &auto.params, auto.lattice),
+               GridBackend::Sparse(s) => step_sparse_dyn_parallel(s, &*auto.rule, &auto.params, auto.lattice),
+               GridBackend::Graph(g)  => step_graph(g, &*auto.rule, &auto.params),
            }
        });
 }
@@ -52,9 +54,10 @@ fn step_every_automaton_parallel(mut reg: ResMut<AutomataRegistry>) {
 
 #[inline]
 fn step_dense_dyn_parallel(
-    grid:   &mut DenseGrid,
-    rule:   &(dyn engine_core::core::AutomatonRule<D = Dim2> + Sync),
-    params: &Value,
+    grid:    &mut DenseGrid,
+    rule:    &(dyn engine_core::core::AutomatonRule<D = Dim2> + Sync),
+    params:  &Value,
+    lattice: Lattice,
 ) {
     use bevy::math::IVec2;
 
@@ -70,9 +73,10 @@ fn step_dense_dyn_parallel(
             let y = (idx as u32 / size.x) as i32;
             let p = IVec2::new(x, y);
 
-            /* build Moore‑8 neighbourhood from the read‑only snapshot */
+            /* build the lattice neighbourhood from the read‑only snapshot */
+            let offsets  = lattice.offsets_at(p);
             let mut nbhd = [CellState::Dead; 8];
-            for (i, off) in Dim2::NEIGHBOUR_OFFSETS.iter().enumerate() {
+            for (i, off) in offsets.iter().enumerate() {
                 let q = p + *off;
                 if (0..size.x as i32).contains(&q.x) &&
                    (0..size.y as i32).contains(&q.y)
@@ -84,7 +88,7 @@ fn step_dense_dyn_parallel(
             let ctx = CellCtx::<Dim2> {
                 self_coord:    p,
                 self_state:    cell.state,
-                neighbourhood: &nbhd,
+                neighbourhood: &nbhd[..offsets.len()],
                 memory:        &cell.memory,
                 _marker:       std::marker::PhantomData,
             };
@@ -98,9 +102,10 @@ fn step_dense_dyn_parallel(
 
 #[inline]
 fn step_sparse_dyn_parallel(
-    grid:   &mut SparseGrid,
-    rule:   &(dyn engine_core::core::AutomatonRule<D = Dim2> + Sync),
-    params: &Value,
+    grid:    &mut SparseGrid,
+    rule:    &(dyn engine_core::core::AutomatonRule<D = Dim2> + Sync),
+    params:  &Value,
+    lattice: Lattice,
 ) {
     use bevy::math::IVec2;
     use std::collections::HashMap;
@@ -113,15 +118,16 @@ fn step_sparse_dyn_parallel(
     let new_map: HashMap<IVec2, Cell> = snapshot
         .par_iter()
         .map(|(&p, cell)| {
+            let offsets  = lattice.offsets_at(p);
             let mut nbhd = [CellState::Dead; 8];
-            for (i, off) in Dim2::NEIGHBOUR_OFFSETS.iter().enumerate() {
+            for (i, off) in offsets.iter().enumerate() {
                 nbhd[i] = snapshot.get(&(p + *off)).map_or(CellState::Dead, |c| c.state);
             }
 
             let ctx = CellCtx::<Dim2> {
                 self_coord:    p,
                 self_state:    cell.state,
-                neighbourhood: &nbhd,
+                neighbourhood: &nbhd[..offsets.len()],
                 memory:        &cell.memory,
                 _marker:       std::marker::PhantomData,
             };
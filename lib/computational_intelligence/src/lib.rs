@@ -8,6 +8,7 @@
 //!
 //! *Later*: use cargo‑features to enable/disable sub‑families.
 
+pub mod agents;
 pub mod automata;
 pub mod analytics;
 pub mod bridges;
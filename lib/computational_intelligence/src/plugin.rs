@@ -35,6 +35,8 @@ use bevy::prelude::*;
 use engine_core::events::{AutomatonAdded, AutomatonRemoved};
 
 use crate::{
+    agents::AgentsPlugin,
+    analytics::plugin::AnalyticsPlugin,
     automata::plugin::AutomataPlugin,
     registry::{
         RuleRegistry,            // Global rule catalogue
@@ -83,8 +85,9 @@ impl Plugin for ComputationalIntelligencePlugin {
 
         // ------------------------------------------------------------------
         // 3.  Sub‑plugins — classical & dynamical rule families, world
-        //     stepper, and anything we add in future roadmaps.
+        //     stepper, grid‑agent housekeeping, analytics time series, and
+        //     anything we add in future roadmaps.
         // ------------------------------------------------------------------
-        app.add_plugins(AutomataPlugin);
+        app.add_plugins((AutomataPlugin, AgentsPlugin, AnalyticsPlugin));
     }
 }
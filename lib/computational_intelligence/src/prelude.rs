@@ -9,4 +9,6 @@ pub use super::{
     automata::classical::plugin::ClassicalAutomataPlugin,
     automata::dynamical::plugin::DynamicalAutomataPlugin,
     registry::{RuleRegistry, AutomataRegistry, AutomatonInfo},
+    analytics::time_series::TimeSeries,
+    agents::{Agent, AgentCtx, AgentOutcome, AgentSpawner, AgentStepperPlugin, CellView},
 };
@@ -3,11 +3,14 @@ use std::sync::Arc;
 
 use bevy::prelude::*;                    // Color, Resource, etc.
 use engine_core::{
-    core::{dim::Dim2, world::World2D, AutomatonRule},
-    engine::grid::GridBackend, events::AutomatonId,
+    core::{dim::{Dim2, Lattice}, world::World2D, AutomatonRule},
+    engine::grid::{CellLayers, GridBackend}, events::AutomatonId,
 };
+use serde::Serialize;
 use serde_json::Value;
 
+use crate::agents::AgentSeedFn;
+
 /* ──────────────────────────────────────────────────────────────────── */
 /* Rule registry                                                       */
 /* ──────────────────────────────────────────────────────────────────── */
@@ -18,6 +21,16 @@ pub struct RuleRegistry {
         String,
         (Arc<dyn AutomatonRule<D = Dim2> + Send + Sync>, Option<fn(&mut GridBackend)>),
     >,
+    /// Optional agent populations spawned alongside the cells of a rule.
+    agents: HashMap<String, AgentSeedFn>,
+    /// Parameters handed to newly spawned automata of a rule.
+    params: HashMap<String, Value>,
+    /// What `register_params` set, restored by `reset_params`.
+    default_params: HashMap<String, Value>,
+    /// Cell colours of multi‑state rules (entry `i` paints `Alive(i + 1)`).
+    palettes: HashMap<String, Vec<Color>>,
+    /// Non‑square lattices of rules (everything else steps on Moore‑8).
+    lattices: HashMap<String, Lattice>,
 }
 
 impl RuleRegistry {
@@ -40,6 +53,43 @@ impl RuleRegistry {
         self.rules.insert(id.into(), (rule, None));
     }
 
+    /// Attach an agent seeder to an already registered rule `id`.  It runs
+    /// right after the cell seed whenever `AutomataCommand::SeedPattern`
+    /// spawns that rule.
+    pub fn register_agents(&mut self, id: impl Into<String>, seed_fn: AgentSeedFn) {
+        self.agents.insert(id.into(), seed_fn);
+    }
+
+    /// Default parameters for rule `id` (what the UI offers for editing).
+    pub fn register_params<P: Serialize>(&mut self, id: impl Into<String>, defaults: &P) {
+        let id    = id.into();
+        let value = serde_json::to_value(defaults).unwrap_or(Value::Null);
+        self.default_params.insert(id.clone(), value.clone());
+        self.params.insert(id, value);
+    }
+
+    /// Colours for the live states of rule `id`; rules without a palette
+    /// render every live cell white.
+    pub fn register_palette(&mut self, id: impl Into<String>, palette: Vec<Color>) {
+        self.palettes.insert(id.into(), palette);
+    }
+
+    /// Run rule `id` on a hexagonal or triangular lattice: its neighbourhood
+    /// follows that stencil and its cells render in that shape.
+    pub fn register_lattice(&mut self, id: impl Into<String>, lattice: Lattice) {
+        self.lattices.insert(id.into(), lattice);
+    }
+
+    /// Replace the parameters used for *future* spawns of rule `id`.
+    pub fn set_params(&mut self, id: &str, params: Value) {
+        self.params.insert(id.to_owned(), params);
+    }
+
+    /// Forget every `set_params` and go back to the registered defaults.
+    pub fn reset_params(&mut self) {
+        self.params = self.default_params.clone();
+    }
+
     /* Lookup ---------------------------------------------------------- */
 
     pub fn get(
@@ -50,6 +100,29 @@ impl RuleRegistry {
         self.rules.get(id)
     }
 
+    pub fn agent_seed(&self, id: &str) -> Option<AgentSeedFn> {
+        self.agents.get(id).copied()
+    }
+
+    /// Current parameters of rule `id` (`Value::Null` if it has none).
+    pub fn params(&self, id: &str) -> Value {
+        self.params.get(id).cloned().unwrap_or(Value::Null)
+    }
+
+    pub fn palette(&self, id: &str) -> Option<Vec<Color>> {
+        self.palettes.get(id).cloned()
+    }
+
+    pub fn lattice(&self, id: &str) -> Lattice {
+        self.lattices.get(id).copied().unwrap_or_default()
+    }
+
+    /// Iterator over every rule that exposes parameters, with its
+    /// registered defaults.
+    pub fn params_iter(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.default_params.iter()
+    }
+
     /// Iterator over all registered IDs.
     pub fn ids(&self) -> impl Iterator<Item = &String> {
         self.rules.keys()
@@ -77,7 +150,11 @@ pub struct AutomatonInfo {
     pub params: Value,
     pub seed_fn: Option<fn(&mut GridBackend)>,
     pub grid: GridBackend,
+    /// Extra per‑cell scalar planes (read/written by agents).
+    pub layers: CellLayers,
     pub dimension: u8,
+    /// Cell shape of a dense grid (stencil and rendering).
+    pub lattice: Lattice,
     pub cell_size: f32,
     pub background_color: Color,
     pub palette: Option<Vec<Color>>,
@@ -91,6 +168,7 @@ impl std::fmt::Debug for AutomatonInfo {
             .field("id", &self.id)
             .field("name", &self.name)
             .field("dimension", &self.dimension)
+            .field("lattice", &self.lattice)
             .field("cell_size", &self.cell_size)
             .finish()
     }
@@ -127,9 +205,12 @@ impl AutomataRegistry {
         self.automata.iter().find(|a| a.name == name)
     }
 
-    /* Mutable iterator (used by the stepper) ------------------------- */
+    /* Mutable access (used by the steppers) -------------------------- */
 
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, AutomatonInfo> {
         self.automata.iter_mut()
     }
+    pub fn get_mut(&mut self, id: AutomatonId) -> Option<&mut AutomatonInfo> {
+        self.automata.iter_mut().find(|a| a.id == id)
+    }
 }
\ No newline at end of file
@@ -2,7 +2,6 @@
 
 use std::marker::PhantomData;
 
-use bevy::math::IVec2;
 use serde::{Deserialize, Serialize};
 
 use crate::core::Dim;
@@ -29,9 +28,12 @@ pub struct Cell {
 
 /// Context provided to every rule invocation.
 pub struct CellCtx<'a, D: Dim> {
-    pub self_coord:   IVec2,
+    pub self_coord:   D::Coord,
     pub self_state:   CellState,
-    pub neighbourhood: &'a [CellState; 8],
+    /// The lattice stencil of the cell (`D::offsets_at` order – Moore‑8 on
+    /// squares, six on hexes, three on triangles); the adjacency list (any
+    /// length) on graphs.
+    pub neighbourhood: &'a [CellState],
     pub memory:       &'a CellMemory,        // <- align with `cell.rs`
     pub _marker:          PhantomData<D>,        // <- silences the lint ✔
 }
@@ -1,4 +1,5 @@
-use bevy::math::IVec2;
+use bevy::math::{IVec2, IVec3};
+use serde::{Deserialize, Serialize};
 
 /// Trait implemented by every supported spatial dimensionality.
 ///
@@ -7,6 +8,14 @@ use bevy::math::IVec2;
 pub trait Dim: Copy + Eq + std::hash::Hash + Send + Sync + 'static {
     type Coord: Copy + Eq + std::hash::Hash + Send + Sync;
     const NEIGHBOUR_OFFSETS: &'static [Self::Coord];
+
+    /// Offsets of the neighbours of the cell at `coord` in *storage*
+    /// coordinates.  Lattices whose stencil depends on the cell (odd/even
+    /// hex rows, up/down triangles) override this.
+    #[inline(always)]
+    fn offsets_at(_coord: Self::Coord) -> &'static [Self::Coord] {
+        Self::NEIGHBOUR_OFFSETS
+    }
 }
 
 /// Two‑dimensional grid using the Moore‑8 neighbourhood.
@@ -21,4 +30,175 @@ impl Dim for Dim2 {
         IVec2::new(-1, 0),                     IVec2::new(1, 0),
         IVec2::new(-1, 1),  IVec2::new(0, 1),  IVec2::new(1, 1),
     ];
-}
\ No newline at end of file
+}
+
+/* ── hexagonal lattice ───────────────────────────────────────────────── */
+
+/// Pointy‑top hexagonal lattice, six neighbours per cell.
+///
+/// Cells are *stored* in “odd‑r” offset coordinates – row `y`, column `x`,
+/// odd rows shifted half a cell to the right – so a hex lattice fits a
+/// rectangular [`DenseGrid`](crate::engine::grid::DenseGrid) unchanged.
+/// `NEIGHBOUR_OFFSETS` are the six *axial* directions; convert with
+/// [`offset_to_axial`] / [`axial_to_offset`] for distances and geometry.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DimHex;
+
+/// E, W, NE, NW, SE, SW of a cell on an even row (offset coordinates).
+const HEX_EVEN_ROW: &[IVec2] = &[
+    IVec2::new(1, 0),  IVec2::new(-1, 0),
+    IVec2::new(0, -1), IVec2::new(-1, -1),
+    IVec2::new(0, 1),  IVec2::new(-1, 1),
+];
+
+/// The same directions from an odd (right‑shifted) row.
+const HEX_ODD_ROW: &[IVec2] = &[
+    IVec2::new(1, 0),  IVec2::new(-1, 0),
+    IVec2::new(1, -1), IVec2::new(0, -1),
+    IVec2::new(1, 1),  IVec2::new(0, 1),
+];
+
+impl Dim for DimHex {
+    type Coord = IVec2;
+
+    const NEIGHBOUR_OFFSETS: &'static [Self::Coord] = &[
+        IVec2::new(1, 0),  IVec2::new(-1, 0),
+        IVec2::new(1, -1), IVec2::new(0, -1),
+        IVec2::new(0, 1),  IVec2::new(-1, 1),
+    ];
+
+    #[inline(always)]
+    fn offsets_at(coord: IVec2) -> &'static [IVec2] {
+        if coord.y & 1 == 0 { HEX_EVEN_ROW } else { HEX_ODD_ROW }
+    }
+}
+
+/// Odd‑r offset `(col, row)` → axial `(q, r)`.
+pub fn offset_to_axial(p: IVec2) -> IVec2 {
+    IVec2::new(p.x - p.y.div_euclid(2), p.y)
+}
+
+/// Axial `(q, r)` → odd‑r offset `(col, row)`.
+pub fn axial_to_offset(a: IVec2) -> IVec2 {
+    IVec2::new(a.x + a.y.div_euclid(2), a.y)
+}
+
+/// Number of steps between two cells given in offset coordinates.
+///
+/// ```
+/// use bevy::math::IVec2;
+/// use engine_core::core::dim::{hex_distance, Dim, DimHex};
+///
+/// let p = IVec2::new(4, 3);
+/// for off in DimHex::offsets_at(p) {
+///     assert_eq!(hex_distance(p, p + *off), 1);
+/// }
+/// assert_eq!(hex_distance(IVec2::new(0, 0), IVec2::new(3, 0)), 3);
+/// ```
+pub fn hex_distance(a: IVec2, b: IVec2) -> i32 {
+    let d = offset_to_axial(a) - offset_to_axial(b);
+    (d.x.abs() + d.y.abs() + (d.x + d.y).abs()) / 2
+}
+
+/* ── triangular lattice ──────────────────────────────────────────────── */
+
+/// Triangular lattice, three edge neighbours per cell.
+///
+/// Cell `(x, y)` points *up* (apex towards `+y`) when `x + y` is even and
+/// *down* otherwise; horizontally adjacent triangles share a slanted edge,
+/// the third neighbour sits across the flat base.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DimTri;
+
+const TRI_DOWN: &[IVec2] = &[IVec2::new(-1, 0), IVec2::new(1, 0), IVec2::new(0, 1)];
+
+impl Dim for DimTri {
+    type Coord = IVec2;
+
+    /// Neighbours of an up‑pointing triangle.
+    const NEIGHBOUR_OFFSETS: &'static [Self::Coord] =
+        &[IVec2::new(-1, 0), IVec2::new(1, 0), IVec2::new(0, -1)];
+
+    #[inline(always)]
+    fn offsets_at(coord: IVec2) -> &'static [IVec2] {
+        if (coord.x + coord.y) & 1 == 0 { Self::NEIGHBOUR_OFFSETS } else { TRI_DOWN }
+    }
+}
+
+/* ── three dimensions ────────────────────────────────────────────────── */
+
+/// Three‑dimensional grid using the Moore‑26 neighbourhood.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Dim3;
+
+const fn moore3() -> [IVec3; 26] {
+    let mut out = [IVec3::ZERO; 26];
+    let (mut i, mut k) = (0, 0);
+    while k < 27 {
+        let off = IVec3::new(k % 3 - 1, k / 3 % 3 - 1, k / 9 - 1);
+        if k != 13 {
+            out[i] = off;
+            i += 1;
+        }
+        k += 1;
+    }
+    out
+}
+
+const MOORE3: [IVec3; 26] = moore3();
+
+impl Dim for Dim3 {
+    type Coord = IVec3;
+    const NEIGHBOUR_OFFSETS: &'static [Self::Coord] = &MOORE3;
+}
+
+/// Three‑dimensional grid using the six face neighbours.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Dim3VonNeumann;
+
+impl Dim for Dim3VonNeumann {
+    type Coord = IVec3;
+
+    const NEIGHBOUR_OFFSETS: &'static [Self::Coord] = &[
+        IVec3::new(-1, 0, 0), IVec3::new(1, 0, 0),
+        IVec3::new(0, -1, 0), IVec3::new(0, 1, 0),
+        IVec3::new(0, 0, -1), IVec3::new(0, 0, 1),
+    ];
+}
+
+/* ── runtime selection ───────────────────────────────────────────────── */
+
+/// Which 2‑D lattice a dense automaton lives on.  Registry rules are all
+/// typed `D = Dim2`; this tag picks the stencil the world stepper builds
+/// their neighbourhood from and the cell shape the renderer draws.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Lattice {
+    /// Moore‑8 squares.
+    #[default]
+    Square,
+    /// [`DimHex`] hexagons.
+    Hex,
+    /// [`DimTri`] triangles.
+    Triangular,
+}
+
+impl Lattice {
+    #[inline(always)]
+    pub fn offsets_at(self, coord: IVec2) -> &'static [IVec2] {
+        match self {
+            Lattice::Square     => Dim2::offsets_at(coord),
+            Lattice::Hex        => DimHex::offsets_at(coord),
+            Lattice::Triangular => DimTri::offsets_at(coord),
+        }
+    }
+
+    /// Selector of the shader’s cell‑shape branch.
+    pub fn shader_id(self) -> u32 {
+        match self {
+            Lattice::Square     => 0,
+            Lattice::Hex        => 1,
+            Lattice::Triangular => 2,
+        }
+    }
+}
@@ -19,6 +19,7 @@ impl World2D {
             n[i] = match &self.backend {
                 GridBackend::Dense(g)  => g.get(*off + coord).map_or(CellState::Dead, |c| c.state),
                 GridBackend::Sparse(g) => g.get(*off + coord).map_or(CellState::Dead, |c| c.state),
+                GridBackend::Graph(_)  => CellState::Dead,
             };
         }
         n
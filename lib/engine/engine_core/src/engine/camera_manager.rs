@@ -130,6 +130,7 @@ fn centre_on_world(
     let (w, h) = match &grid.backend {
         crate::engine::grid::GridBackend::Dense(g)  => (g.size.x, g.size.y),
         crate::engine::grid::GridBackend::Sparse(_) => (1024, 1024),
+        crate::engine::grid::GridBackend::Graph(g)  => (g.canvas.x, g.canvas.y),
     };
     tf.translation.x = w as f32 * 0.5;
     tf.translation.y = h as f32 * 0.5;
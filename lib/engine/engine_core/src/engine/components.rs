@@ -118,6 +118,7 @@ fn spawn_once_when_worldgrid_exists(
     let (w, h) = match &grid.backend {
         crate::engine::grid::GridBackend::Dense(g)  => (g.size.x, g.size.y),
         crate::engine::grid::GridBackend::Sparse(_) => (1024, 1024),
+        crate::engine::grid::GridBackend::Graph(g)  => (g.canvas.x, g.canvas.y),
     };
 
     // NOTE:  we *could* carry a cell‑size inside WorldGrid; for now use 1.0
@@ -0,0 +1,87 @@
+use bevy::math::{IVec3, UVec2, UVec3};
+use serde::{Serialize, Deserialize};
+
+use crate::core::{Cell, CellState};
+
+/// Dense `x × y × z` volume, stored slice by slice (`z` outermost).
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DenseGrid3 {
+    pub cells: Vec<Cell>,
+    pub size:  UVec3,
+}
+
+impl DenseGrid3 {
+    #[inline]
+    pub fn idx(&self, p: IVec3) -> usize {
+        ((p.z as u32 * self.size.y + p.y as u32) * self.size.x + p.x as u32) as usize
+    }
+
+    #[inline]
+    pub fn contains(&self, p: IVec3) -> bool {
+        (0..self.size.x as i32).contains(&p.x)
+            && (0..self.size.y as i32).contains(&p.y)
+            && (0..self.size.z as i32).contains(&p.z)
+    }
+
+    pub fn get(&self, p: IVec3) -> Option<&Cell> {
+        if self.contains(p) { self.cells.get(self.idx(p)) } else { None }
+    }
+
+    pub fn get_mut(&mut self, p: IVec3) -> Option<&mut Cell> {
+        if self.contains(p) {
+            let idx = self.idx(p);
+            self.cells.get_mut(idx)
+        } else { None }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (IVec3, &Cell)> + '_ {
+        let size = self.size;
+        self.cells.iter().enumerate().map(move |(i, c)| {
+            let i = i as u32;
+            let p = IVec3::new((i % size.x) as i32, (i / size.x % size.y) as i32, (i / (size.x * size.y)) as i32);
+            (p, c)
+        })
+    }
+
+    pub fn blank(size: UVec3) -> Self {
+        Self { cells: vec![Cell::default(); (size.x * size.y * size.z) as usize], size }
+    }
+
+    pub fn population(&self) -> usize {
+        self.cells.iter().filter(|c| c.state != CellState::Dead).count()
+    }
+
+    /// Cells of the plane `z`, row‑major like a [`DenseGrid`](super::DenseGrid).
+    pub fn slice(&self, z: u32) -> &[Cell] {
+        let plane = (self.size.x * self.size.y) as usize;
+        let z     = z.min(self.size.z.saturating_sub(1)) as usize;
+        &self.cells[z * plane..(z + 1) * plane]
+    }
+
+    /// Max‑intensity projection along `z`: for every `(x, y)` the largest
+    /// `intensity(z, state)` on the ray, `0` where nothing scores.
+    ///
+    /// ```
+    /// use bevy::math::{IVec3, UVec3};
+    /// use engine_core::{core::CellState, engine::grid::DenseGrid3};
+    ///
+    /// let mut vol = DenseGrid3::blank(UVec3::new(2, 1, 4));
+    /// vol.get_mut(IVec3::new(1, 0, 2)).unwrap().state = CellState::Alive(1);
+    /// // depth cue: nearer (smaller z) voxels are brighter
+    /// let mip = vol.project_max(|z, s| if s == CellState::Dead { 0 } else { 4 - z as u8 });
+    /// assert_eq!(mip, vec![0, 2]);
+    /// ```
+    pub fn project_max(&self, intensity: impl Fn(u32, CellState) -> u8) -> Vec<u8> {
+        let plane = (self.size.x * self.size.y) as usize;
+        let mut out = vec![0u8; plane];
+        for z in 0..self.size.z {
+            for (o, c) in out.iter_mut().zip(self.slice(z)) {
+                *o = (*o).max(intensity(z, c.state));
+            }
+        }
+        out
+    }
+
+    /// Footprint of one `z` slice.
+    pub fn plane_size(&self) -> UVec2 { self.size.truncate() }
+}
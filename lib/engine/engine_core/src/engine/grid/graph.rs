@@ -0,0 +1,60 @@
+//! Graph grid – cells are the nodes of an arbitrary undirected graph
+//! (small‑world, scale‑free, random, loaded from an edge list, …).
+//!
+//! Rules see a node’s adjacency list as its neighbourhood, so its length
+//! is the node degree rather than 8.  `positions` is a 2‑D layout in the
+//! unit square that renderers draw into a `canvas`‑sized raster.
+
+use bevy::math::{IVec2, UVec2, Vec2};
+use serde::{Deserialize, Serialize};
+
+use crate::core::Cell;
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct GraphGrid {
+    pub cells:     Vec<Cell>,
+    /// `adjacency[i]` – neighbours of node `i` (symmetric, no self loops).
+    pub adjacency: Vec<Vec<u32>>,
+    /// Layout position of every node in `[0, 1]²`.
+    pub positions: Vec<Vec2>,
+    /// Raster size the layout is drawn into.
+    pub canvas:    UVec2,
+}
+
+impl GraphGrid {
+    /// Dead nodes laid out on a circle.
+    pub fn new(adjacency: Vec<Vec<u32>>, canvas: UVec2) -> Self {
+        let n = adjacency.len();
+        let positions = (0..n)
+            .map(|i| {
+                let a = std::f32::consts::TAU * i as f32 / n.max(1) as f32;
+                Vec2::splat(0.5) + 0.45 * Vec2::new(a.cos(), a.sin())
+            })
+            .collect();
+        Self { cells: vec![Cell::default(); n], adjacency, positions, canvas }
+    }
+
+    pub fn len(&self) -> usize { self.cells.len() }
+    pub fn is_empty(&self) -> bool { self.cells.is_empty() }
+
+    pub fn neighbours(&self, node: usize) -> &[u32] { &self.adjacency[node] }
+    pub fn degree(&self, node: usize) -> usize { self.adjacency[node].len() }
+
+    /// Every undirected edge once, as `(low, high)`.
+    pub fn edges(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.adjacency.iter().enumerate().flat_map(|(i, nbrs)| {
+            nbrs.iter().filter(move |&&j| j as usize > i).map(move |&j| (i as u32, j))
+        })
+    }
+
+    pub fn edge_count(&self) -> usize { self.edges().count() }
+
+    /// Node ids double as coordinates `(id, 0)` in `CellCtx::self_coord`.
+    pub fn coord(node: usize) -> IVec2 { IVec2::new(node as i32, 0) }
+
+    /// Raster pixel of `node` on the canvas.
+    pub fn pixel(&self, node: usize) -> IVec2 {
+        let max = (self.canvas.max(UVec2::ONE) - UVec2::ONE).as_vec2();
+        (self.positions[node].clamp(Vec2::ZERO, Vec2::ONE) * max).round().as_ivec2()
+    }
+}
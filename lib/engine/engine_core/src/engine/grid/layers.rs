@@ -0,0 +1,72 @@
+//! Named scalar layers that sit *next to* a grid (sugar, pheromone, …).
+//!
+//! A `CellState` only carries one byte per cell.  Models that need extra
+//! per‑cell quantities keep them here as dense `f32` planes that share the
+//! grid’s width × height.  Coordinates outside the plane read as `0.0` and
+//! writes to them are ignored, mirroring `DenseGrid::get`.
+
+use std::collections::HashMap;
+
+use bevy::math::{IVec2, UVec2};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CellLayers {
+    size:   UVec2,
+    planes: HashMap<String, Vec<f32>>,
+}
+
+impl CellLayers {
+    /// Empty layer stack for a `size.x × size.y` grid.
+    pub fn new(size: UVec2) -> Self {
+        Self { size, planes: HashMap::new() }
+    }
+
+    pub fn size(&self) -> UVec2 { self.size }
+
+    /// Adds (or resets) the layer `name`, filling every cell with `fill`.
+    pub fn add(&mut self, name: impl Into<String>, fill: f32) {
+        let len = (self.size.x * self.size.y) as usize;
+        self.planes.insert(name.into(), vec![fill; len]);
+    }
+
+    pub fn contains(&self, name: &str) -> bool { self.planes.contains_key(name) }
+
+    /// Iterator over all layer names.
+    pub fn names(&self) -> impl Iterator<Item = &String> { self.planes.keys() }
+
+    /// Whole plane in row‑major order.
+    pub fn plane(&self, name: &str) -> Option<&[f32]> {
+        self.planes.get(name).map(Vec::as_slice)
+    }
+
+    pub fn plane_mut(&mut self, name: &str) -> Option<&mut [f32]> {
+        self.planes.get_mut(name).map(Vec::as_mut_slice)
+    }
+
+    #[inline]
+    fn idx(&self, p: IVec2) -> Option<usize> {
+        if (0..self.size.x as i32).contains(&p.x) && (0..self.size.y as i32).contains(&p.y) {
+            Some((p.y as u32 * self.size.x + p.x as u32) as usize)
+        } else { None }
+    }
+
+    /// Value of `name` at `p` (`0.0` for unknown layers / out of bounds).
+    pub fn get(&self, name: &str, p: IVec2) -> f32 {
+        match (self.planes.get(name), self.idx(p)) {
+            (Some(plane), Some(i)) => plane[i],
+            _ => 0.0,
+        }
+    }
+
+    /// Writes `v` into layer `name` at `p`; the layer is created on demand.
+    pub fn set(&mut self, name: &str, p: IVec2, v: f32) {
+        let Some(i) = self.idx(p) else { return };
+        if !self.planes.contains_key(name) {
+            self.add(name, 0.0);
+        }
+        if let Some(plane) = self.planes.get_mut(name) {
+            plane[i] = v;
+        }
+    }
+}
@@ -1,12 +1,19 @@
 pub mod dense;
+pub mod dense3;
 pub mod sparse;
+pub mod graph;
+pub mod layers;
 
 pub use dense::DenseGrid;
+pub use dense3::DenseGrid3;
 pub use sparse::SparseGrid;
+pub use graph::GraphGrid;
+pub use layers::CellLayers;
 use serde::{Serialize, Deserialize};
 
 #[derive(Clone, Serialize, Deserialize)]
 pub enum GridBackend {
     Dense(DenseGrid),
     Sparse(SparseGrid),
+    Graph(GraphGrid),
 }
\ No newline at end of file
@@ -6,12 +6,50 @@ use crate::{
     engine::grid::DenseGrid,
 };
 
+/// Largest stencil a 2‑D lattice may have (Moore‑8).
+const MAX_NEIGHBOURS: usize = 8;
+
 #[inline(always)]
 pub fn step_dense<R: AutomatonRule<D = Dim2>>(
     grid:   &mut DenseGrid,
     rule:   &R,
     params: &Value,
 ) {
+    step_lattice(grid, rule, params);
+}
+
+/// One synchronous step of a dense grid on any 2‑D lattice `R::D` – square,
+/// [`DimHex`](crate::core::DimHex) or [`DimTri`](crate::core::DimTri).  The
+/// neighbourhood follows `D::offsets_at`; cells past the border are dead.
+///
+/// ```
+/// use bevy::math::UVec2;
+/// use engine_core::{
+///     core::{AutomatonRule, CellCtx, CellOutcome, CellState, DimHex},
+///     engine::{grid::DenseGrid, stepper::dense::step_lattice},
+/// };
+///
+/// /// Live cells count their live hex neighbours.
+/// struct Count;
+/// impl AutomatonRule for Count {
+///     type D = DimHex;
+///     fn next_state(&self, ctx: CellCtx<DimHex>, _: &serde_json::Value) -> CellOutcome {
+///         let n = ctx.neighbourhood.iter().filter(|s| **s != CellState::Dead).count();
+///         CellOutcome::Next { state: CellState::Alive(n as u8 + 1), memory: ctx.memory.clone() }
+///     }
+/// }
+///
+/// let mut grid = DenseGrid::blank(UVec2::new(5, 5));
+/// step_lattice(&mut grid, &Count, &serde_json::Value::Null);
+/// // an interior cell has six neighbours, all alive after the first step
+/// step_lattice(&mut grid, &Count, &serde_json::Value::Null);
+/// assert_eq!(grid.cells[2 * 5 + 2].state, CellState::Alive(7));
+/// ```
+pub fn step_lattice<R>(grid: &mut DenseGrid, rule: &R, params: &Value)
+where
+    R: AutomatonRule + ?Sized,
+    R::D: Dim<Coord = IVec2>,
+{
     let snapshot = grid.cells.clone();
     let mut next = snapshot.clone();
 
@@ -20,9 +58,10 @@ pub fn step_dense<R: AutomatonRule<D = Dim2>>(
             let p   = IVec2::new(x, y);
             let idx = grid.idx(p);
 
-            /* build Moore‑8 neighbourhood */
-            let mut nbhd = [CellState::Dead; 8];
-            for (i, off) in Dim2::NEIGHBOUR_OFFSETS.iter().enumerate() {
+            /* build the lattice neighbourhood */
+            let offsets  = <R::D as Dim>::offsets_at(p);
+            let mut nbhd = [CellState::Dead; MAX_NEIGHBOURS];
+            for (i, off) in offsets.iter().enumerate() {
                 let q = p + *off;
                 if (0..grid.size.x as i32).contains(&q.x)
                     && (0..grid.size.y as i32).contains(&q.y)
@@ -34,9 +73,9 @@ pub fn step_dense<R: AutomatonRule<D = Dim2>>(
             let ctx = CellCtx {
                 self_coord: p,
                 self_state: snapshot[idx].state,
-                neighbourhood: &nbhd,
+                neighbourhood: &nbhd[..offsets.len()],
                 memory: &snapshot[idx].memory,
-                _marker: std::marker::PhantomData::<Dim2>,
+                _marker: std::marker::PhantomData::<R::D>,
             };
 
             if let CellOutcome::Next { state, memory } = rule.next_state(ctx, params) {
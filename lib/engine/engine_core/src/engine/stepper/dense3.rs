@@ -0,0 +1,82 @@
+use bevy::math::IVec3;
+use serde_json::Value;
+
+use crate::{
+    core::{AutomatonRule, CellCtx, CellOutcome, CellState, Dim},
+    engine::grid::DenseGrid3,
+};
+
+/// Largest 3‑D stencil (Moore‑26).
+const MAX_NEIGHBOURS: usize = 26;
+
+/// One synchronous step of a volume under a 3‑D rule – Moore‑26
+/// ([`Dim3`](crate::core::Dim3)) or the six faces
+/// ([`Dim3VonNeumann`](crate::core::Dim3VonNeumann)).  Cells past the
+/// border are dead.
+///
+/// ```
+/// use bevy::math::{IVec3, UVec3};
+/// use engine_core::{
+///     core::{AutomatonRule, CellCtx, CellOutcome, CellState, Dim3VonNeumann},
+///     engine::{grid::DenseGrid3, stepper::dense3::step_dense3},
+/// };
+///
+/// /// A cell lights up next to any live face neighbour.
+/// struct Spread;
+/// impl AutomatonRule for Spread {
+///     type D = Dim3VonNeumann;
+///     fn next_state(&self, ctx: CellCtx<Dim3VonNeumann>, _: &serde_json::Value) -> CellOutcome {
+///         if ctx.neighbourhood.iter().any(|s| *s != CellState::Dead) {
+///             CellOutcome::Next { state: CellState::Alive(1), memory: ctx.memory.clone() }
+///         } else {
+///             CellOutcome::Unchanged
+///         }
+///     }
+/// }
+///
+/// let mut vol = DenseGrid3::blank(UVec3::splat(5));
+/// vol.get_mut(IVec3::splat(2)).unwrap().state = CellState::Alive(1);
+/// step_dense3(&mut vol, &Spread, &serde_json::Value::Null);
+/// assert_eq!(vol.population(), 7);   // the seed and its six faces
+/// ```
+pub fn step_dense3<R>(grid: &mut DenseGrid3, rule: &R, params: &Value)
+where
+    R: AutomatonRule + ?Sized,
+    R::D: Dim<Coord = IVec3>,
+{
+    let snapshot = grid.cells.clone();
+    let mut next = snapshot.clone();
+
+    for z in 0..grid.size.z as i32 {
+        for y in 0..grid.size.y as i32 {
+            for x in 0..grid.size.x as i32 {
+                let p   = IVec3::new(x, y, z);
+                let idx = grid.idx(p);
+
+                let offsets  = <R::D as Dim>::offsets_at(p);
+                let mut nbhd = [CellState::Dead; MAX_NEIGHBOURS];
+                for (i, off) in offsets.iter().enumerate() {
+                    let q = p + *off;
+                    if grid.contains(q) {
+                        nbhd[i] = snapshot[grid.idx(q)].state;
+                    }
+                }
+
+                let ctx = CellCtx {
+                    self_coord: p,
+                    self_state: snapshot[idx].state,
+                    neighbourhood: &nbhd[..offsets.len()],
+                    memory: &snapshot[idx].memory,
+                    _marker: std::marker::PhantomData::<R::D>,
+                };
+
+                if let CellOutcome::Next { state, memory } = rule.next_state(ctx, params) {
+                    next[idx].state  = state;
+                    next[idx].memory = memory;
+                }
+            }
+        }
+    }
+
+    grid.cells = next;
+}
@@ -0,0 +1,34 @@
+use crate::{
+    core::{AutomatonRule, CellCtx, CellOutcome, CellState, Dim2},
+    engine::grid::GraphGrid,
+};
+
+/// One synchronous step of every node; the neighbourhood is the node’s
+/// adjacency list in stored order.
+#[inline(always)]
+pub fn step_graph<R: AutomatonRule<D = Dim2> + ?Sized>(
+    grid:   &mut GraphGrid,
+    rule:   &R,
+    params: &serde_json::Value,
+) {
+    let snapshot = grid.cells.clone();
+    let mut nbhd: Vec<CellState> = Vec::new();
+
+    for (i, cell) in grid.cells.iter_mut().enumerate() {
+        nbhd.clear();
+        nbhd.extend(grid.adjacency[i].iter().map(|&j| snapshot[j as usize].state));
+
+        let ctx = CellCtx {
+            self_coord:    GraphGrid::coord(i),
+            self_state:    snapshot[i].state,
+            neighbourhood: &nbhd,
+            memory:        &snapshot[i].memory,
+            _marker:       std::marker::PhantomData::<Dim2>,
+        };
+
+        if let CellOutcome::Next { state, memory } = rule.next_state(ctx, params) {
+            cell.state  = state;
+            cell.memory = memory;
+        }
+    }
+}
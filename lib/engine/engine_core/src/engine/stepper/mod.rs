@@ -1,5 +1,7 @@
 pub mod dense;
+pub mod dense3;
 pub mod sparse;
+pub mod graph;
 pub mod plugin;
 pub mod dense_parallel;
 
@@ -8,7 +8,7 @@ use bevy::{
 };
 use crate::{
     core::{AutomatonRule, Dim2, World2D},
-    engine::{grid::GridBackend, stepper::{dense::step_dense, sparse::step_sparse, RuleParams}}, state::AppState,
+    engine::{grid::GridBackend, stepper::{dense::step_dense, graph::step_graph, sparse::step_sparse, RuleParams}}, state::AppState,
 };
 
 pub struct StepperPlugin<R: Resource + Clone> {
@@ -39,5 +39,6 @@ fn step_world<R: AutomatonRule<D = Dim2> + Resource>(
     match &mut world.backend {
         GridBackend::Dense(g)  => step_dense(g, &*rule, &params.0),
         GridBackend::Sparse(g) => step_sparse(g, &*rule, &params.0),
+        GridBackend::Graph(g)  => step_graph(g, &*rule, &params.0),
     }
 }
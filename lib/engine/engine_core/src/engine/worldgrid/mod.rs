@@ -62,6 +62,7 @@ impl WorldGrid {
         match &mut self.backend {
             GridBackend::Dense(g)  => if let Some(c) = g.get_mut(world) { c.state = state; },
             GridBackend::Sparse(s) => s.set_state(world, state),
+            GridBackend::Graph(_)  => {}
         }
     }
 
@@ -26,6 +26,7 @@ use bevy::{
 
 use computational_intelligence::registry::AutomataRegistry;
 use engine_core::{
+    core::{cell::CellState, Lattice},
     engine::{camera_manager::WORLD_LAYER, components::WorldCamera, grid::GridBackend},
     events::{AutomatonAdded, AutomatonId, AutomatonRemoved}, state::AppState,
 };
@@ -109,14 +110,15 @@ fn handle_automata_added(
         let (grid_w, grid_h) = match &info.grid {
             GridBackend::Dense(g)  => (g.size.x, g.size.y),
             GridBackend::Sparse(_) => (512, 512),
+            GridBackend::Graph(g)  => (g.canvas.x.max(1), g.canvas.y.max(1)),
         };
 
         /* 2 ── texture -------------------------------------------------- */
         let mut image = Image::new_fill(
             Extent3d { width: grid_w, height: grid_h, depth_or_array_layers: 1 },
             TextureDimension::D2,
-            &[0u8],                           // start fully “dead”
-            TextureFormat::R8Unorm,
+            &[0u8; 4],                        // start fully “dead”
+            TextureFormat::Rgba8UnormSrgb,
             RenderAssetUsages::default(),
         );
         image.sampler = ImageSampler::nearest();
@@ -130,14 +132,14 @@ fn handle_automata_added(
                 cell_size:    info.cell_size,
                 texture_size: Vec2::new(grid_w as f32, grid_h as f32),
                 dead_color:   Vec4::new(0.0, 0.0, 0.0, 0.0), // ← fully transparent
-                alive_color:  Vec4::ONE,
+                lattice:      info.lattice.shader_id(),
             },
             grid_texture: tex.clone(),
         });
 
         /* 4 ── quad layout --------------------------------------------- */
-        let w_world = grid_w as f32 * info.cell_size;
-        let h_world = grid_h as f32 * info.cell_size;
+        let w_world = (grid_w as f32 + row_overhang(info.lattice)) * info.cell_size;
+        let h_world = grid_h as f32 * info.cell_size * row_pitch(info.lattice);
         // convert the IVec2 world offset (cells) to world‑space units
         let world_off = info.world_offset.as_vec2() * info.cell_size;
 
@@ -147,7 +149,7 @@ fn handle_automata_added(
                 Mesh2d(meshes.add(Mesh::from(Rectangle::from_size(Vec2::ONE))).into()),
                 Transform {
                     translation: Vec3::new(
-                        world_off.x + w_world * 0.5,   // centred inside its slice
+                        world_off.x + w_world * 0.5,   // left edge on the slice, overhang to the right
                         world_off.y + h_world * 0.5,
                         1.0,
                     ),
@@ -171,6 +173,26 @@ fn handle_automata_added(
     }
 }
 
+/// Cells the rows of `lattice` reach past the grid width: odd hex rows are
+/// shifted by half a cell, and triangles are two texels wide.
+fn row_overhang(lattice: Lattice) -> f32 {
+    match lattice {
+        Lattice::Square     => 0.0,
+        Lattice::Hex        => 0.5,
+        Lattice::Triangular => 1.0,
+    }
+}
+
+/// Height of a cell row in texel widths (must match the shader): hex rows
+/// interlock `√3⁄2` apart, triangles are two texels wide and `√3` tall.
+fn row_pitch(lattice: Lattice) -> f32 {
+    match lattice {
+        Lattice::Square     => 1.0,
+        Lattice::Hex        => 3f32.sqrt() * 0.5,
+        Lattice::Triangular => 3f32.sqrt(),
+    }
+}
+
 /// Despawns quads + frees GPU assets.
 fn handle_automata_removed(
     mut commands:   Commands,
@@ -188,26 +210,44 @@ fn handle_automata_removed(
     }
 }
 
+/// RGBA for every cell state: dead → transparent, `Alive(s)` → palette
+/// entry `s − 1` (last entry beyond the end), white without a palette.
+pub(crate) fn state_colours(palette: Option<&[Color]>) -> [[u8; 4]; 256] {
+    let mut lut = [[255u8; 4]; 256];
+    lut[0] = [0; 4];
+    if let Some(palette) = palette.filter(|p| !p.is_empty()) {
+        for (s, rgba) in lut.iter_mut().enumerate().skip(1) {
+            *rgba = palette[(s - 1).min(palette.len() - 1)].to_srgba().to_u8_array();
+        }
+    }
+    lut
+}
+
+/// Entry of `s` in a [`state_colours`] table.
+pub(crate) fn state_index(s: CellState) -> usize {
+    match s {
+        CellState::Dead     => 0,
+        CellState::Alive(v) => v.max(1) as usize,
+    }
+}
+
 /// CPU fallback – uploads the current grid into each automaton texture.
 fn upload_all_automata(
     automata_registry: Res<AutomataRegistry>,
     render_map:        Res<AutomataRenderMap>,
     mut images:        ResMut<Assets<Image>>,
 ) {
-    use engine_core::core::cell::CellState;
-
     for info in automata_registry.list() {
         let Some((_, tex, _)) = render_map.map.get(&info.id) else { continue };
         let Some(img)         = images.get_mut(tex)           else { continue };
+        let lut = state_colours(info.palette.as_deref());
 
         if let Some(buf) = img.data.as_mut() {
             buf.fill(0);
             match &info.grid {
                 GridBackend::Dense(g) => {
-                    for (i, cell) in g.cells.iter().enumerate() {
-                        if !matches!(cell.state, CellState::Dead) {
-                            buf[i] = 255;
-                        }
+                    for (px, cell) in buf.chunks_exact_mut(4).zip(&g.cells) {
+                        px.copy_from_slice(&lut[state_index(cell.state)]);
                     }
                 }
                 GridBackend::Sparse(s) => {
@@ -219,11 +259,54 @@ fn upload_all_automata(
                             && (0..h).contains(&pos.y)
                         {
                             let idx = (pos.y as u32 * img.texture_descriptor.size.width
-                                + pos.x as u32) as usize;
-                            buf[idx] = 255;
+                                + pos.x as u32) as usize * 4;
+                            buf[idx..idx + 4].copy_from_slice(&lut[state_index(cell.state)]);
                         }
                     }
                 }
+                GridBackend::Graph(g) => {
+                    let size = img.texture_descriptor.size;
+                    draw_graph(buf, UVec2::new(size.width, size.height), g, |s| lut[state_index(s)]);
+                }
+            }
+        }
+    }
+}
+
+/// Edges as faint lines, nodes as 3 × 3 dots; dead nodes stay visible in
+/// grey so the topology reads even when nothing is alive.
+fn draw_graph(
+    buf:    &mut [u8],
+    size:   UVec2,
+    graph:  &engine_core::engine::grid::GraphGrid,
+    colour: impl Fn(engine_core::core::cell::CellState) -> [u8; 4],
+) {
+    const EDGE: [u8; 4] = [90, 90, 100, 110];
+    const IDLE: [u8; 4] = [120, 120, 130, 255];
+
+    let mut put = |p: IVec2, rgba: [u8; 4]| {
+        if (0..size.x as i32).contains(&p.x) && (0..size.y as i32).contains(&p.y) {
+            let i = (p.y as u32 * size.x + p.x as u32) as usize * 4;
+            buf[i..i + 4].copy_from_slice(&rgba);
+        }
+    };
+
+    for (a, b) in graph.edges() {
+        let (p, q) = (graph.pixel(a as usize), graph.pixel(b as usize));
+        let steps  = (q - p).abs().max_element().max(1);
+        for t in 0..=steps {
+            put(p + (q - p) * t / steps, EDGE);
+        }
+    }
+    for (i, cell) in graph.cells.iter().enumerate() {
+        let rgba = match cell.state {
+            engine_core::core::cell::CellState::Dead => IDLE,
+            s => colour(s),
+        };
+        let c = graph.pixel(i);
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                put(c + IVec2::new(dx, dy), rgba);
             }
         }
     }
@@ -7,11 +7,16 @@ use engine_core::core::{world::World2D, cell::CellState};
 use engine_core::engine::grid::GridBackend;
 use std::collections::HashSet;
 
-use super::plugin::ActiveImageHandle;
+use super::plugin::{state_colours, state_index, ActiveImageHandle};
 
 #[derive(Resource, Default)]
 pub struct PrevLive(pub HashSet<IVec2>);
 
+/// Palette the uploaders paint live cells with (white without one), looked
+/// up exactly like the active renderer does.
+#[derive(Resource, Default)]
+pub struct UploadPalette(pub Option<Vec<Color>>);
+
 /* --------------------------------------------------------------------- */
 
 /// Dense‑grid upload (full rewrite every frame).
@@ -20,27 +25,32 @@ pub fn upload_dense(
     world: Res<World2D>,
     mut images: ResMut<Assets<Image>>,
     image_handle: Res<ActiveImageHandle>,
+    palette: Option<Res<UploadPalette>>,
 ) {
+    let lut = state_colours(palette.as_ref().and_then(|p| p.0.as_deref()));
     if let GridBackend::Dense(grid) = &world.backend {
         if let Some(buf) = images
             .get_mut(&image_handle.0)
             .and_then(|img| img.data.as_mut())
-            .filter(|b| b.len() == grid.cells.len())
+            .filter(|b| b.len() == grid.cells.len() * 4)
         {
-            for (idx, cell) in grid.cells.iter().enumerate() {
-                buf[idx] = if matches!(cell.state, CellState::Dead) { 0 } else { 255 };
+            for (px, cell) in buf.chunks_exact_mut(4).zip(&grid.cells) {
+                px.copy_from_slice(&lut[state_index(cell.state)]);
             }
         }
     }
 }
 
-/// Sparse‑grid upload (differential).
+/// Sparse‑grid upload (differential: only cells that died are cleared,
+/// live ones are repainted since their state may have changed).
 pub fn upload_sparse(
     world: Res<World2D>,
     mut images: ResMut<Assets<Image>>,
     image_handle: Res<ActiveImageHandle>,
     mut prev: ResMut<PrevLive>,
+    palette: Option<Res<UploadPalette>>,
 ) {
+    let lut = state_colours(palette.as_ref().and_then(|p| p.0.as_deref()));
     if let GridBackend::Sparse(sparse) = &world.backend {
         if let Some(image) = images.get_mut(&image_handle.0) {
             let tex_w = image.texture_descriptor.size.width as i32;
@@ -62,19 +72,16 @@ pub fn upload_sparse(
                         && (0..tex_w).contains(&pos.x)
                         && (0..tex_h).contains(&pos.y)
                     {
-                        let idx = (pos.y as u32 * tex_w as u32 + pos.x as u32) as usize;
-                        buf[idx] = 0;
+                        let idx = (pos.y as u32 * tex_w as u32 + pos.x as u32) as usize * 4;
+                        buf[idx..idx + 4].fill(0);
                     }
                 }
 
-                /* set pixels that were born */
+                /* paint live pixels */
                 for &pos in &current_live {
-                    if !prev.0.contains(&pos)
-                        && (0..tex_w).contains(&pos.x)
-                        && (0..tex_h).contains(&pos.y)
-                    {
-                        let idx = (pos.y as u32 * tex_w as u32 + pos.x as u32) as usize;
-                        buf[idx] = 255;
+                    if (0..tex_w).contains(&pos.x) && (0..tex_h).contains(&pos.y) {
+                        let idx = (pos.y as u32 * tex_w as u32 + pos.x as u32) as usize * 4;
+                        buf[idx..idx + 4].copy_from_slice(&lut[state_index(sparse.map[&pos].state)]);
                     }
                 }
 
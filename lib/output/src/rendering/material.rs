@@ -53,10 +53,11 @@ pub struct AutomataParams {
     pub cell_size:    f32,
     /// Size of the grid texture in texels.
     pub texture_size: Vec2,
-    /// RGBA colour for a dead cell.
+    /// RGBA colour shown where the texture is transparent (dead cells).
     pub dead_color:   Vec4,
-    /// RGBA colour for a live cell.
-    pub alive_color:  Vec4,
+    /// Cell shape – [`Lattice::shader_id`](engine_core::core::Lattice::shader_id):
+    /// 0 squares, 1 odd‑r hexagons, 2 alternating triangles.
+    pub lattice:      u32,
 }
 
 /* ─────────────────────────── Material2d impl ────────────────────────────── */
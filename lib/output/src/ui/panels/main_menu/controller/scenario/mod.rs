@@ -1,2 +1,3 @@
 pub mod new;
 pub mod load;
+pub mod params;
@@ -16,6 +16,7 @@ use crate::ui::{
         main_menu::model::{GridType, Rgba, ScenarioDraft},
     },
 };
+use super::params::json_editor;
 
 /// Screen resource.
 #[derive(Resource, Default)]
@@ -102,6 +103,16 @@ impl MenuScreen for NewScenario {
                             let classical_options = [
                                 ("wolfram:rule30", "Wolfram Rule 30"),
                                 ("wolfram:rule110", "Wolfram Rule 110"),
+                                ("lsystem:koch", "L‑system – Koch snowflake"),
+                                ("lsystem:plant", "L‑system – fractal plant"),
+                                ("lsystem:bush", "L‑system – stochastic bush"),
+                                ("lsystem:tree", "L‑system – parametric tree"),
+                                ("lsystem:signal", "L‑system – context‑sensitive"),
+                                ("lba:anbncn", "LBA – aⁿbⁿcⁿ"),
+                                ("lba:counter", "LBA – binary counter"),
+                                ("tm:busy_beaver", "Turing machine – 4‑state busy beaver"),
+                                ("tm:palindrome", "Turing machine – 2‑tape palindromes"),
+                                ("tm:universal", "Turing machine – universal"),
                             ];
                             for (id, label) in classical_options {
                                 let mut sel =
@@ -130,6 +141,28 @@ impl MenuScreen for NewScenario {
                                 ("lenia", "Lenia (blob)"),
                                 ("lenia:orbium", "Lenia – Orbium"),
                                 ("particle:hpp", "Particle HPP"),
+                                ("swarm:turmite", "Turmite (Langton's ant)"),
+                                ("swarm:ants", "Ant colony"),
+                                ("swarm:boids", "Boids"),
+                                ("sugarscape", "Sugarscape"),
+                                ("wator", "Wa-Tor (fish & sharks)"),
+                                ("schelling", "Schelling segregation"),
+                                ("traffic:nasch", "Traffic (Nagel–Schreckenberg)"),
+                                ("games:pd", "Spatial prisoner's dilemma"),
+                                ("games:pd:kaleidoscope", "Prisoner's dilemma – kaleidoscope"),
+                                ("spin:ising", "Ising model"),
+                                ("spin:potts", "q-state Potts model"),
+                                ("kuramoto", "Kuramoto oscillators"),
+                                ("kuramoto:spirals", "Kuramoto – spiral waves"),
+                                ("rbn", "Random Boolean network (NK)"),
+                                ("graph:life", "Life-like rule on a graph"),
+                                ("graph:voter", "Voter model on a graph"),
+                                ("hex:life", "Life B2/S34 on hexagons"),
+                                ("hex:snowflake", "Packard snowflake on hexagons"),
+                                ("hex:reiter", "Reiter snowflake growth"),
+                                ("tri:life", "Life‑like B1/S on triangles"),
+                                ("life3d:4555", "3‑D Life 4555 (slice viewer)"),
+                                ("life3d:clouds", "3‑D Clouds rule (projection)"),
                             ];
 
                             for (id, label) in dynamical_options {
@@ -142,6 +175,17 @@ impl MenuScreen for NewScenario {
                             ui.radio_value(&mut self.model.selected_dynamical, None, "None");
                         });
 
+                        /* Parameters of the selected rules (if they expose any) */
+                        let selected = self.model.selected_classical.iter()
+                            .chain(self.model.selected_dynamical.as_ref());
+                        for id in selected {
+                            if let Some(params) = self.model.rule_params.get_mut(id) {
+                                ui.collapsing(format!("Parameters – {id}"), |ui| {
+                                    json_editor(ui, id, params);
+                                });
+                            }
+                        }
+
                         ui.separator();
 
                         /* ── Action buttons ─────────────────────────── */
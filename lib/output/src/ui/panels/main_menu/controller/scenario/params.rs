@@ -0,0 +1,65 @@
+//! Generic editor for the JSON parameters rules expose through
+//! `RuleRegistry::register_params`, plus the hand‑over into the registry
+//! when the simulation starts.
+
+use bevy::prelude::*;
+use bevy_egui::egui;
+use computational_intelligence::registry::RuleRegistry;
+use serde_json::Value;
+
+use super::new::ScenarioMeta;
+
+/// Decimals shown for float parameters; edits are rounded to them so an
+/// `f32` field stores `0.31` rather than its `f64` widening.
+const DECIMALS: i32 = 4;
+
+/// Recursively edits `value` in place: numbers → drag values, booleans →
+/// checkboxes, strings → text fields, arrays / objects → nested grids.
+pub fn json_editor(ui: &mut egui::Ui, salt: &str, value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            egui::Grid::new(salt).num_columns(2).striped(true).show(ui, |ui| {
+                for (key, v) in map.iter_mut() {
+                    ui.label(key);
+                    json_editor(ui, &format!("{salt}.{key}"), v);
+                    ui.end_row();
+                }
+            });
+        }
+        Value::Array(items) => {
+            ui.horizontal_wrapped(|ui| {
+                for (i, v) in items.iter_mut().enumerate() {
+                    json_editor(ui, &format!("{salt}[{i}]"), v);
+                }
+            });
+        }
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                let mut i = i;
+                if ui.add(egui::DragValue::new(&mut i)).changed() {
+                    *value = Value::from(i);
+                }
+            } else if let Some(f) = n.as_f64() {
+                let mut f = f;
+                if ui.add(egui::DragValue::new(&mut f).speed(0.01).max_decimals(DECIMALS as usize)).changed() {
+                    let scale = 10f64.powi(DECIMALS);
+                    *value = Value::from((f * scale).round() / scale);
+                }
+            }
+        }
+        Value::Bool(b)   => { ui.checkbox(b, ""); }
+        Value::String(s) => { ui.text_edit_singleline(s); }
+        Value::Null      => { ui.weak("–"); }
+    }
+}
+
+/// Pushes the edited parameters of the scenario into the [`RuleRegistry`]
+/// so every automaton spawned from now on picks them up.  Rules the
+/// scenario does not mention fall back to their defaults, so edits never
+/// leak from one scenario into the next.
+pub fn apply_rule_params(meta: Res<ScenarioMeta>, mut rules: ResMut<RuleRegistry>) {
+    rules.reset_params();
+    for (id, params) in &meta.0.rule_params {
+        rules.set_params(id, params.clone());
+    }
+}
@@ -3,6 +3,7 @@
 use bevy::prelude::*;
 use bevy_egui::{EguiPlugin, EguiPrimaryContextPass};
 
+use computational_intelligence::registry::RuleRegistry;
 use engine_core::state::AppState;
 use super::{
     ui_runner, 
@@ -42,8 +43,13 @@ impl Plugin for MainMenuPlugin {
             })
 
             // ── NEW SCENARIO ─────────────────────────────────────────────
-            .add_systems(OnEnter(AppState::NewScenario), |mut commands: Commands| {
-                commands.insert_resource(NewScenario::default());
+            .add_systems(OnEnter(AppState::NewScenario), |mut commands: Commands, rules: Res<RuleRegistry>| {
+                let mut screen = NewScenario::default();
+                screen.model.rule_params = rules
+                    .params_iter()
+                    .map(|(id, p)| (id.clone(), p.clone()))
+                    .collect();
+                commands.insert_resource(screen);
             })
             .add_systems(
                 EguiPrimaryContextPass,
@@ -98,7 +104,10 @@ impl Plugin for MainMenuPlugin {
             // ── IN-GAME INITIALIZATION ───────────────────────────────────
             .add_systems(
                 OnEnter(AppState::InGame),
-                controller::scenario::new::init_new_world
+                (
+                    controller::scenario::new::init_new_world,
+                    controller::scenario::params::apply_rule_params,
+                ),
             );
     }
 }
\ No newline at end of file
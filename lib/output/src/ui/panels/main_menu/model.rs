@@ -1,6 +1,9 @@
 //! Data‑only “model” layer for the main‑menu screens.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// Dense versus sparse grid back‑end.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -41,6 +44,9 @@ pub struct ScenarioDraft {
     pub selected_classical: Vec<String>,
     /// *Exactly one* dynamical automaton (or `None`)
     pub selected_dynamical: Option<String>,
+    /// Per‑rule parameters, pre‑filled from the rule registry’s defaults.
+    #[serde(default)]
+    pub rule_params:        BTreeMap<String, Value>,
 }
 
 impl Default for ScenarioDraft {
@@ -54,6 +60,7 @@ impl Default for ScenarioDraft {
             bg_color: Rgba::default(),
             selected_classical: Vec::new(),
             selected_dynamical: None,
+            rule_params: BTreeMap::new(),
         }
     }
 }
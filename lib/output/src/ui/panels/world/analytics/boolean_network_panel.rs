@@ -0,0 +1,123 @@
+//! “Boolean network” window – draws the selected NK network with its live
+//! node states and runs the attractor / Derrida analyses on demand.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use computational_intelligence::{
+    automata::dynamical::boolean_network::{
+        Attractor, BooleanNetwork, BooleanNetworks, MAX_ENUMERABLE, RBN_ID,
+    },
+    registry::AutomataRegistry,
+};
+use engine_core::events::AutomatonId;
+
+use crate::ui::panels::world::minimap_overlay::MinimapSelection;
+use super::plot::xy_plot;
+
+/// Random pairs per distance of the Derrida plot.
+const DERRIDA_SAMPLES: usize = 200;
+/// Attractors listed before “… and n more”.
+const LISTED: usize = 8;
+
+/// Analyses of the last inspected network.
+#[derive(Default)]
+pub struct LastAnalysis {
+    id:         Option<AutomatonId>,
+    attractors: Option<Vec<Attractor>>,
+    derrida:    Option<Vec<(f64, f64)>>,
+}
+
+pub fn boolean_network_panel(
+    automata:     Res<AutomataRegistry>,
+    networks:     Res<BooleanNetworks>,
+    sel:          Res<MinimapSelection>,
+    mut egui_ctx: EguiContexts,
+    mut last:     Local<LastAnalysis>,
+) {
+    let Ok(ctx) = egui_ctx.ctx_mut() else { return };
+
+    /* selected network, else the first one */
+    let info = sel.0
+        .and_then(|id| automata.get(id))
+        .filter(|a| a.name == RBN_ID)
+        .or_else(|| automata.find_by_name(RBN_ID));
+    let Some(info) = info else { return };
+    let Some(run) = networks.0.get(&info.id) else { return };
+    if last.id != Some(info.id) {
+        *last = LastAnalysis { id: Some(info.id), ..default() };
+    }
+
+    egui::Window::new("Boolean network")
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -40.0])
+        .resizable(false)
+        .default_open(false)
+        .show(ctx, |ui| {
+            let on = run.state.iter().filter(|b| **b).count();
+            ui.label(format!("#{} · N {} · {} on", info.id.0, run.network.len(), on));
+            draw_network(ui, &run.network, &run.state, egui::vec2(220.0, 220.0));
+
+            ui.horizontal(|ui| {
+                let small = run.network.len() <= MAX_ENUMERABLE;
+                if ui.add_enabled(small, egui::Button::new("Attractors")).clicked() {
+                    last.attractors = run.network.attractors();
+                }
+                if ui.button("Derrida plot").clicked() {
+                    last.derrida = Some(run.network.derrida(DERRIDA_SAMPLES, &mut rand::rng()));
+                }
+            });
+
+            if let Some(att) = &last.attractors {
+                ui.separator();
+                let total = 1u64 << run.network.len();
+                ui.label(format!("{} attractor(s)", att.len()));
+                let current = BooleanNetwork::encode(&run.state);
+                for a in att.iter().take(LISTED) {
+                    let here = if a.cycle.contains(&current) { "  ◀ now" } else { "" };
+                    ui.monospace(format!(
+                        "period {:>4} · basin {:>5.1} %{here}",
+                        a.period(), 100.0 * a.basin as f64 / total as f64,
+                    ));
+                }
+                if att.len() > LISTED {
+                    ui.label(format!("… and {} more", att.len() - LISTED));
+                }
+            }
+
+            if let Some(pts) = &last.derrida {
+                ui.separator();
+                xy_plot(ui, pts, egui::vec2(220.0, 140.0));
+                if let Some(&(x, y)) = pts.first() {
+                    ui.label(format!("slope at origin ≈ {:.2}", y / x));
+                }
+            }
+        });
+}
+
+/// Nodes on a circle, coloured by state, with a faint line per input wire.
+fn draw_network(ui: &mut egui::Ui, net: &BooleanNetwork, state: &[bool], size: egui::Vec2) {
+    let (resp, painter) = ui.allocate_painter(size, egui::Sense::hover());
+    let rect = resp.rect;
+    painter.rect_filled(rect, 2.0, egui::Color32::from_gray(24));
+
+    let n      = net.len().max(1);
+    let centre = rect.center();
+    let radius = rect.width().min(rect.height()) * 0.42;
+    let pos    = |i: usize| {
+        let a = TAU * i as f32 / n as f32;
+        centre + radius * egui::vec2(a.cos(), a.sin())
+    };
+
+    let wire = egui::Stroke::new(0.6, egui::Color32::from_white_alpha(40));
+    for i in 0..net.len() {
+        for &j in net.inputs(i) {
+            painter.line_segment([pos(j), pos(i)], wire);
+        }
+    }
+    let dot = (radius * 0.6 / n as f32 * TAU / 2.0).clamp(1.5, 6.0);
+    for (i, on) in state.iter().enumerate() {
+        let fill = if *on { egui::Color32::from_rgb(255, 216, 76) } else { egui::Color32::from_rgb(38, 46, 76) };
+        painter.circle(pos(i), dot, fill, egui::Stroke::new(0.5, egui::Color32::GRAY));
+    }
+}
@@ -0,0 +1,89 @@
+//! “Fundamental diagram” window – sweeps the density of the selected
+//! traffic automaton’s road and plots flow against density.
+
+use bevy::{
+    prelude::*,
+    tasks::{block_on, AsyncComputeTaskPool, Task},
+};
+use bevy_egui::{egui, EguiContexts};
+use computational_intelligence::{
+    analytics::fundamental_diagram::{fundamental_diagram, to_csv, FlowSample},
+    automata::dynamical::traffic::{TrafficParams, TRAFFIC_ID},
+    registry::AutomataRegistry,
+};
+use engine_core::{engine::grid::GridBackend, events::AutomatonId};
+
+use crate::ui::panels::world::minimap_overlay::MinimapSelection;
+use super::plot::xy_plot;
+
+/// Densities probed per sweep and ticks of warm‑up / averaging per point.
+const POINTS:  usize = 40;
+const WARMUP:  usize = 200;
+const MEASURE: usize = 200;
+
+/// Result of the last sweep and the one still running off‑frame, each with
+/// the automaton it belongs to.
+#[derive(Default)]
+pub struct LastSweep {
+    done:    Option<(AutomatonId, Vec<FlowSample>)>,
+    running: Option<(AutomatonId, Task<Vec<FlowSample>>)>,
+}
+
+pub fn fundamental_diagram_panel(
+    automata:     Res<AutomataRegistry>,
+    sel:          Res<MinimapSelection>,
+    mut egui_ctx: EguiContexts,
+    mut sweep:    Local<LastSweep>,
+) {
+    let Ok(ctx) = egui_ctx.ctx_mut() else { return };
+
+    if sweep.running.as_ref().is_some_and(|(_, task)| task.is_finished()) {
+        let (id, task) = sweep.running.take().unwrap();
+        sweep.done = Some((id, block_on(task)));
+    }
+
+    /* selected road, else the first one */
+    let road = sel.0
+        .and_then(|id| automata.get(id))
+        .filter(|a| a.name == TRAFFIC_ID)
+        .or_else(|| automata.find_by_name(TRAFFIC_ID));
+    let Some(info) = road else { return };
+
+    egui::Window::new("Fundamental diagram")
+        .anchor(egui::Align2::LEFT_BOTTOM, [10.0, -40.0])
+        .resizable(false)
+        .default_open(false)
+        .show(ctx, |ui| {
+            let p = TrafficParams::from_value(&info.params);
+            ui.label(format!(
+                "#{} · {} lane(s) · vmax {} · p_brake {:.2}",
+                info.id.0, p.lanes, p.vmax, p.p_brake,
+            ));
+            ui.horizontal(|ui| {
+                let idle = sweep.running.is_none();
+                if ui.add_enabled(idle, egui::Button::new("Sweep density")).clicked() {
+                    let length = match &info.grid {
+                        GridBackend::Dense(g)  => g.size.x as usize,
+                        GridBackend::Sparse(_) | GridBackend::Graph(_) => 256,
+                    };
+                    let task = AsyncComputeTaskPool::get()
+                        .spawn(async move { fundamental_diagram(&p, length, POINTS, WARMUP, MEASURE) });
+                    sweep.running = Some((info.id, task));
+                }
+                if !idle {
+                    ui.spinner();
+                }
+            });
+
+            let Some((_, samples)) = sweep.done.as_ref().filter(|(id, _)| *id == info.id) else { return };
+            ui.separator();
+            let pts: Vec<(f64, f64)> = samples.iter().map(|s| (s.density, s.flow)).collect();
+            xy_plot(ui, &pts, egui::vec2(240.0, 160.0));
+            if let Some(best) = samples.iter().max_by(|a, b| a.flow.total_cmp(&b.flow)) {
+                ui.label(format!("capacity {:.3} at density {:.3}", best.flow, best.density));
+            }
+            if ui.button("Copy CSV").clicked() {
+                ui.ctx().copy_text(to_csv(samples));
+            }
+        });
+}
@@ -0,0 +1,12 @@
+//! Analytics HUD – plots of the per‑automaton time series and model‑specific
+//! diagrams (traffic fundamental diagram, Boolean network inspector, 3‑D
+//! volume slicer).
+
+pub mod boolean_network_panel;
+pub mod fundamental_diagram_panel;
+pub mod plot;
+pub mod time_series_panel;
+pub mod volume_panel;
+pub mod plugin;
+
+pub use plugin::AnalyticsPanelPlugin;
@@ -0,0 +1,77 @@
+//! Minimal line‑plot widgets painted straight onto an egui `Ui`.
+
+use bevy_egui::egui::{self, Color32, Pos2, Rect, Sense, Stroke, Vec2};
+use computational_intelligence::analytics::time_series::Series;
+
+const LINE: Color32 = Color32::from_rgb(120, 200, 255);
+
+fn frame(ui: &mut egui::Ui, size: Vec2) -> (egui::Response, egui::Painter, Rect) {
+    let (resp, painter) = ui.allocate_painter(size, Sense::hover());
+    let rect = resp.rect;
+    painter.rect_filled(rect, 2.0, Color32::from_gray(24));
+    (resp, painter, rect.shrink(3.0))
+}
+
+/// Maps `v` from `[lo, hi]` onto `[a, b]` (centre when the range is empty).
+fn remap(v: f64, lo: f64, hi: f64, a: f32, b: f32) -> f32 {
+    if hi > lo { a + (b - a) * ((v - lo) / (hi - lo)) as f32 } else { (a + b) * 0.5 }
+}
+
+/// Value‑over‑time sparkline of one series.
+pub fn sparkline(ui: &mut egui::Ui, series: &Series, size: Vec2) -> egui::Response {
+    let (resp, painter, rect) = frame(ui, size);
+    let Some((lo, hi)) = series.range() else { return resp };
+    let Some((t0, _)) = series.iter().next() else { return resp };
+    let t1 = t0 + series.len().saturating_sub(1) as u64;
+
+    let pts: Vec<Pos2> = series.iter()
+        .map(|(t, v)| Pos2::new(
+            remap(t as f64, t0 as f64, t1 as f64, rect.left(), rect.right()),
+            remap(v, lo, hi, rect.bottom(), rect.top()),
+        ))
+        .collect();
+    painter.add(egui::Shape::line(pts, Stroke::new(1.2, LINE)));
+    resp.on_hover_text(format!("min {lo:.3} · max {hi:.3}"))
+}
+
+/// Line through `(x, y)` points scaled to their bounding box, e.g. a
+/// flow–density diagram.
+pub fn xy_plot(ui: &mut egui::Ui, points: &[(f64, f64)], size: Vec2) -> egui::Response {
+    let (resp, painter, rect) = frame(ui, size);
+    if points.is_empty() { return resp; }
+    let bounds = |f: fn(&(f64, f64)) -> f64| {
+        points.iter().map(f).fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(v), hi.max(v)))
+    };
+    let ((x_lo, x_hi), (y_lo, y_hi)) = (bounds(|p| p.0), bounds(|p| p.1));
+    let y_lo = y_lo.min(0.0);
+
+    let pts: Vec<Pos2> = points.iter()
+        .map(|&(x, y)| Pos2::new(
+            remap(x, x_lo, x_hi, rect.left(), rect.right()),
+            remap(y, y_lo, y_hi, rect.bottom(), rect.top()),
+        ))
+        .collect();
+    for p in &pts {
+        painter.circle_filled(*p, 1.5, Color32::WHITE);
+    }
+    painter.add(egui::Shape::line(pts, Stroke::new(1.0, LINE)));
+    resp.on_hover_text(format!("x {x_lo:.3}‥{x_hi:.3} · y {y_lo:.3}‥{y_hi:.3}"))
+}
+
+/// Phase plot: `x` against `y`, sample by sample (e.g. prey vs predators).
+pub fn phase_plot(ui: &mut egui::Ui, x: &Series, y: &Series, size: Vec2) -> egui::Response {
+    let (resp, painter, rect) = frame(ui, size);
+    let (Some((x_lo, x_hi)), Some((y_lo, y_hi))) = (x.range(), y.range()) else { return resp };
+
+    let pts: Vec<Pos2> = x.iter().zip(y.iter())
+        .map(|((_, a), (_, b))| Pos2::new(
+            remap(a, x_lo, x_hi, rect.left(), rect.right()),
+            remap(b, y_lo, y_hi, rect.bottom(), rect.top()),
+        ))
+        .collect();
+    if let Some(last) = pts.last() {
+        painter.circle_filled(*last, 2.5, Color32::WHITE);
+    }
+    painter.add(egui::Shape::line(pts, Stroke::new(1.0, LINE)));
+    resp
+}
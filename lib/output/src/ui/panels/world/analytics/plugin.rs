@@ -0,0 +1,23 @@
+//! Injects the analytics windows while in‑game.
+
+use bevy::prelude::*;
+use bevy_egui::EguiPrimaryContextPass;
+use engine_core::state::AppState;
+
+use super::{
+    boolean_network_panel::boolean_network_panel,
+    fundamental_diagram_panel::fundamental_diagram_panel,
+    time_series_panel::time_series_panel,
+    volume_panel::volume_panel,
+};
+
+pub struct AnalyticsPanelPlugin;
+
+impl Plugin for AnalyticsPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            EguiPrimaryContextPass,
+            (time_series_panel, fundamental_diagram_panel, boolean_network_panel, volume_panel).run_if(in_state(AppState::InGame)),
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! “Analytics” window – every time series of the selected automaton plus
+//! a phase plot of any two of them (prey vs predators, …).
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use computational_intelligence::{
+    analytics::time_series::TimeSeries,
+    registry::AutomataRegistry,
+};
+
+use crate::ui::panels::world::minimap_overlay::MinimapSelection;
+use super::plot::{phase_plot, sparkline};
+
+/// Series names on the phase‑plot axes (`None` → first / second series).
+#[derive(Default)]
+pub struct PhaseAxes {
+    x: Option<String>,
+    y: Option<String>,
+}
+
+pub fn time_series_panel(
+    automata:     Res<AutomataRegistry>,
+    series:       Res<TimeSeries>,
+    sel:          Res<MinimapSelection>,
+    mut egui_ctx: EguiContexts,
+    mut axes:     Local<PhaseAxes>,
+) {
+    let Ok(ctx) = egui_ctx.ctx_mut() else { return };
+
+    /* selected automaton, else the first one that records anything */
+    let target = sel.0.or_else(|| {
+        automata.list().iter().map(|a| a.id).find(|id| series.series(*id).next().is_some())
+    });
+    let Some(id) = target else { return };
+    if series.series(id).next().is_none() { return; }
+    let name = automata.get(id).map_or("?", |a| a.name.as_str());
+
+    egui::Window::new("Analytics")
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -40.0])
+        .resizable(false)
+        .default_open(false)
+        .show(ctx, |ui| {
+            ui.label(format!("{name} (#{})", id.0));
+            ui.separator();
+
+            for (label, s) in series.series(id) {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{label}: {:.3}", s.last().unwrap_or_default()));
+                });
+                sparkline(ui, s, egui::vec2(220.0, 40.0));
+            }
+
+            /* phase plot – needs at least two series */
+            let names: Vec<&String> = series.series(id).map(|(n, _)| n).collect();
+            if names.len() >= 2 {
+                ui.separator();
+                ui.collapsing("Phase plot", |ui| {
+                    let x = axes.x.clone().filter(|n| names.contains(&n)).unwrap_or_else(|| names[0].clone());
+                    let y = axes.y.clone().filter(|n| names.contains(&n)).unwrap_or_else(|| names[1].clone());
+                    let (mut x_sel, mut y_sel) = (x.clone(), y.clone());
+                    ui.horizontal(|ui| {
+                        axis_combo(ui, "phase_x", "x", &mut x_sel, &names);
+                        axis_combo(ui, "phase_y", "y", &mut y_sel, &names);
+                    });
+                    if let (Some(xs), Some(ys)) = (series.get(id, &x_sel), series.get(id, &y_sel)) {
+                        phase_plot(ui, xs, ys, egui::vec2(220.0, 220.0));
+                    }
+                    axes.x = Some(x_sel);
+                    axes.y = Some(y_sel);
+                });
+            }
+
+            ui.separator();
+            if ui.button("Copy CSV").clicked() {
+                ui.ctx().copy_text(series.to_csv(id));
+            }
+        });
+}
+
+fn axis_combo(ui: &mut egui::Ui, id: &str, label: &str, current: &mut String, names: &[&String]) {
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(format!("{label}: {current}"))
+        .show_ui(ui, |ui| {
+            for name in names {
+                ui.selectable_value(current, (*name).clone(), name.as_str());
+            }
+        });
+}
@@ -0,0 +1,49 @@
+//! “Volume” window – scrubs the `z` slice of the selected 3‑D automaton or
+//! switches it to the max‑intensity projection.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use computational_intelligence::{
+    automata::dynamical::life3d::{View, Volumes, LIFE3D_4555_ID, LIFE3D_CLOUDS_ID},
+    registry::AutomataRegistry,
+};
+
+use crate::ui::panels::world::minimap_overlay::MinimapSelection;
+
+pub fn volume_panel(
+    automata:     Res<AutomataRegistry>,
+    mut volumes:  ResMut<Volumes>,
+    sel:          Res<MinimapSelection>,
+    mut egui_ctx: EguiContexts,
+) {
+    let Ok(ctx) = egui_ctx.ctx_mut() else { return };
+
+    /* selected volume, else the first one */
+    let is_3d = |name: &str| name == LIFE3D_4555_ID || name == LIFE3D_CLOUDS_ID;
+    let info = sel.0
+        .and_then(|id| automata.get(id))
+        .filter(|a| is_3d(&a.name))
+        .or_else(|| automata.list().iter().find(|a| is_3d(&a.name)));
+    let Some(info) = info else { return };
+    let Some(vol) = volumes.0.get_mut(&info.id) else { return };
+
+    egui::Window::new("Volume")
+        .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -10.0])
+        .resizable(false)
+        .default_open(true)
+        .show(ctx, |ui| {
+            let size = vol.grid.size;
+            ui.label(format!(
+                "#{} · {} × {} × {} · {} alive",
+                info.id.0, size.x, size.y, size.z, vol.grid.population()
+            ));
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut vol.view, View::Slice, "Slice");
+                ui.radio_value(&mut vol.view, View::Mip, "Projection");
+            });
+            ui.add_enabled(
+                vol.view == View::Slice,
+                egui::Slider::new(&mut vol.z, 0..=size.z.saturating_sub(1)).text("z"),
+            );
+        });
+}
@@ -0,0 +1,363 @@
+//! “State diagram” window – builds a classical machine from its text
+//! definition, draws its state graph and steps it through an input word
+//! with the current state(s) lit.
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    hash::Hash,
+};
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use computational_intelligence::automata::classical::{
+    contextful::lba::{LbaConfig, LBA},
+    contextless::{
+        grammar::Grammar,
+        pda::{PdaConfig, PDA},
+    },
+    diagram::{Diagram, ToDiagram},
+    regular::{
+        dfa::DFA,
+        regex_nfa::NFA,
+        transducer::{Mealy, Moore},
+    },
+    turing::universal_tm::TuringMachine,
+};
+
+/// Configurations kept per step of a nondeterministic run.
+const FRONTIER: usize = 256;
+/// Node radius and the room a layout column / row gets, in points.
+const NODE:   f32 = 13.0;
+const COLUMN: f32 = 96.0;
+const ROW:    f32 = 60.0;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Kind {
+    #[default]
+    Nfa,
+    Dfa,
+    Mealy,
+    Moore,
+    Pda,
+    Lba,
+    Turing,
+}
+
+impl Kind {
+    const ALL: [Kind; 7] = [Kind::Nfa, Kind::Dfa, Kind::Mealy, Kind::Moore, Kind::Pda, Kind::Lba, Kind::Turing];
+
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Nfa    => "NFA (regex)",
+            Kind::Dfa    => "minimal DFA (regex)",
+            Kind::Mealy  => "Mealy machine",
+            Kind::Moore  => "Moore machine",
+            Kind::Pda    => "PDA (grammar)",
+            Kind::Lba    => "LBA",
+            Kind::Turing => "Turing machine",
+        }
+    }
+
+    /// A definition and input to start from.
+    fn example(self) -> (&'static str, &'static str) {
+        match self {
+            Kind::Nfa | Kind::Dfa => ("(a|b)*abb", "babb"),
+            Kind::Mealy => ("mealy\nstart even\neven 1 -> odd / 1\neven 0 -> even / 0\nodd 1 -> even / 0\nodd 0 -> odd / 1", "1101"),
+            Kind::Moore => ("moore\nstart even\noutput even = e\noutput odd = o\neven 1 -> odd\nodd 1 -> even\neven 0 -> even\nodd 0 -> odd", "1101"),
+            Kind::Pda    => ("S -> a S b | ε", "aabb"),
+            Kind::Lba    => ("start q0\naccept qA\nq0 a -> a R q0\nq0 b -> b R q1\nq1 b -> b R q1\nq0 > -> > S qA\nq1 > -> > S qA", "aabb"),
+            Kind::Turing => ("start q0\naccept qA\nq0 a -> X R q0\nq0 b -> b R q0\nq0 _ -> _ L qA", "abab"),
+        }
+    }
+}
+
+/// A machine and where its run has got to.
+enum Run {
+    /// Finite automata and transducers, computed up front: the states after
+    /// each symbol with a status line.
+    Finite(Vec<(BTreeSet<usize>, String)>),
+    Pda { pda: PDA, input: Vec<String>, frontier: Vec<PdaConfig> },
+    Lba { lba: LBA, frontier: Vec<LbaConfig> },
+    Turing(TuringMachine),
+}
+
+struct Loaded {
+    diagram: Diagram,
+    layout:  Vec<Vec2>,
+    canvas:  egui::Vec2,
+    run:     Run,
+    steps:   usize,
+    /// Some step had more than [`FRONTIER`] configurations and dropped the
+    /// rest, so a "not accepting" verdict may be wrong.
+    truncated: bool,
+}
+
+impl Loaded {
+    fn new(kind: Kind, source: &str, input: &str) -> Result<Self, String> {
+        let (diagram, run) = match kind {
+            Kind::Nfa => {
+                let nfa = NFA::from_regex(source).map_err(|e| e.to_string())?;
+                let trail = nfa.trace(input).into_iter().map(|s| (s.states, verdict(s.accepting).into())).collect();
+                (nfa.diagram(), Run::Finite(trail))
+            }
+            Kind::Dfa => {
+                let dfa = DFA::from_nfa(&NFA::from_regex(source).map_err(|e| e.to_string())?).minimise();
+                let trail = dfa.trace(input).into_iter().map(|s| (s.state.into_iter().collect(), verdict(s.accepting).into())).collect();
+                (dfa.diagram(), Run::Finite(trail))
+            }
+            Kind::Mealy => {
+                let m = Mealy::parse(source)?;
+                let (mut state, mut out) = (Some(m.start), String::new());
+                let mut trail = vec![(BTreeSet::from([m.start]), "output ε".to_string())];
+                for c in input.chars() {
+                    state = state.and_then(|q| m.step(q, c)).map(|(to, o)| { out.push_str(o); to });
+                    trail.push((state.into_iter().collect(), state.map_or("stuck".into(), |_| format!("output {out}"))));
+                }
+                (m.diagram(), Run::Finite(trail))
+            }
+            Kind::Moore => {
+                let m = Moore::parse(source)?;
+                let (mut state, mut out) = (Some(m.start), m.output[m.start].clone());
+                let mut trail = vec![(BTreeSet::from([m.start]), format!("output {out}"))];
+                for c in input.chars() {
+                    state = state.and_then(|q| m.step(q, c)).inspect(|&to| out.push_str(&m.output[to]));
+                    trail.push((state.into_iter().collect(), state.map_or("stuck".into(), |_| format!("output {out}"))));
+                }
+                (m.diagram(), Run::Finite(trail))
+            }
+            Kind::Pda => {
+                let g = Grammar::parse(source)?;
+                let pda = PDA::from_grammar(&g);
+                (pda.diagram(), Run::Pda { frontier: vec![pda.initial()], input: g.tokenize(input), pda })
+            }
+            Kind::Lba => {
+                let lba = LBA::parse(source)?;
+                (lba.diagram(), Run::Lba { frontier: vec![lba.initial(input)], lba })
+            }
+            Kind::Turing => {
+                let mut tm = TuringMachine::parse(source)?;
+                tm.load(input);
+                (tm.diagram(), Run::Turing(tm))
+            }
+        };
+        let layout = diagram.layout();
+        let mut per_column: HashMap<u32, usize> = HashMap::new();
+        for p in &layout {
+            *per_column.entry(p.x.to_bits()).or_default() += 1;
+        }
+        let rows = per_column.values().max().copied().unwrap_or(1);
+        let canvas = egui::vec2((per_column.len() as f32 * COLUMN).max(280.0), (rows as f32 * ROW).max(160.0));
+        Ok(Self { diagram, layout, canvas, run, steps: 0, truncated: false })
+    }
+
+    /// Advances one symbol (finite machines), one move (Turing machines)
+    /// or one move of every configuration (PDAs, LBAs).
+    fn step(&mut self) {
+        let truncated = &mut self.truncated;
+        let moved = match &mut self.run {
+            Run::Finite(trail) => self.steps + 1 < trail.len(),
+            Run::Pda { pda, input, frontier } => advance(
+                frontier,
+                truncated,
+                |c| pda.successors(c, input).into_iter().map(|(_, c)| c).collect(),
+                |c| pda.is_accepting(c, input.len()),
+            ),
+            Run::Lba { lba, frontier } => {
+                advance(frontier, truncated, |c| lba.successors(c), |c| lba.accept.contains(&c.state))
+            }
+            Run::Turing(tm) => tm.step(),
+        };
+        if moved { self.steps += 1; }
+    }
+
+    fn active(&self) -> BTreeSet<usize> {
+        match &self.run {
+            Run::Finite(trail)           => trail[self.steps].0.clone(),
+            Run::Pda { frontier, .. }    => frontier.iter().map(|c| c.state).collect(),
+            Run::Lba { frontier, .. }    => frontier.iter().map(|c| c.state).collect(),
+            Run::Turing(tm)              => BTreeSet::from([tm.state]),
+        }
+    }
+
+    fn status(&self) -> String {
+        let status = self.run_status();
+        if self.truncated {
+            format!("{status} (frontier truncated to {FRONTIER}, runs were dropped)")
+        } else {
+            status
+        }
+    }
+
+    fn run_status(&self) -> String {
+        match &self.run {
+            Run::Finite(trail) => trail[self.steps].1.clone(),
+            Run::Pda { pda, input, frontier } => {
+                let accepted = frontier.iter().any(|c| pda.is_accepting(c, input.len()));
+                match frontier.as_slice() {
+                    [c] => format!("{}  {}", pda.describe(c, input), verdict(accepted)),
+                    _   => format!("{} configurations, {}", frontier.len(), verdict(accepted)),
+                }
+            }
+            Run::Lba { lba, frontier } => {
+                let accepted = frontier.iter().any(|c| lba.accept.contains(&c.state));
+                match frontier.as_slice() {
+                    [c] => format!("{}  {}", lba.describe(c), verdict(accepted)),
+                    _   => format!("{} configurations, {}", frontier.len(), verdict(accepted)),
+                }
+            }
+            Run::Turing(tm) => {
+                let cells: String = tm.tape.iter().enumerate()
+                    .map(|(i, &c)| if i == tm.head { format!("[{c}]") } else { c.to_string() })
+                    .collect();
+                let cells = if tm.head >= tm.tape.len() { format!("{cells}[{}]", tm.blank) } else { cells };
+                match tm.halted() {
+                    Some(h) => format!("{cells}  {h:?}"),
+                    None    => format!("{cells}  in {}", tm.name(tm.state)),
+                }
+            }
+        }
+    }
+}
+
+fn verdict(accepting: bool) -> &'static str { if accepting { "accepting" } else { "not accepting" } }
+
+/// Moves every configuration of `frontier` on, keeping accepting ones that
+/// cannot move and dropping duplicates, at most [`FRONTIER`] of them (sets
+/// `truncated` if more were reached); whether anything changed.
+fn advance<C: Clone + Eq + Hash>(
+    frontier:   &mut Vec<C>,
+    truncated:  &mut bool,
+    successors: impl Fn(&C) -> Vec<C>,
+    accepting:  impl Fn(&C) -> bool,
+) -> bool {
+    let mut seen = HashSet::new();
+    let mut next: Vec<C> = frontier
+        .iter()
+        .flat_map(|c| {
+            let next = successors(c);
+            if next.is_empty() && accepting(c) { vec![c.clone()] } else { next }
+        })
+        .filter(|c| seen.insert(c.clone()))
+        .collect();
+    if next.len() > FRONTIER {
+        next.truncate(FRONTIER);
+        *truncated = true;
+    }
+    let moved = next != *frontier && !next.is_empty();
+    if moved { *frontier = next; }
+    moved
+}
+
+#[derive(Default)]
+pub struct DiagramViewer {
+    kind:   Kind,
+    source: String,
+    input:  String,
+    error:  Option<String>,
+    loaded: Option<Loaded>,
+}
+
+pub fn diagram_panel(mut egui_ctx: EguiContexts, mut viewer: Local<DiagramViewer>) {
+    let Ok(ctx) = egui_ctx.ctx_mut() else { return };
+    let v = &mut *viewer;
+    if v.source.is_empty() && v.loaded.is_none() {
+        let (source, input) = v.kind.example();
+        (v.source, v.input) = (source.into(), input.into());
+    }
+
+    egui::Window::new("State diagram")
+        .anchor(egui::Align2::CENTER_TOP, [0.0, 10.0])
+        .resizable(false)
+        .default_open(false)
+        .show(ctx, |ui| {
+            let before = v.kind;
+            egui::ComboBox::from_id_salt("diagram_kind")
+                .selected_text(v.kind.name())
+                .show_ui(ui, |ui| {
+                    for k in Kind::ALL {
+                        ui.selectable_value(&mut v.kind, k, k.name());
+                    }
+                });
+            if v.kind != before {
+                let (source, input) = v.kind.example();
+                (v.source, v.input) = (source.into(), input.into());
+                v.loaded = None;
+                v.error = None;
+            }
+            ui.add(egui::TextEdit::multiline(&mut v.source).code_editor().desired_rows(3).desired_width(280.0));
+            ui.horizontal(|ui| {
+                ui.label("input");
+                ui.text_edit_singleline(&mut v.input);
+            });
+
+            ui.horizontal(|ui| {
+                if ui.button("Load").clicked() {
+                    match Loaded::new(v.kind, &v.source, &v.input) {
+                        Ok(l)  => (v.loaded, v.error) = (Some(l), None),
+                        Err(e) => (v.loaded, v.error) = (None, Some(e)),
+                    }
+                }
+                let Some(l) = &mut v.loaded else { return };
+                if ui.button("Step").clicked() { l.step(); }
+                if ui.button("Copy DOT").clicked() { ui.ctx().copy_text(l.diagram.to_dot()); }
+            });
+            if let Some(e) = &v.error {
+                ui.colored_label(egui::Color32::LIGHT_RED, e);
+            }
+
+            let Some(l) = &v.loaded else { return };
+            ui.separator();
+            ui.label(format!("step {} · {} states · {}", l.steps, l.diagram.nodes.len(), l.status()));
+            egui::ScrollArea::both().max_width(480.0).max_height(320.0).show(ui, |ui| {
+                draw_diagram(ui, &l.diagram, &l.layout, &l.active(), l.canvas);
+            });
+        });
+}
+
+/// States as circles at their layout positions, the `active` ones filled,
+/// moves as labelled arrows and loops.
+fn draw_diagram(ui: &mut egui::Ui, d: &Diagram, layout: &[Vec2], active: &BTreeSet<usize>, size: egui::Vec2) {
+    let (resp, painter) = ui.allocate_painter(size, egui::Sense::hover());
+    painter.rect_filled(resp.rect, 2.0, egui::Color32::from_gray(24));
+    let area = resp.rect.shrink2(egui::vec2(2.5 * NODE, 2.0 * NODE));
+    let at = |q: usize| area.min + egui::vec2(layout[q].x * area.width(), layout[q].y * area.height());
+
+    let ink  = egui::Stroke::new(1.0, egui::Color32::from_white_alpha(110));
+    let font = egui::FontId::monospace(9.0);
+    let text = egui::Color32::from_gray(200);
+
+    for e in &d.edges {
+        let label = e.labels.join("\n");
+        let a = at(e.from);
+        if e.from == e.to {
+            let centre = a - egui::vec2(0.0, 1.6 * NODE);
+            painter.circle_stroke(centre, 0.8 * NODE, ink);
+            painter.text(centre - egui::vec2(0.0, NODE), egui::Align2::CENTER_BOTTOM, label, font.clone(), text);
+            continue;
+        }
+        let b   = at(e.to);
+        let dir = (b - a).normalized();
+        let normal = egui::vec2(-dir.y, dir.x);
+        // Moves both ways between two states are drawn side by side.
+        let shift = if d.edges.iter().any(|r| r.from == e.to && r.to == e.from) { 4.0 * normal } else { egui::Vec2::ZERO };
+        let (from, to) = (a + NODE * dir + shift, b - NODE * dir + shift);
+        painter.arrow(from, to - from, ink);
+        let mid = from + (to - from) / 2.0 + 3.0 * shift;
+        painter.text(mid, egui::Align2::CENTER_CENTER, label, font.clone(), text);
+    }
+
+    if d.start < layout.len() {
+        painter.arrow(at(d.start) - egui::vec2(2.2 * NODE, 0.0), egui::vec2(1.2 * NODE, 0.0), ink);
+    }
+    for (q, node) in d.nodes.iter().enumerate() {
+        let p  = at(q);
+        let on = active.contains(&q);
+        let fill = if on { egui::Color32::from_rgb(255, 216, 76) } else { egui::Color32::from_rgb(38, 46, 76) };
+        painter.circle(p, NODE, fill, egui::Stroke::new(1.0, egui::Color32::GRAY));
+        if node.accepting {
+            painter.circle_stroke(p, NODE - 3.0, egui::Stroke::new(1.0, egui::Color32::GRAY));
+        }
+        let colour = if on { egui::Color32::BLACK } else { text };
+        painter.text(p, egui::Align2::CENTER_CENTER, &node.label, font.clone(), colour);
+    }
+}
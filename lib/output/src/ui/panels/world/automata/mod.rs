@@ -1,5 +1,6 @@
 //! Namespace for all automata‑related HUD panels.
 
+pub mod diagram_panel;
 pub mod show_active_automata;
 pub mod spawn_panel;
 pub mod plugin;               // keep plugin separate
@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use bevy_egui::EguiPrimaryContextPass;
 
 use crate::ui::panels::world::automata::{
+    diagram_panel::diagram_panel,
     show_active_automata::show_active_automata,
     spawn_panel,
 };
@@ -22,6 +23,9 @@ impl Plugin for AutomataPanelPlugin {
                     // Spawner window (top‑right)
                     spawn_panel::spawn_panel
                         .run_if(in_state(AppState::InGame)),
+                    // State diagram viewer (top‑centre)
+                    diagram_panel
+                        .run_if(in_state(AppState::InGame)),
                 ),
             );
     }
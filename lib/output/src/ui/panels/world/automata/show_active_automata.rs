@@ -20,6 +20,38 @@ fn friendly_name(id: &str) -> &str {
         "lenia"           => "Lenia (blob)",
         "lenia:orbium"    => "Lenia – Orbium",
         "particle:hpp"    => "Lattice‑gas HPP",
+        "swarm:turmite"   => "Turmite",
+        "swarm:ants"      => "Ant colony",
+        "swarm:boids"     => "Boids",
+        "sugarscape"      => "Sugarscape",
+        "wator"           => "Wa‑Tor",
+        "schelling"       => "Schelling",
+        "traffic:nasch"   => "Traffic (NaSch)",
+        "games:pd"        => "Spatial PD",
+        "games:pd:kaleidoscope" => "PD kaleidoscope",
+        "spin:ising"            => "Ising",
+        "spin:potts"            => "Potts",
+        "kuramoto"              => "Kuramoto",
+        "kuramoto:spirals"      => "Kuramoto spirals",
+        "rbn"                   => "Boolean network",
+        "graph:life"            => "Life on a graph",
+        "graph:voter"           => "Voter on a graph",
+        "hex:life"              => "Hex Life",
+        "hex:snowflake"         => "Packard snowflake",
+        "hex:reiter"            => "Reiter snowflake",
+        "tri:life"              => "Triangle Life",
+        "life3d:4555"           => "3‑D Life 4555",
+        "life3d:clouds"         => "3‑D Clouds",
+        "lsystem:koch"          => "Koch snowflake",
+        "lsystem:plant"         => "Fractal plant",
+        "lsystem:bush"          => "Stochastic bush",
+        "lsystem:tree"          => "Parametric tree",
+        "lsystem:signal"        => "Signal plant",
+        "lba:anbncn"            => "LBA aⁿbⁿcⁿ",
+        "lba:counter"           => "LBA counter",
+        "tm:busy_beaver"        => "Busy beaver",
+        "tm:palindrome"         => "TM palindromes",
+        "tm:universal"          => "Universal TM",
         _                 => id,
     }
 }
@@ -58,6 +90,9 @@ pub fn show_active_automata(
                     engine_core::engine::grid::GridBackend::Sparse(s) => {
                         s.iter().filter(|(_, c)| !matches!(c.state, CellState::Dead)).count()
                     }
+                    engine_core::engine::grid::GridBackend::Graph(g) => {
+                        g.cells.iter().filter(|c| !matches!(c.state, CellState::Dead)).count()
+                    }
                 };
 
                 let is_selected = sel.0 == Some(info.id);
@@ -70,6 +70,7 @@ pub fn minimap_overlay(
             let (w, h) = match &info.grid {
                 engine_core::engine::grid::GridBackend::Dense(g)  => (g.size.x as f32, g.size.y as f32),
                 engine_core::engine::grid::GridBackend::Sparse(_) => (512.0, 512.0),
+                engine_core::engine::grid::GridBackend::Graph(g)  => (g.canvas.x.max(1) as f32, g.canvas.y.max(1) as f32),
             };
             const MAX: f32 = 150.0;
             if w >= h { (MAX, MAX * h / w) } else { (MAX * w / h, MAX) }
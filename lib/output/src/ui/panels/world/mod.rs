@@ -1,5 +1,6 @@
 //! In‑scene HUD panel hierarchy.
 
+pub mod analytics;
 pub mod automata;
 pub mod minimap_overlay;
 pub mod pause_menu;
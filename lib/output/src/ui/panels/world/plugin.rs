@@ -7,6 +7,7 @@ use engine_core::state::AppState;
 use crate::ui::panels::world::minimap_overlay::MinimapSelection;
 
 use super::{
+    analytics::AnalyticsPanelPlugin,
     automata::AutomataPanelPlugin,
     pause_menu::PauseMenuPlugin,
     zoom_overlay::zoom_overlay,
@@ -18,7 +19,7 @@ pub struct WorldMenusPlugin;
 impl Plugin for WorldMenusPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MinimapSelection>();
-        app.add_plugins((AutomataPanelPlugin, PauseMenuPlugin))
+        app.add_plugins((AutomataPanelPlugin, AnalyticsPanelPlugin, PauseMenuPlugin))
             // HUD widgets run in the egui pass only while playing.
             .add_systems(
                 EguiPrimaryContextPass,